@@ -0,0 +1,46 @@
+//! Compare the lookup of jurisdiction keys hashed by SipHash and by their integer value.
+//!
+//! Run with `cargo bench --features nohash --bench hash`.
+
+use jurisdiction::Jurisdiction;
+use nohash_hasher::IntMap;
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 10_000;
+
+fn lookups<S: BuildHasher>(
+    map: &HashMap<Jurisdiction, usize, S>,
+    keys: &[Jurisdiction],
+) -> Duration {
+    let start = Instant::now();
+    let mut sum = 0;
+    for _ in 0..ROUNDS {
+        for key in keys {
+            sum += map[black_box(key)];
+        }
+    }
+    black_box(sum);
+    start.elapsed()
+}
+
+fn main() {
+    let keys: Vec<Jurisdiction> = Jurisdiction::all().collect();
+    let sip: HashMap<Jurisdiction, usize> = keys.iter().map(|j| (j.clone(), j.index())).collect();
+    let int: IntMap<Jurisdiction, usize> = keys.iter().map(|j| (j.clone(), j.index())).collect();
+
+    let lookups_count = (ROUNDS * keys.len()) as u32;
+    let sip = lookups(&sip, &keys);
+    let int = lookups(&int, &keys);
+    println!(
+        "HashMap<Jurisdiction, _> (SipHash): {:?} per lookup",
+        sip / lookups_count
+    );
+    println!(
+        "IntMap<Jurisdiction, _>:            {:?} per lookup",
+        int / lookups_count
+    );
+}
@@ -0,0 +1,50 @@
+//! Compare the validation of alpha codes against parsing them into jurisdictions.
+//!
+//! Run with `cargo bench --bench validate`.
+
+use jurisdiction::Jurisdiction;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 10_000;
+
+fn checks(codes: &[String], check: impl Fn(&str) -> bool) -> Duration {
+    let start = Instant::now();
+    let mut valid = 0;
+    for _ in 0..ROUNDS {
+        for code in codes {
+            valid += check(black_box(code)) as usize;
+        }
+    }
+    black_box(valid);
+    start.elapsed()
+}
+
+fn main() {
+    let letters = b'A'..=b'Z';
+    let codes: Vec<String> = letters
+        .clone()
+        .flat_map(|first| letters.clone().map(move |second| [first, second]))
+        .map(|code| String::from_utf8(code.to_vec()).unwrap())
+        .collect();
+    let (valid, invalid): (Vec<String>, Vec<String>) = codes
+        .into_iter()
+        .partition(|code| code.parse::<Jurisdiction>().is_ok());
+
+    for (kind, codes) in &[("assigned", &valid), ("unassigned", &invalid)] {
+        let count = (ROUNDS * codes.len()) as u32;
+        let validated = checks(codes, jurisdiction::is_valid_code);
+        let parsed = checks(codes, |code| code.parse::<Jurisdiction>().is_ok());
+        println!(
+            "is_valid_code, {:<10} codes:        {:?} per code",
+            kind,
+            validated / count
+        );
+        println!(
+            "from_str(..).is_ok(), {:<10} codes: {:?} per code",
+            kind,
+            parsed / count
+        );
+    }
+}
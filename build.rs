@@ -1,19 +1,47 @@
 //! Generate the necessary definitions for `jurisidiction`.
 
+// The region paths of the module are only of use to the crate itself
+#[allow(dead_code)]
 #[path = "src/region.rs"]
 mod region;
 
 use crate::region::*;
 
 use anyhow::Result;
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
 use std::fs::File;
 use std::io::Write;
 use std::str::FromStr;
 
+/// The source of the UN M49 country-region definitions.
+const M49_SOURCE: &str = "UN M49 standard country or area codes for statistical use";
+
+/// Return the revision of the UN M49 country-region definitions embedded unless another is
+/// selected by the `JURISDICTION_M49_REVISION` environment variable, the latest of the
+/// revisions found as `<dir>/country-region-<revision>.json`.
+fn latest_m49_revision(dir: &str) -> Result<String> {
+    let mut revisions = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let revision = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("country-region-"))
+            .and_then(|name| name.strip_suffix(".json"));
+        if let Some(revision) = revision {
+            revisions.push(revision.to_string());
+        }
+    }
+    revisions
+        .into_iter()
+        .max()
+        .ok_or_else(|| anyhow::anyhow!("no M49 revision found as {}/country-region-*.json", dir))
+}
+
 #[allow(unused)]
 #[derive(Deserialize)]
 struct CountryRegionDefinition {
@@ -39,277 +67,2666 @@ struct CountryRegionDefinition {
     intermediate_region_code: String,
 }
 
-fn generate_alpha(definitions: &[CountryRegionDefinition]) -> TokenStream {
-    let alpha2 = generate_alpha2(&definitions);
-    let alpha3 = generate_alpha3(&definitions);
+#[derive(Deserialize)]
+struct SubdivisionDataset {
+    #[serde(rename = "3166-2")]
+    subdivisions: Vec<CountrySubdivisionDefinition>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct CountrySubdivisionDefinition {
+    code: String,
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    parent: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PostalCodeDefinition {
+    format: String,
+    example: String,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct BankingDataset {
+    source: String,
+    iban_length: HashMap<String, u8>,
+    sepa: Vec<String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct CldrContainmentDataset {
+    source: String,
+    /// The territories directly contained by each territory, forming a tree beneath `001`.
+    containment: BTreeMap<String, Vec<String>>,
+    /// The groupings of territories outside of the tree, such as `EU`.
+    groupings: BTreeMap<String, Vec<String>>,
+}
+
+impl CldrContainmentDataset {
+    /// Return the territories containing `territory` within the tree, nearest first.
+    fn parents(&self, territory: &str) -> Vec<&str> {
+        let mut parents = Vec::new();
+        let mut current = territory;
+        while let Some((parent, _)) = self
+            .containment
+            .iter()
+            .find(|(_, contained)| contained.iter().any(|t| t == current))
+        {
+            parents.push(parent.as_str());
+            current = parent;
+        }
+        parents
+    }
+
+    /// Return the territories directly contained by `territory`, within the tree or groupings.
+    fn children(&self, territory: &str) -> Vec<&str> {
+        let mut children: Vec<&str> = self
+            .containment
+            .get(territory)
+            .into_iter()
+            .chain(self.groupings.get(territory))
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        children.sort_unstable();
+        children
+    }
+
+    /// Return whether `territory` contains other territories, rather than being a jurisdiction.
+    fn is_container(&self, territory: &str) -> bool {
+        self.containment.contains_key(territory) || self.groupings.contains_key(territory)
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct Gs1Dataset {
+    source: String,
+    prefixes: Vec<Gs1PrefixDefinition>,
+}
+
+#[derive(Deserialize)]
+struct Gs1PrefixDefinition {
+    range: (u16, u16),
+    member_organization: String,
+    jurisdictions: Vec<String>,
+}
+
+impl Gs1Dataset {
+    fn ranges(&self, alpha2: &str) -> Vec<(u16, u16)> {
+        self.prefixes
+            .iter()
+            .filter(|def| def.jurisdictions.iter().any(|j| j == alpha2))
+            .map(|def| def.range)
+            .collect()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct IpDataset {
+    source: String,
+    /// The WIPO ST.3 code of each jurisdiction.
+    codes: HashMap<String, String>,
+    /// The intergovernmental organizations assigned an ST.3 code.
+    organizations: Vec<IpOrganizationDefinition>,
+}
+
+#[derive(Deserialize)]
+struct IpOrganizationDefinition {
+    code: String,
+    /// The variant of `IpOrganization`.
+    value: String,
+    name: String,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct AviationDataset {
+    source: String,
+    /// The nationality marks of each jurisdiction, as written in registrations.
+    prefixes: HashMap<String, Vec<String>>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct RadioDataset {
+    source: String,
+    series: Vec<RadioSeriesDefinition>,
+}
+
+/// A block of call sign series, the prefixes of equal length from the first to the last
+/// of `range`.
+#[derive(Deserialize)]
+struct RadioSeriesDefinition {
+    range: (String, String),
+    jurisdiction: String,
+}
+
+impl RadioDataset {
+    fn ranges(&self, alpha2: &str) -> Vec<(&str, &str)> {
+        self.series
+            .iter()
+            .filter(|def| def.jurisdiction == alpha2)
+            .map(|def| (def.range.0.as_str(), def.range.1.as_str()))
+            .collect()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct RailDataset {
+    source: String,
+    codes: HashMap<String, u8>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct TelephonyDataset {
+    source: String,
+    codes: Vec<MobileCountryCodeDefinition>,
+}
+
+/// A mobile country code, along with the jurisdictions sharing it with the primary
+/// jurisdiction listed first.
+#[derive(Deserialize)]
+struct MobileCountryCodeDefinition {
+    mcc: u16,
+    jurisdictions: Vec<String>,
+}
+
+impl TelephonyDataset {
+    fn codes(&self, alpha2: &str) -> Vec<u16> {
+        self.codes
+            .iter()
+            .filter(|def| def.jurisdictions.iter().any(|j| j == alpha2))
+            .map(|def| def.mcc)
+            .collect()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct StatisticsDataset {
+    source: String,
+    year: String,
+    area_km2: HashMap<String, u32>,
+    /// The permanent population, absent for jurisdictions without one.
+    population: HashMap<String, u64>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct LocaleDataset {
+    source: String,
+    languages: HashMap<String, Vec<String>>,
+    inherit: HashMap<String, String>,
+}
+
+impl LocaleDataset {
+    /// Return the BCP-47 tags of the locales of `alpha2`, in priority order.
+    ///
+    /// Territories without languages of their own inherit the locales of their sovereign.
+    fn locales(&self, alpha2: &str) -> Vec<String> {
+        if let Some(sovereign) = self.inherit.get(alpha2) {
+            return self.locales(sovereign);
+        }
+
+        self.languages
+            .get(alpha2)
+            .map(|languages| {
+                languages
+                    .iter()
+                    .map(|language| format!("{}-{}", language, alpha2))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct FormalNameDataset {
+    source: String,
+    names: HashMap<String, String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct DemonymDataset {
+    source: String,
+    demonyms: HashMap<String, DemonymDefinition>,
+}
+
+#[derive(Deserialize)]
+struct DemonymDefinition {
+    demonym: String,
+    plural: String,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct TerritoryKindDataset {
+    source: String,
+    /// The variant of `TerritoryKind` of jurisdictions not listed within `kinds`.
+    default: String,
+    kinds: Vec<TerritoryAssignment<String>>,
+}
+
+impl TerritoryKindDataset {
+    fn kind(&self, alpha2: &str) -> &str {
+        assigned(&self.kinds, &self.default, alpha2).as_str()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct SovereigntyDataset {
+    source: String,
+    /// Jurisdictions that are not independent, along with the sovereign state administering
+    /// them, if any. Jurisdictions not listed are independent.
+    territories: HashMap<String, Option<String>>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct PoliticalDataset {
+    source: String,
+    as_of: String,
+    groupings: Vec<GroupingDefinition>,
+}
+
+#[derive(Deserialize)]
+struct GroupingDefinition {
+    name: String,
+    description: String,
+    #[serde(default)]
+    members: Vec<String>,
+    /// Groupings preceding this one, whose members are members of this grouping.
+    #[serde(default)]
+    union: Vec<String>,
+}
+
+impl PoliticalDataset {
+    /// Return the sorted members of a grouping, resolving the groupings it is a union of.
+    fn members(&self, grouping: &GroupingDefinition) -> Vec<String> {
+        let mut members = grouping.members.clone();
+        for name in grouping.union.iter() {
+            let other = self
+                .groupings
+                .iter()
+                .take_while(|other| other.name != grouping.name)
+                .find(|other| &other.name == name)
+                .unwrap_or_else(|| {
+                    panic!("{} is a union of unknown grouping {}", grouping.name, name)
+                });
+            members.extend(self.members(other));
+        }
+        members.sort();
+        members.dedup();
+        members
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct EuAdequacyDataset {
+    source: String,
+    as_of: String,
+    /// The adequacy decision in force for each jurisdiction, if any.
+    decisions: HashMap<String, EuAdequacyDecision>,
+}
+
+#[derive(Deserialize)]
+struct EuAdequacyDecision {
+    /// The variant of `AdequacyStatus`.
+    status: String,
+    /// The date the decision was adopted, as `YYYY-MM-DD`.
+    since: String,
+    /// The scope of partial decisions.
+    scope: Option<String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct UnRegionalGroupDataset {
+    source: String,
+    as_of: String,
+    groups: Vec<UnRegionalGroupDefinition>,
+}
+
+#[derive(Deserialize)]
+struct UnRegionalGroupDefinition {
+    /// The variant of `UnRegionalGroup`.
+    value: String,
+    /// The name of the set of its members within `sets`.
+    set: String,
+    description: String,
+    members: Vec<String>,
+}
+
+impl UnRegionalGroupDataset {
+    /// Return the UN regional group of `alpha2`, if a member state of any.
+    fn group(&self, alpha2: &str) -> Option<&str> {
+        self.groups
+            .iter()
+            .find(|group| group.members.iter().any(|member| member == alpha2))
+            .map(|group| group.value.as_str())
+    }
+
+    /// Return the members of every group.
+    fn members(&self) -> impl Iterator<Item = &String> {
+        self.groups.iter().flat_map(|group| group.members.iter())
+    }
+}
+
+/// The languages of the localized names, as the file name within `data/names/` and
+/// the variant of `Language`.
+const LANGUAGES: &[(&str, &str)] = &[
+    ("ar", "Arabic"),
+    ("da", "Danish"),
+    ("de", "German"),
+    ("es", "Spanish"),
+    ("fi", "Finnish"),
+    ("fr", "French"),
+    ("it", "Italian"),
+    ("ja", "Japanese"),
+    ("nb", "NorwegianBokmal"),
+    ("nl", "Dutch"),
+    ("pl", "Polish"),
+    ("pt", "Portuguese"),
+    ("ru", "Russian"),
+    ("sv", "Swedish"),
+    ("zh", "Chinese"),
+];
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct NamesDataset {
+    source: String,
+    names: HashMap<String, String>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct VatDataset {
+    source: String,
+    prefixes: Vec<VatPrefixDefinition>,
+}
+
+#[derive(Deserialize)]
+struct VatPrefixDefinition {
+    prefix: String,
+    jurisdiction: String,
+    format: String,
+    example: String,
+    #[serde(default = "default_true")]
+    primary: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A value assigned to a list of territories, deviating from the dataset default.
+#[derive(Deserialize)]
+struct TerritoryAssignment<T> {
+    value: T,
+    territories: Vec<String>,
+}
+
+/// Resolve the value assigned to `alpha2`, falling back to `default`.
+fn assigned<'a, T>(
+    assignments: &'a [TerritoryAssignment<T>],
+    default: &'a T,
+    alpha2: &str,
+) -> &'a T {
+    assignments
+        .iter()
+        .find(|assignment| assignment.territories.iter().any(|t| t == alpha2))
+        .map(|assignment| &assignment.value)
+        .unwrap_or(default)
+}
+
+fn assigned_territories<T>(
+    assignments: &[TerritoryAssignment<T>],
+) -> impl Iterator<Item = &String> {
+    assignments
+        .iter()
+        .flat_map(|assignment| assignment.territories.iter())
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct WeekDataset {
+    source: String,
+    default: WeekDefault,
+    first_day: Vec<TerritoryAssignment<String>>,
+    weekend: Vec<TerritoryAssignment<Vec<String>>>,
+}
+
+#[derive(Deserialize)]
+struct WeekDefault {
+    first_day: String,
+    weekend: Vec<String>,
+}
+
+impl WeekDataset {
+    fn first_day(&self, alpha2: &str) -> &str {
+        assigned(&self.first_day, &self.default.first_day, alpha2).as_str()
+    }
+
+    fn weekend(&self, alpha2: &str) -> &[String] {
+        assigned(&self.weekend, &self.default.weekend, alpha2).as_slice()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct ContinentDataset {
+    source: String,
+    continent: Vec<TerritoryAssignment<String>>,
+}
+
+impl ContinentDataset {
+    fn continent(&self, alpha2: &str) -> &str {
+        let mut assigned = self
+            .continent
+            .iter()
+            .filter(|assignment| assignment.territories.iter().any(|t| t == alpha2));
+        let continent = assigned
+            .next()
+            .unwrap_or_else(|| panic!("data/continent.json does not assign {}", alpha2));
+        assert!(
+            assigned.next().is_none(),
+            "data/continent.json assigns {} to several continents",
+            alpha2
+        );
+        continent.value.as_str()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct IncomeGroupDataset {
+    source: String,
+    fiscal_year: String,
+    groups: Vec<TerritoryAssignment<String>>,
+}
+
+impl IncomeGroupDataset {
+    /// Return the income group of `alpha2`, if classified.
+    fn group(&self, alpha2: &str) -> Option<&str> {
+        self.groups
+            .iter()
+            .find(|assignment| assignment.territories.iter().any(|t| t == alpha2))
+            .map(|assignment| assignment.value.as_str())
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct LegalSystemDataset {
+    source: String,
+    /// The references backing the classification, by their key.
+    citations: BTreeMap<String, String>,
+    groups: Vec<LegalSystemAssignment>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct LegalSystemAssignment {
+    /// The variants of `LegalSystem` combined within the group, several for mixed systems.
+    value: Vec<String>,
+    /// The key of the reference within `citations`.
+    citation: String,
+    territories: Vec<String>,
+}
+
+impl LegalSystemDataset {
+    /// Return the legal systems combined within `alpha2`, if classified.
+    fn systems(&self, alpha2: &str) -> Option<&[String]> {
+        self.groups
+            .iter()
+            .find(|assignment| assignment.territories.iter().any(|t| t == alpha2))
+            .map(|assignment| assignment.value.as_slice())
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct PowerDataset {
+    source: String,
+    power: HashMap<String, PowerDefinition>,
+}
+
+#[derive(Deserialize)]
+struct PowerDefinition {
+    plugs: Vec<String>,
+    voltage: Vec<u16>,
+    frequency: Vec<u8>,
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct MeasurementDataset {
+    source: String,
+    default: MeasurementDefault,
+    measurement_system: Vec<TerritoryAssignment<String>>,
+    paper_size: Vec<TerritoryAssignment<String>>,
+}
+
+#[derive(Deserialize)]
+struct MeasurementDefault {
+    measurement_system: String,
+    paper_size: String,
+}
+
+impl MeasurementDataset {
+    fn measurement_system(&self, alpha2: &str) -> &str {
+        assigned(
+            &self.measurement_system,
+            &self.default.measurement_system,
+            alpha2,
+        )
+        .as_str()
+    }
+
+    fn paper_size(&self, alpha2: &str) -> &str {
+        assigned(&self.paper_size, &self.default.paper_size, alpha2).as_str()
+    }
+}
+
+#[allow(unused)]
+#[derive(Deserialize)]
+struct EmergencyDataset {
+    source: String,
+    numbers: Vec<TerritoryAssignment<EmergencyNumbersDefinition>>,
+}
+
+#[derive(Deserialize)]
+struct EmergencyNumbersDefinition {
+    general: Option<String>,
+    police: Option<String>,
+    ambulance: Option<String>,
+    fire: Option<String>,
+}
+
+/// Supplemental per-jurisdiction datasets, keyed by alpha2 code.
+struct SupplementalData {
+    postal_codes: HashMap<String, PostalCodeDefinition>,
+    week: WeekDataset,
+    continent: ContinentDataset,
+    income_group: IncomeGroupDataset,
+    legal_system: LegalSystemDataset,
+    power: PowerDataset,
+    measurement: MeasurementDataset,
+    emergency: EmergencyDataset,
+    banking: BankingDataset,
+    cldr_containment: CldrContainmentDataset,
+    gs1: Gs1Dataset,
+    ip: IpDataset,
+    aviation: AviationDataset,
+    radio: RadioDataset,
+    rail: RailDataset,
+    telephony: TelephonyDataset,
+    statistics: StatisticsDataset,
+    locale: LocaleDataset,
+    formal_names: FormalNameDataset,
+    demonyms: DemonymDataset,
+    sovereignty: SovereigntyDataset,
+    territory_kinds: TerritoryKindDataset,
+    political: PoliticalDataset,
+    un_regional_groups: UnRegionalGroupDataset,
+    eu_adequacy: EuAdequacyDataset,
+    /// Localized names of the languages selected through their `names-*` feature.
+    localized_names: Vec<(&'static str, NamesDataset)>,
+}
+
+impl SupplementalData {
+    fn load(definitions: &[CountryRegionDefinition]) -> Result<SupplementalData> {
+        let postal_codes: HashMap<String, PostalCodeDefinition> =
+            serde_json::from_reader(File::open("data/postal-code.json")?)?;
+        assert_known_alpha2(definitions, "data/postal-code.json", postal_codes.keys());
+
+        let week: WeekDataset = serde_json::from_reader(File::open("data/week.json")?)?;
+        let territories =
+            assigned_territories(&week.first_day).chain(assigned_territories(&week.weekend));
+        assert_known_alpha2(definitions, "data/week.json", territories);
+
+        let continent: ContinentDataset =
+            serde_json::from_reader(File::open("data/continent.json")?)?;
+        let territories = assigned_territories(&continent.continent);
+        assert_known_alpha2(definitions, "data/continent.json", territories);
+
+        let income_group: IncomeGroupDataset =
+            serde_json::from_reader(File::open("data/income-group.json")?)?;
+        let territories = assigned_territories(&income_group.groups);
+        assert_known_alpha2(definitions, "data/income-group.json", territories);
+
+        let legal_system: LegalSystemDataset =
+            serde_json::from_reader(File::open("data/legal-system.json")?)?;
+        let territories = legal_system
+            .groups
+            .iter()
+            .flat_map(|assignment| assignment.territories.iter());
+        assert_known_alpha2(definitions, "data/legal-system.json", territories);
+        let mut classified = BTreeSet::new();
+        for assignment in legal_system.groups.iter() {
+            assert!(
+                legal_system.citations.contains_key(&assignment.citation),
+                "legal system citation {} is not defined",
+                assignment.citation
+            );
+            assert!(!assignment.value.is_empty(), "legal system without value");
+            for alpha2 in assignment.territories.iter() {
+                assert!(
+                    classified.insert(alpha2),
+                    "{} is classified by several legal systems",
+                    alpha2
+                );
+            }
+        }
+
+        let power: PowerDataset = serde_json::from_reader(File::open("data/power.json")?)?;
+        assert_known_alpha2(definitions, "data/power.json", power.power.keys());
+
+        let measurement: MeasurementDataset =
+            serde_json::from_reader(File::open("data/measurement.json")?)?;
+        let territories = assigned_territories(&measurement.measurement_system)
+            .chain(assigned_territories(&measurement.paper_size));
+        assert_known_alpha2(definitions, "data/measurement.json", territories);
+
+        let emergency: EmergencyDataset =
+            serde_json::from_reader(File::open("data/emergency.json")?)?;
+        let territories = assigned_territories(&emergency.numbers);
+        assert_known_alpha2(definitions, "data/emergency.json", territories);
+
+        let banking: BankingDataset = serde_json::from_reader(File::open("data/banking.json")?)?;
+        let territories = banking.iban_length.keys().chain(banking.sepa.iter());
+        assert_known_alpha2(definitions, "data/banking.json", territories);
+
+        let cldr_containment: CldrContainmentDataset =
+            serde_json::from_reader(File::open("data/cldr-containment.json")?)?;
+        let territories = cldr_containment
+            .containment
+            .values()
+            .chain(cldr_containment.groupings.values())
+            .flatten()
+            .filter(|territory| !cldr_containment.is_container(territory));
+        assert_known_alpha2(definitions, "data/cldr-containment.json", territories);
+        for def in definitions.iter() {
+            let within = cldr_containment
+                .containment
+                .values()
+                .filter(|contained| contained.contains(&def.alpha2))
+                .count();
+            assert_eq!(within, 1, "{} not contained once by CLDR", def.alpha2);
+            assert_eq!(
+                cldr_containment.parents(&def.alpha2).last(),
+                Some(&"001"),
+                "{} not contained by the world",
+                def.alpha2
+            );
+        }
+
+        let gs1: Gs1Dataset = serde_json::from_reader(File::open("data/gs1.json")?)?;
+        let territories = gs1.prefixes.iter().flat_map(|def| {
+            def.jurisdictions
+                .iter()
+                .chain(std::iter::once(&def.member_organization))
+        });
+        assert_known_alpha2(definitions, "data/gs1.json", territories);
+
+        let ip: IpDataset = serde_json::from_reader(File::open("data/ip.json")?)?;
+        assert_known_alpha2(definitions, "data/ip.json", ip.codes.keys());
+        let mut codes = BTreeSet::new();
+        let organizations = ip.organizations.iter().map(|def| &def.code);
+        for code in ip.codes.values().chain(organizations) {
+            assert!(
+                code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()),
+                "ST.3 code {:?} is not two uppercase letters",
+                code
+            );
+            assert!(codes.insert(code), "ST.3 code {} assigned twice", code);
+        }
+
+        let aviation: AviationDataset = serde_json::from_reader(File::open("data/aviation.json")?)?;
+        assert_known_alpha2(definitions, "data/aviation.json", aviation.prefixes.keys());
+
+        let radio: RadioDataset = serde_json::from_reader(File::open("data/radio.json")?)?;
+        let territories = radio.series.iter().map(|def| &def.jurisdiction);
+        assert_known_alpha2(definitions, "data/radio.json", territories);
+
+        let rail: RailDataset = serde_json::from_reader(File::open("data/rail.json")?)?;
+        assert_known_alpha2(definitions, "data/rail.json", rail.codes.keys());
+        let mut codes: Vec<u8> = rail.codes.values().copied().collect();
+        codes.sort_unstable();
+        for pair in codes.windows(2) {
+            assert!(pair[0] != pair[1], "UIC code {} assigned twice", pair[0]);
+        }
+
+        let telephony: TelephonyDataset =
+            serde_json::from_reader(File::open("data/telephony.json")?)?;
+        let territories = telephony
+            .codes
+            .iter()
+            .flat_map(|def| def.jurisdictions.iter());
+        assert_known_alpha2(definitions, "data/telephony.json", territories);
+
+        let statistics: StatisticsDataset =
+            serde_json::from_reader(File::open("data/statistics.json")?)?;
+        let territories = statistics
+            .area_km2
+            .keys()
+            .chain(statistics.population.keys());
+        assert_known_alpha2(definitions, "data/statistics.json", territories);
+
+        let locale: LocaleDataset = serde_json::from_reader(File::open("data/locale.json")?)?;
+        let territories = locale
+            .languages
+            .keys()
+            .chain(locale.inherit.keys())
+            .chain(locale.inherit.values());
+        assert_known_alpha2(definitions, "data/locale.json", territories);
+
+        let formal_names: FormalNameDataset =
+            serde_json::from_reader(File::open("data/formal-name.json")?)?;
+        assert_known_alpha2(
+            definitions,
+            "data/formal-name.json",
+            formal_names.names.keys(),
+        );
+
+        let demonyms: DemonymDataset = serde_json::from_reader(File::open("data/demonym.json")?)?;
+        assert_known_alpha2(definitions, "data/demonym.json", demonyms.demonyms.keys());
+
+        let sovereignty: SovereigntyDataset =
+            serde_json::from_reader(File::open("data/sovereignty.json")?)?;
+        let territories = sovereignty
+            .territories
+            .keys()
+            .chain(sovereignty.territories.values().flatten());
+        assert_known_alpha2(definitions, "data/sovereignty.json", territories);
+
+        let territory_kinds: TerritoryKindDataset =
+            serde_json::from_reader(File::open("data/territory-kind.json")?)?;
+        let territories = assigned_territories(&territory_kinds.kinds);
+        assert_known_alpha2(definitions, "data/territory-kind.json", territories);
+        // Only the independent jurisdictions are of the default kind, sovereign states
+        for def in definitions.iter() {
+            let listed = assigned_territories(&territory_kinds.kinds)
+                .filter(|alpha2| **alpha2 == def.alpha2)
+                .count();
+            let independent = !sovereignty.territories.contains_key(&def.alpha2);
+            assert_eq!(
+                listed,
+                if independent { 0 } else { 1 },
+                "{} territory kind does not match its sovereignty",
+                def.alpha2
+            );
+        }
+
+        let political: PoliticalDataset =
+            serde_json::from_reader(File::open("data/political.json")?)?;
+        let territories = political
+            .groupings
+            .iter()
+            .flat_map(|grouping| grouping.members.iter());
+        assert_known_alpha2(definitions, "data/political.json", territories);
+
+        let un_regional_groups: UnRegionalGroupDataset =
+            serde_json::from_reader(File::open("data/un-regional-group.json")?)?;
+        let territories = un_regional_groups.members();
+        assert_known_alpha2(definitions, "data/un-regional-group.json", territories);
+        let mut members = BTreeSet::new();
+        for alpha2 in un_regional_groups.members() {
+            assert!(
+                members.insert(alpha2),
+                "{} is listed in several UN regional groups",
+                alpha2
+            );
+        }
+
+        let eu_adequacy: EuAdequacyDataset =
+            serde_json::from_reader(File::open("data/eu-adequacy.json")?)?;
+        assert_known_alpha2(
+            definitions,
+            "data/eu-adequacy.json",
+            eu_adequacy.decisions.keys(),
+        );
+        for (alpha2, decision) in eu_adequacy.decisions.iter() {
+            match decision.status.as_str() {
+                "Adequate" => assert!(decision.scope.is_none(), "{} adequacy scope", alpha2),
+                "PartiallyAdequate" => assert!(decision.scope.is_some(), "{} scope", alpha2),
+                status => panic!("{} has unknown adequacy status {}", alpha2, status),
+            }
+        }
+        // Transfers within the scope of the GDPR need no adequacy decision
+        for grouping in political.groupings.iter() {
+            if grouping.name == "GDPR_SCOPE" {
+                for alpha2 in political.members(grouping) {
+                    assert!(
+                        !eu_adequacy.decisions.contains_key(&alpha2),
+                        "{} is within the scope of the GDPR",
+                        alpha2
+                    );
+                }
+            }
+        }
+
+        // Only embed the languages selected, keeping the binary size proportional to them
+        let mut localized_names = Vec::new();
+        for (code, variant) in LANGUAGES.iter() {
+            let feature = format!("CARGO_FEATURE_NAMES_{}", code.to_uppercase());
+            if std::env::var_os(feature).is_none() {
+                continue;
+            }
+            let path = format!("data/names/{}.json", code);
+            let names: NamesDataset = serde_json::from_reader(File::open(&path)?)?;
+            assert_known_alpha2(definitions, &path, names.names.keys());
+            localized_names.push((*variant, names));
+        }
+
+        Ok(SupplementalData {
+            postal_codes,
+            week,
+            continent,
+            income_group,
+            legal_system,
+            power,
+            measurement,
+            emergency,
+            banking,
+            cldr_containment,
+            gs1,
+            ip,
+            aviation,
+            radio,
+            rail,
+            telephony,
+            statistics,
+            locale,
+            formal_names,
+            demonyms,
+            sovereignty,
+            territory_kinds,
+            political,
+            un_regional_groups,
+            eu_adequacy,
+            localized_names,
+        })
+    }
+}
+
+/// Guard against supplemental datasets referring to jurisdictions that do not exist.
+fn assert_known_alpha2<'a>(
+    definitions: &[CountryRegionDefinition],
+    dataset: &str,
+    keys: impl Iterator<Item = &'a String>,
+) {
+    for key in keys {
+        assert!(
+            definitions.iter().any(|def| &def.alpha2 == key),
+            "{} refers to unknown alpha2 code {}",
+            dataset,
+            key
+        );
+    }
+}
+
+/// Fold a name to lower case ASCII for search, dropping diacritics and punctuation.
+///
+/// Hyphens and slashes separate words like whitespace, and runs of separators collapse
+/// into a single space, e.g. `Guinea-Bissau` folds to `guinea bissau`. This mirrors the
+/// folding of search queries in the search module, which only handles ASCII input.
+fn fold_ascii(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.chars() {
+        let c = match c {
+            'À'..='Å' | 'à'..='å' => 'a',
+            'Ç' | 'ç' => 'c',
+            'È'..='Ë' | 'è'..='ë' => 'e',
+            'Ì'..='Ï' | 'ì'..='ï' => 'i',
+            'Ñ' | 'ñ' => 'n',
+            'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' => 'o',
+            'Ù'..='Ü' | 'ù'..='ü' => 'u',
+            'Ý' | 'ý' | 'ÿ' => 'y',
+            c => c,
+        };
+        if c.is_ascii_alphanumeric() {
+            folded.push(c.to_ascii_lowercase());
+        } else if (c.is_ascii_whitespace() || c == '-' || c == '/')
+            && !folded.is_empty()
+            && !folded.ends_with(' ')
+        {
+            folded.push(' ');
+        } else {
+            assert!(
+                c.is_ascii_punctuation() || c.is_ascii_whitespace(),
+                "no ASCII folding of {:?} in {:?}",
+                c,
+                name
+            );
+        }
+    }
+    folded.truncate(folded.trim_end().len());
+    folded
+}
+
+fn generate_alpha(definitions: &[CountryRegionDefinition]) -> TokenStream {
+    let alpha2 = generate_alpha2(definitions);
+    let alpha3 = generate_alpha3(definitions);
+
+    // Generate the table of both alpha2 and alpha3 codes, sorted by their key for binary
+    // search. The key of a code is its bytes as a big endian integer, such that the keys of
+    // two and three character codes never collide.
+    let mut codes: Vec<(u32, &str)> = Vec::new();
+    for def in definitions.iter() {
+        for code in [&def.alpha2, &def.alpha3] {
+            let key = code
+                .bytes()
+                .fold(0u32, |key, byte| (key << 8) | u32::from(byte));
+            codes.push((key, &def.alpha2));
+        }
+    }
+    codes.sort_unstable();
+    let size = codes.len();
+    let codes = codes.iter().map(|(key, alpha2)| {
+        let a = Ident::new(alpha2, Span::call_site());
+        quote!( (#key, Alpha2::#a) )
+    });
+
+    quote!(
+        use serde::{Deserialize, Serialize};
+
+        /// The alpha2 and alpha3 codes of every jurisdiction, by the key of `code_key`, sorted
+        /// by key.
+        const CODES: [(u32, Alpha2); #size] = [#(#codes),*];
+
+        /// Return the key of an alpha2 or alpha3 code within `CODES`.
+        const fn code_key(code: &[u8]) -> Option<u32> {
+            match *code {
+                [a, b] => Some(u32::from_be_bytes([0, 0, a, b])),
+                [a, b, c] => Some(u32::from_be_bytes([0, a, b, c])),
+                _ => None,
+            }
+        }
+
+        /// Search `CODES` for the `Alpha2` of an upper case alpha2 or alpha3 code.
+        const fn search_code(code: &[u8]) -> Option<Alpha2> {
+            let key = match code_key(code) {
+                Some(key) => key,
+                None => return None,
+            };
+            let (mut low, mut high) = (0, CODES.len());
+            while low < high {
+                let middle = low + (high - low) / 2;
+                let (candidate, alpha) = CODES[middle];
+                if candidate == key {
+                    return Some(alpha);
+                } else if candidate < key {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+            None
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_codes_sorted() {
+            assert!(CODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            for (key, alpha) in CODES.iter() {
+                let jurisdiction = crate::Jurisdiction::from(*alpha);
+                let alpha2 = code_key(jurisdiction.alpha2().to_string().as_bytes());
+                let alpha3 = code_key(jurisdiction.alpha3().to_string().as_bytes());
+                assert!(alpha2 == Some(*key) || alpha3 == Some(*key), "{:?}", alpha);
+            }
+        }
+
+        #alpha2
+
+        #alpha3
+    )
+}
+
+fn generate_alpha2(definitions: &[CountryRegionDefinition]) -> TokenStream {
+    // Generate enum body
+    let mut enum_body = TokenStream::new();
+    for def in definitions.iter() {
+        let a = Ident::new(&def.alpha2, Span::call_site());
+        enum_body.extend(quote!(
+            #a,
+        ));
+    }
+
+    let mut variants = TokenStream::new();
+    for (index, def) in definitions.iter().enumerate() {
+        let a = Ident::new(&def.alpha2, Span::call_site());
+        variants.extend(quote!(
+            (Alpha2::#a, #index),
+        ));
+    }
+
+    let codes: Vec<&str> = definitions.iter().map(|def| def.alpha2.as_str()).collect();
+    let lowercase = codes.iter().map(|code| code.to_lowercase());
+    let size = codes.len();
+    let deserialize = generate_alpha_deserialize(
+        "Alpha2",
+        "alpha2",
+        &codes,
+        quote!(match code.len() {
+            2 => search_code(code),
+            _ => None,
+        }),
+    );
+
+    quote!(
+        /// Two alpha character ISO 3166 country code classification.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+        #[cfg_attr(
+            feature = "diesel",
+            derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+            diesel(sql_type = diesel::sql_types::Text)
+        )]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+        #[cfg_attr(
+            feature = "strum",
+            derive(
+                strum::EnumIter,
+                strum::EnumCount,
+                strum::IntoStaticStr,
+                strum::EnumString
+            ),
+            strum(ascii_case_insensitive)
+        )]
+        #[allow(missing_docs)]
+        pub enum Alpha2 {
+            #enum_body
+        }
+
+        /// The lower case codes of the variants, in order of declaration.
+        static ALPHA2_LOWERCASE: [&str; #size] = [#(#lowercase),*];
+
+        impl Alpha2 {
+            /// Return the `Alpha2` of an alpha2 or alpha3 code, usable in constant expressions.
+            pub(crate) const fn from_code(code: &str) -> Option<Alpha2> {
+                search_code(code.as_bytes())
+            }
+
+            /// Return the ISO 3166-1 numeric code, zero-padded to three digits, e.g. `040`.
+            pub fn numeric_str(&self) -> &'static str {
+                crate::Jurisdiction::from(*self).numeric_str()
+            }
+
+            /// Return the lower case code, e.g. `no`.
+            pub(crate) fn lowercase(&self) -> &'static str {
+                ALPHA2_LOWERCASE[*self as usize]
+            }
+        }
+
+        impl From<Alpha2> for crate::Jurisdiction {
+            fn from(alpha: Alpha2) -> Self {
+                // The variants are declared in the order of the definitions
+                crate::Jurisdiction::from_position(alpha as usize)
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_from_alpha2_exhaustive() {
+            for (alpha, index) in [#variants].iter() {
+                let jurisdiction = crate::Jurisdiction::from(*alpha);
+                assert_eq!(jurisdiction.position(), *index);
+                assert_eq!(jurisdiction.alpha2(), *alpha);
+            }
+        }
+
+        #deserialize
+
+        impl std::fmt::Display for Alpha2 {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "{}",
+                    serde_plain::to_string(&self).map_err(|_| std::fmt::Error)?
+                )
+            }
+        }
+    )
+}
+
+fn generate_alpha3(definitions: &[CountryRegionDefinition]) -> TokenStream {
+    // Generate enum body
+    let mut enum_body = TokenStream::new();
+    for def in definitions.iter() {
+        let a = Ident::new(&def.alpha3, Span::call_site());
+        enum_body.extend(quote!(
+            #a,
+        ));
+    }
+
+    let mut variants = TokenStream::new();
+    for (index, def) in definitions.iter().enumerate() {
+        let a = Ident::new(&def.alpha3, Span::call_site());
+        variants.extend(quote!(
+            (Alpha3::#a, #index),
+        ));
+    }
+
+    let codes: Vec<&str> = definitions.iter().map(|def| def.alpha3.as_str()).collect();
+    let lowercase = codes.iter().map(|code| code.to_lowercase());
+    let size = codes.len();
+    let deserialize = generate_alpha_deserialize(
+        "Alpha3",
+        "alpha3",
+        &codes,
+        quote!(match code.len() {
+            3 => search_code(code).map(|alpha| crate::Jurisdiction::from(alpha).alpha3()),
+            _ => None,
+        }),
+    );
+
+    quote!(
+        /// Three alpha character ISO 3166 country code classification.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+        #[cfg_attr(
+            feature = "diesel",
+            derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+            diesel(sql_type = diesel::sql_types::Text)
+        )]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+        #[cfg_attr(
+            feature = "strum",
+            derive(
+                strum::EnumIter,
+                strum::EnumCount,
+                strum::IntoStaticStr,
+                strum::EnumString
+            ),
+            strum(ascii_case_insensitive)
+        )]
+        #[allow(missing_docs)]
+        pub enum Alpha3 {
+            #enum_body
+        }
+
+        /// The lower case codes of the variants, in order of declaration.
+        static ALPHA3_LOWERCASE: [&str; #size] = [#(#lowercase),*];
+
+        impl Alpha3 {
+            /// Return the ISO 3166-1 numeric code, zero-padded to three digits, e.g. `040`.
+            pub fn numeric_str(&self) -> &'static str {
+                crate::Jurisdiction::from(*self).numeric_str()
+            }
+
+            /// Return the lower case code, e.g. `nor`.
+            pub(crate) fn lowercase(&self) -> &'static str {
+                ALPHA3_LOWERCASE[*self as usize]
+            }
+        }
+
+        impl From<Alpha3> for crate::Jurisdiction {
+            fn from(alpha: Alpha3) -> Self {
+                // The variants are declared in the order of the definitions
+                crate::Jurisdiction::from_position(alpha as usize)
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_from_alpha3_exhaustive() {
+            for (alpha, index) in [#variants].iter() {
+                let jurisdiction = crate::Jurisdiction::from(*alpha);
+                assert_eq!(jurisdiction.position(), *index);
+                assert_eq!(jurisdiction.alpha3(), *alpha);
+            }
+        }
+
+        #deserialize
+
+        impl std::fmt::Display for Alpha3 {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "{}",
+                    serde_plain::to_string(&self).map_err(|_| std::fmt::Error)?
+                )
+            }
+        }
+    )
+}
+
+/// Generate the case insensitive `Deserialize` impl of an alpha code enum, by way of the
+/// `AlphaCode` trait of the `strict` module, looking up exact codes by `from_exact`.
+fn generate_alpha_deserialize(
+    name: &str,
+    kind: &str,
+    codes: &[&str],
+    from_exact: TokenStream,
+) -> TokenStream {
+    let ident = Ident::new(name, Span::call_site());
+    let expecting = format!("an ISO 3166 {} code", kind);
+
+    quote!(
+        impl crate::strict::AlphaCode for #ident {
+            const NAME: &'static str = #name;
+            const EXPECTING: &'static str = #expecting;
+            const VARIANTS: &'static [&'static str] = &[#(#codes),*];
+
+            fn from_exact(code: &[u8]) -> Option<Self> {
+                #from_exact
+            }
+        }
+
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::strict::deserialize_alpha(deserializer, true)
+            }
+        }
+    )
+}
+
+/// The variants of a kind of region, the definition indices of the jurisdictions of each
+/// region grouped by region in the order of the variants and ordered by numeric country code
+/// within each region, and the offset of each region
+/// within the grouping. A region spans from its offset to the offset of the next region,
+/// such that there is one offset more than there are regions.
+struct RegionTable {
+    variants: Vec<Ident>,
+    indices: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+/// Return the `RegionTable` of the regions named by `names`, in the order of the variants.
+fn region_table<R: fmt::Debug + PartialEq + for<'de> Deserialize<'de>>(
+    names: &[&str],
+    definitions: &[CountryRegionDefinition],
+    region: impl Fn(&CountryRegionDefinition) -> &R,
+) -> RegionTable {
+    let mut variants = Vec::with_capacity(names.len());
+    let mut indices = Vec::with_capacity(definitions.len());
+    let mut offsets = vec![0];
+    for name in names {
+        let variant: R = serde_plain::from_str(name).expect("region name");
+        variants.push(Ident::new(&format!("{:?}", variant), Span::call_site()));
+        // Order the jurisdictions of each region by numeric country code, for binary search
+        let mut members: Vec<usize> = (0..definitions.len())
+            .filter(|index| *region(&definitions[*index]) == variant)
+            .collect();
+        members.sort_by_key(|index| u16::from_str(&definitions[*index].country_code).ok());
+        indices.extend(members);
+        offsets.push(indices.len());
+    }
+    assert_eq!(
+        indices.len(),
+        definitions.len(),
+        "jurisdictions without region"
+    );
+    RegionTable {
+        variants,
+        indices,
+        offsets,
+    }
+}
+
+fn generate_region(definitions: &[CountryRegionDefinition]) -> TokenStream {
+    let kinds = [
+        (
+            "Region",
+            "region",
+            quote!(
+                /// Return the number of jurisdictions zoning to this region.
+            ),
+            region_table(Region::NAMES, definitions, |def| &def.region),
+        ),
+        (
+            "SubRegion",
+            "sub_region",
+            quote!(
+                /// Return the number of jurisdictions zoning to this sub region.
+            ),
+            region_table(SubRegion::NAMES, definitions, |def| &def.sub_region),
+        ),
+        (
+            "IntermediateRegion",
+            "intermediate_region",
+            quote!(
+                /// Return the number of jurisdictions zoning to this intermediate region.
+                ///
+                /// Jurisdictions without an intermediate region are counted by `Undefined`.
+            ),
+            region_table(IntermediateRegion::NAMES, definitions, |def| {
+                &def.intermediate_region
+            }),
+        ),
+    ];
+
+    // Generate a flat table of the jurisdictions of each kind of region, grouped by
+    // region, and the offsets of each region within it, indexed by the region variants.
+    let mut tables = TokenStream::new();
+    let mut table_tests = TokenStream::new();
+    let mut count_tests = TokenStream::new();
+    for (kind, accessor, count_doc, table) in kinds.iter() {
+        let prefix = accessor.to_uppercase();
+        let jurisdictions = Ident::new(&format!("{}_JURISDICTIONS", prefix), Span::call_site());
+        let offsets_ident = Ident::new(&format!("{}_OFFSETS", prefix), Span::call_site());
+        let contains_doc = format!(
+            " Return whether `jurisdiction` zones to this {}.",
+            accessor.replace('_', " ")
+        );
+        let accessor = Ident::new(accessor, Span::call_site());
+        let kind = Ident::new(kind, Span::call_site());
+        let indices = &table.indices;
+        let offsets = &table.offsets;
+        let size = indices.len();
+        let offsets_size = offsets.len();
+
+        for variant in table.variants.iter() {
+            count_tests.extend(quote!(
+                assert_eq!(#kind::#variant.jurisdiction_count(), #kind::#variant.jurisdictions().len());
+            ));
+        }
+
+        tables.extend(quote!(
+            static #jurisdictions: [Jurisdiction; #size] = [
+                #(Jurisdiction::from_definition(&GENERATED_DEFINITIONS[#indices])),*
+            ];
+
+            const #offsets_ident: [usize; #offsets_size] = [#(#offsets),*];
+
+            impl #kind {
+                #count_doc
+                #[allow(clippy::trivially_copy_pass_by_ref)]
+                pub const fn jurisdiction_count(&self) -> usize {
+                    let index = *self as usize;
+                    #offsets_ident[index + 1] - #offsets_ident[index]
+                }
+
+                #[allow(clippy::trivially_copy_pass_by_ref)]
+                pub(crate) fn jurisdictions(&self) -> &'static [Jurisdiction] {
+                    let index = *self as usize;
+                    &#jurisdictions[#offsets_ident[index]..#offsets_ident[index + 1]]
+                }
+
+                #[doc = #contains_doc]
+                ///
+                /// The jurisdictions of the region are ordered by numeric country code, and
+                /// searched by bisection.
+                #[allow(clippy::trivially_copy_pass_by_ref)]
+                pub fn contains(&self, jurisdiction: &Jurisdiction) -> bool {
+                    self.jurisdictions()
+                        .binary_search_by_key(&jurisdiction.country_code(), Jurisdiction::country_code)
+                        .is_ok()
+                }
+            }
+        ));
+
+        table_tests.extend(quote!(
+            assert_eq!(#offsets_ident[0], 0);
+            assert!(#offsets_ident.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert_eq!(#offsets_ident[#offsets_ident.len() - 1], #jurisdictions.len());
+            assert_eq!(#offsets_ident.len(), #kind::NAMES.len() + 1);
+            for name in #kind::NAMES {
+                let region: #kind = serde_plain::from_str(name).unwrap();
+                let jurisdictions = region.jurisdictions();
+                assert!(
+                    jurisdictions
+                        .windows(2)
+                        .all(|pair| pair[0].country_code() < pair[1].country_code()),
+                    "{:?} not sorted by country code",
+                    region
+                );
+                for jurisdiction in jurisdictions {
+                    assert_eq!(jurisdiction.#accessor(), region);
+                }
+            }
+            for jurisdiction in Jurisdiction::all() {
+                let region = jurisdiction.#accessor();
+                assert!(region.jurisdictions().contains(&jurisdiction));
+                assert!(region.contains(&jurisdiction));
+            }
+        ));
+    }
+
+    // Generate the number of jurisdictions of each region, in the order of the regions
+    let table = &kinds[0].3;
+    let region_counts =
+        table
+            .variants
+            .iter()
+            .zip(table.offsets.windows(2))
+            .map(|(variant, pair)| {
+                let count = pair[1] - pair[0];
+                quote!( (Region::#variant, #count) )
+            });
+
+    // Iterate the jurisdictions of each kind of region, through both values and references
+    let mut into_iterators = TokenStream::new();
+    for (kind, _, _, _) in kinds.iter() {
+        let kind = Ident::new(kind, Span::call_site());
+        for ty in [quote!(#kind), quote!(&#kind)] {
+            into_iterators.extend(quote!(
+                /// Iterate the jurisdictions zoning to the region, ordered by numeric country
+                /// code.
+                impl IntoIterator for #ty {
+                    type Item = Jurisdiction;
+                    type IntoIter = std::iter::Cloned<std::slice::Iter<'static, Jurisdiction>>;
+
+                    fn into_iter(self) -> Self::IntoIter {
+                        self.jurisdictions().iter().cloned()
+                    }
+                }
+            ));
+        }
+    }
+
+    quote!(
+        use crate::generated::definition::GENERATED_DEFINITIONS;
+        use crate::region::{Region, SubRegion, IntermediateRegion};
+        use crate::Jurisdiction;
+
+        /// The number of jurisdictions of each region, in the order of the regions.
+        pub(crate) static REGION_COUNTS: &[(Region, usize)] = &[#(#region_counts),*];
+
+        #tables
+
+        #[cfg(test)]
+        #[test]
+        fn test_jurisdiction_counts() {
+            #count_tests
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_region_tables() {
+            #table_tests
+        }
+
+        #into_iterators
+    )
+}
+
+/// The strings of the definition table, concatenated into a single string, such that each
+/// definition refers to its strings by a compact offset and length, rather than a pointer.
+#[derive(Default)]
+struct StringTable {
+    table: String,
+    offsets: HashMap<String, usize>,
+}
+
+impl StringTable {
+    /// Append the string to the table, unless already present, returning a `TableStr`.
+    fn intern(&mut self, string: &str) -> TokenStream {
+        let table = &mut self.table;
+        let offset = *self.offsets.entry(string.to_string()).or_insert_with(|| {
+            table.push_str(string);
+            table.len() - string.len()
+        });
+        let offset = u16::try_from(offset).expect("string table exceeds u16 offsets");
+        let len = u16::try_from(string.len()).expect("string exceeds u16 length");
+        quote!(TableStr::new(#offset, #len))
+    }
+
+    fn into_literal(self) -> Literal {
+        assert!(
+            self.table.len() <= usize::from(u16::MAX),
+            "string table exceeds u16 offsets"
+        );
+        Literal::string(&self.table)
+    }
+}
+
+/// The numeric and enum fields of the definition table, each generated as an array parallel
+/// to the definitions and indexed by the position of a definition, rather than interleaved
+/// with the pointers of the definitions and padded to their alignment.
+#[derive(Default)]
+struct Columns {
+    columns: Vec<(
+        &'static str,
+        Option<&'static str>,
+        TokenStream,
+        Vec<TokenStream>,
+    )>,
+}
+
+impl Columns {
+    /// Append the value of the next definition to the column `name` of `ty`, gated behind
+    /// `feature`.
+    fn push(
+        &mut self,
+        name: &'static str,
+        feature: Option<&'static str>,
+        ty: TokenStream,
+        value: TokenStream,
+    ) {
+        match self.columns.iter_mut().find(|column| column.0 == name) {
+            Some(column) => column.3.push(value),
+            None => self.columns.push((name, feature, ty, vec![value])),
+        }
+    }
+
+    fn into_statics(self) -> TokenStream {
+        let mut statics = TokenStream::new();
+        for (name, feature, ty, values) in self.columns {
+            let name = Ident::new(name, Span::call_site());
+            let size = values.len();
+            let cfg = feature.map(|feature| quote!(#[cfg(feature = #feature)]));
+            statics.extend(quote!(
+                #cfg
+                pub static #name: [#ty; #size] = [#(#values),*];
+            ));
+        }
+        statics
+    }
+}
+
+fn generate_definition(
+    definitions: &[CountryRegionDefinition],
+    supplemental: &SupplementalData,
+) -> TokenStream {
+    // Index the jurisdictions alphabetically by alpha2 code, see `Jurisdiction::index`
+    let mut by_index: Vec<usize> = (0..definitions.len()).collect();
+    by_index.sort_by(|a, b| definitions[*a].alpha2.cmp(&definitions[*b].alpha2));
+    let mut indices = vec![0u16; definitions.len()];
+    for (index, position) in by_index.iter().enumerate() {
+        indices[*position] = u16::try_from(index).expect("index not representable as u16");
+    }
+
+    let mut strings = StringTable::default();
+    let mut columns = Columns::default();
+    let mut tokendefs = TokenStream::new();
+    for (def, index) in definitions.iter().zip(indices) {
+        let name = &def.name;
+        let cc = u16::from_str(&def.country_code).expect("country code not representable as u16");
+        let numeric = format!("{:03}", cc);
+        assert_eq!(numeric, def.country_code, "country code not three digits");
+        let numeric = strings.intern(&numeric);
+        let alpha2 = Ident::new(&def.alpha2, Span::call_site());
+        let alpha3 = Ident::new(&def.alpha3, Span::call_site());
+        let region = Ident::new(&format!("{:?}", &def.region), Span::call_site());
+        let sub_region = Ident::new(&format!("{:?}", &def.sub_region), Span::call_site());
+        let intermediate_region = Ident::new(
+            &format!("{:?}", &def.intermediate_region),
+            Span::call_site(),
+        );
+
+        let name_ascii = strings.intern(&fold_ascii(name));
+        let iso3166_2_prefix = strings.intern(
+            def.iso_3166_2
+                .strip_prefix("ISO 3166-2:")
+                .expect("ISO 3166-2 reference not prefixed by the standard"),
+        );
+
+        // M49 places areas outside of any region, i.e. Antarctica, directly beneath the
+        // world, such that their own code identifies their top level area
+        let rc = match def.region {
+            Region::Undefined => cc,
+            _ => u16::from_str(&def.region_code).expect("region without a region code"),
+        };
+        let sc = u16::from_str(&def.sub_region_code).unwrap_or(0);
+        let irc = u16::from_str(&def.intermediate_region_code).unwrap_or(0);
+
+        // Formal names identical to the short name are not stored twice
+        let formal_name = match supplemental.formal_names.names.get(&def.alpha2) {
+            Some(formal_name) if formal_name != name => {
+                let formal_name = strings.intern(formal_name);
+                quote!(Some(#formal_name))
+            }
+            _ => quote!(None),
+        };
+        let name = strings.intern(name);
+        let irc: TokenStream = match irc {
+            0 => proc_macro2::TokenTree::from(Ident::new("None", Span::call_site())).into(),
+            _ => quote!(Some(#irc)),
+        };
+
+        let postal = match supplemental.postal_codes.get(&def.alpha2) {
+            Some(postal) => {
+                let format = &postal.format;
+                let example = &postal.example;
+                quote!(Some(PostalFormat {
+                    pattern: #format,
+                    example: #example,
+                }))
+            }
+            None => quote!(None),
+        };
+
+        let first_day = Ident::new(supplemental.week.first_day(&def.alpha2), Span::call_site());
+        let weekend = supplemental
+            .week
+            .weekend(&def.alpha2)
+            .iter()
+            .map(|day| Ident::new(day, Span::call_site()));
+        let continent = Ident::new(
+            supplemental.continent.continent(&def.alpha2),
+            Span::call_site(),
+        );
+        let income_group = match supplemental.income_group.group(&def.alpha2) {
+            Some(group) => {
+                let group = Ident::new(group, Span::call_site());
+                quote!(Some(IncomeGroup::#group))
+            }
+            None => quote!(None),
+        };
+        let legal_system = match supplemental.legal_system.systems(&def.alpha2) {
+            Some([system]) => {
+                let system = Ident::new(system, Span::call_site());
+                quote!(Some(LegalSystem::#system))
+            }
+            Some(systems) => {
+                let systems = systems
+                    .iter()
+                    .map(|system| Ident::new(system, Span::call_site()));
+                quote!(Some(LegalSystem::Mixed(&[#(LegalSystem::#systems),*])))
+            }
+            None => quote!(None),
+        };
+        let eu_adequacy = match supplemental.eu_adequacy.decisions.get(&def.alpha2) {
+            Some(EuAdequacyDecision {
+                since,
+                scope: Some(scope),
+                ..
+            }) => quote!(AdequacyStatus::PartiallyAdequate {
+                since: #since,
+                scope: #scope,
+            }),
+            Some(EuAdequacyDecision { since, .. }) => {
+                quote!(AdequacyStatus::Adequate { since: #since })
+            }
+            None => quote!(AdequacyStatus::NotAdequate),
+        };
+        let un_regional_group = match supplemental.un_regional_groups.group(&def.alpha2) {
+            Some(group) => {
+                let group = Ident::new(group, Span::call_site());
+                quote!(Some(UnRegionalGroup::#group))
+            }
+            None => quote!(None),
+        };
+        let (plug_types, mains_voltage, mains_frequency) =
+            match supplemental.power.power.get(&def.alpha2) {
+                Some(power) => (
+                    power
+                        .plugs
+                        .iter()
+                        .map(|plug| Ident::new(plug, Span::call_site()))
+                        .collect(),
+                    power.voltage.clone(),
+                    power.frequency.clone(),
+                ),
+                None => (Vec::new(), Vec::new(), Vec::new()),
+            };
+        let measurement_system = Ident::new(
+            supplemental.measurement.measurement_system(&def.alpha2),
+            Span::call_site(),
+        );
+        let paper_size = Ident::new(
+            supplemental.measurement.paper_size(&def.alpha2),
+            Span::call_site(),
+        );
+        let number = |number: &Option<String>| match number {
+            Some(number) => quote!(Some(#number)),
+            None => quote!(None),
+        };
+        let emergency_numbers = match supplemental
+            .emergency
+            .numbers
+            .iter()
+            .find(|assignment| assignment.territories.contains(&def.alpha2))
+        {
+            Some(TerritoryAssignment { value, .. }) => {
+                let (general, police, ambulance, fire) = (
+                    number(&value.general),
+                    number(&value.police),
+                    number(&value.ambulance),
+                    number(&value.fire),
+                );
+                quote!(EmergencyNumbers {
+                    general: #general,
+                    police: #police,
+                    ambulance: #ambulance,
+                    fire: #fire,
+                })
+            }
+            None => quote!(EmergencyNumbers {
+                general: None,
+                police: None,
+                ambulance: None,
+                fire: None,
+            }),
+        };
+        let iban_length = match supplemental.banking.iban_length.get(&def.alpha2) {
+            Some(length) => quote!(Some(#length)),
+            None => quote!(None),
+        };
+        let sepa_member = supplemental.banking.sepa.contains(&def.alpha2);
+        let uic_code = match supplemental.rail.codes.get(&def.alpha2) {
+            Some(code) => quote!(Some(#code)),
+            None => quote!(None),
+        };
+        let cldr_parents = supplemental.cldr_containment.parents(&def.alpha2);
+        let gs1_prefixes = supplemental
+            .gs1
+            .ranges(&def.alpha2)
+            .into_iter()
+            .map(|(start, end)| quote!(#start..=#end));
+        let st3_code = match supplemental.ip.codes.get(&def.alpha2) {
+            Some(code) => quote!(Some(#code)),
+            None => quote!(None),
+        };
+        let aircraft_prefixes = supplemental
+            .aviation
+            .prefixes
+            .get(&def.alpha2)
+            .cloned()
+            .unwrap_or_default();
+        let callsign_prefixes = supplemental
+            .radio
+            .ranges(&def.alpha2)
+            .into_iter()
+            .map(|(start, end)| quote!(#start..=#end));
+        let mobile_country_codes = supplemental.telephony.codes(&def.alpha2);
+        let area_km2 = match supplemental.statistics.area_km2.get(&def.alpha2) {
+            Some(area) => quote!(Some(#area)),
+            None => quote!(None),
+        };
+        let population = match supplemental.statistics.population.get(&def.alpha2) {
+            Some(population) => quote!(Some(#population)),
+            None => quote!(None),
+        };
+        let default_locales = supplemental.locale.locales(&def.alpha2);
+        let demonym = match supplemental.demonyms.demonyms.get(&def.alpha2) {
+            Some(DemonymDefinition { demonym, plural }) => quote!(Some((#demonym, #plural))),
+            None => quote!(None),
+        };
+        let independent = !supplemental
+            .sovereignty
+            .territories
+            .contains_key(&def.alpha2);
+        let sovereign = match supplemental.sovereignty.territories.get(&def.alpha2) {
+            Some(Some(sovereign)) => {
+                let sovereign = definitions
+                    .iter()
+                    .find(|def| &def.alpha2 == sovereign)
+                    .expect("validated alpha2");
+                let sovereign = Ident::new(&sovereign.alpha2, Span::call_site());
+                quote!(Some(Alpha2::#sovereign))
+            }
+            _ => quote!(None),
+        };
+        let territory_kind = Ident::new(
+            supplemental.territory_kinds.kind(&def.alpha2),
+            Span::call_site(),
+        );
+        let dependencies = definitions
+            .iter()
+            .enumerate()
+            .filter(|(_, dependency)| {
+                supplemental.sovereignty.territories.get(&dependency.alpha2)
+                    == Some(&Some(def.alpha2.clone()))
+            })
+            .map(
+                |(index, _)| quote!(Jurisdiction::from_definition(&GENERATED_DEFINITIONS[#index])),
+            );
+        let localized_names = supplemental
+            .localized_names
+            .iter()
+            .filter_map(|(variant, names)| {
+                let variant = Ident::new(variant, Span::call_site());
+                let name = names.names.get(&def.alpha2)?;
+                Some(quote!((Language::#variant, #name)))
+            });
+
+        columns.push("GENERATED_INDICES", None, quote!(u16), quote!(#index));
+        columns.push("GENERATED_COUNTRY_CODES", None, quote!(u16), quote!(#cc));
+        columns.push(
+            "GENERATED_ALPHA3",
+            None,
+            quote!(Alpha3),
+            quote!(Alpha3::#alpha3),
+        );
+        columns.push(
+            "GENERATED_REGIONS",
+            Some("region"),
+            quote!(Region),
+            quote!(Region::#region),
+        );
+        columns.push(
+            "GENERATED_SUB_REGIONS",
+            Some("region"),
+            quote!(SubRegion),
+            quote!(SubRegion::#sub_region),
+        );
+        columns.push(
+            "GENERATED_INTERMEDIATE_REGIONS",
+            Some("region"),
+            quote!(IntermediateRegion),
+            quote!(IntermediateRegion::#intermediate_region),
+        );
+        columns.push(
+            "GENERATED_REGION_CODES",
+            Some("region"),
+            quote!(u16),
+            quote!(#rc),
+        );
+        columns.push(
+            "GENERATED_SUB_REGION_CODES",
+            Some("region"),
+            quote!(u16),
+            quote!(#sc),
+        );
+        columns.push(
+            "GENERATED_INTERMEDIATE_REGION_CODES",
+            Some("region"),
+            quote!(Option<u16>),
+            irc,
+        );
+        columns.push(
+            "GENERATED_CONTINENTS",
+            Some("continent"),
+            quote!(Continent),
+            quote!(Continent::#continent),
+        );
+        columns.push(
+            "GENERATED_INCOME_GROUPS",
+            Some("economics"),
+            quote!(Option<IncomeGroup>),
+            income_group,
+        );
+        columns.push(
+            "GENERATED_LEGAL_SYSTEMS",
+            Some("legal"),
+            quote!(Option<LegalSystem>),
+            legal_system,
+        );
+        columns.push(
+            "GENERATED_UN_REGIONAL_GROUPS",
+            Some("political"),
+            quote!(Option<UnRegionalGroup>),
+            un_regional_group,
+        );
+        columns.push(
+            "GENERATED_EU_ADEQUACY",
+            Some("political"),
+            quote!(AdequacyStatus),
+            eu_adequacy,
+        );
+        columns.push(
+            "GENERATED_AREAS_KM2",
+            Some("statistics"),
+            quote!(Option<u32>),
+            area_km2,
+        );
+        columns.push(
+            "GENERATED_POPULATIONS",
+            Some("statistics"),
+            quote!(Option<u64>),
+            population,
+        );
+        columns.push(
+            "GENERATED_FIRST_DAYS_OF_WEEK",
+            Some("conventions"),
+            quote!(Weekday),
+            quote!(Weekday::#first_day),
+        );
+        columns.push(
+            "GENERATED_MEASUREMENT_SYSTEMS",
+            Some("conventions"),
+            quote!(MeasurementSystem),
+            quote!(MeasurementSystem::#measurement_system),
+        );
+        columns.push(
+            "GENERATED_PAPER_SIZES",
+            Some("conventions"),
+            quote!(PaperSize),
+            quote!(PaperSize::#paper_size),
+        );
+        columns.push(
+            "GENERATED_IBAN_LENGTHS",
+            Some("banking"),
+            quote!(Option<u8>),
+            iban_length,
+        );
+        columns.push(
+            "GENERATED_SEPA_MEMBERS",
+            Some("banking"),
+            quote!(bool),
+            quote!(#sepa_member),
+        );
+        columns.push(
+            "GENERATED_UIC_CODES",
+            Some("rail"),
+            quote!(Option<u8>),
+            uic_code,
+        );
+        columns.push(
+            "GENERATED_INDEPENDENT",
+            Some("sovereignty"),
+            quote!(bool),
+            quote!(#independent),
+        );
+        columns.push(
+            "GENERATED_SOVEREIGNS",
+            Some("sovereignty"),
+            quote!(Option<Alpha2>),
+            sovereign,
+        );
+        columns.push(
+            "GENERATED_TERRITORY_KINDS",
+            Some("sovereignty"),
+            quote!(TerritoryKind),
+            quote!(TerritoryKind::#territory_kind),
+        );
+
+        tokendefs.extend(quote!(
+            Definition {
+                numeric: #numeric,
+                name: #name,
+                formal_name: #formal_name,
+                name_ascii: #name_ascii,
+                alpha2: Alpha2::#alpha2,
+                iso3166_2_prefix: #iso3166_2_prefix,
+                #[cfg(feature = "power")]
+                plug_types: &[#(PlugType::#plug_types),*],
+                #[cfg(feature = "power")]
+                mains_voltage: &[#(#mains_voltage),*],
+                #[cfg(feature = "power")]
+                mains_frequency: &[#(#mains_frequency),*],
+                #[cfg(feature = "postal")]
+                postal_code_format: #postal,
+                #[cfg(feature = "conventions")]
+                weekend: &[#(Weekday::#weekend),*],
+                #[cfg(feature = "conventions")]
+                emergency_numbers: #emergency_numbers,
+                #[cfg(feature = "cldr-containment")]
+                cldr_parents: &[#(#cldr_parents),*],
+                #[cfg(feature = "gs1")]
+                gs1_prefixes: &[#(#gs1_prefixes),*],
+                #[cfg(feature = "ip")]
+                st3_code: #st3_code,
+                #[cfg(feature = "aviation")]
+                aircraft_prefixes: &[#(#aircraft_prefixes),*],
+                #[cfg(feature = "radio")]
+                callsign_prefixes: &[#(#callsign_prefixes),*],
+                #[cfg(feature = "telephony")]
+                mobile_country_codes: &[#(#mobile_country_codes),*],
+                #[cfg(feature = "locale")]
+                default_locales: &[#(#default_locales),*],
+                #[cfg(feature = "demonym")]
+                demonym: #demonym,
+                #[cfg(feature = "sovereignty")]
+                dependencies: &[#(#dependencies),*],
+                #[cfg(feature = "localized-names")]
+                localized_names: &[#(#localized_names),*],
+            },
+        ));
+    }
+
+    // The jurisdictions are binary searched by numeric country code
+    let mut by_country_code: Vec<(u16, &str)> = definitions
+        .iter()
+        .map(|def| {
+            let cc =
+                u16::from_str(&def.country_code).expect("country code not representable as u16");
+            (cc, def.alpha2.as_str())
+        })
+        .collect();
+    by_country_code.sort();
+    let by_country_code = by_country_code.iter().map(|(cc, alpha2)| {
+        let alpha2 = Ident::new(alpha2, Span::call_site());
+        quote!((#cc, Alpha2::#alpha2))
+    });
 
-    quote!(
-        use serde::{Deserialize, Serialize};
+    let array_size = definitions.len();
+    let strings = strings.into_literal();
+    let columns = columns.into_statics();
 
-        #alpha2
+    // Order the jurisdictions by name ahead of time, as `Jurisdiction::cmp_by_name` does
+    let mut by_name: Vec<usize> = (0..definitions.len()).collect();
+    by_name.sort_by(|a, b| {
+        let (a, b) = (&definitions[*a].name, &definitions[*b].name);
+        fold_ascii(a).cmp(&fold_ascii(b)).then_with(|| a.cmp(b))
+    });
+    let by_name = by_name
+        .iter()
+        .map(|index| quote!(crate::Jurisdiction::from_definition(&GENERATED_DEFINITIONS[#index])));
+    let by_index = by_index
+        .iter()
+        .map(|index| quote!(crate::Jurisdiction::from_definition(&GENERATED_DEFINITIONS[#index])));
+    quote!(
+        #[cfg(feature = "region")]
+        use crate::region::{Region, SubRegion, IntermediateRegion};
+        #[cfg(feature = "continent")]
+        use crate::continent::Continent;
+        #[cfg(feature = "conventions")]
+        use crate::conventions::{EmergencyNumbers, MeasurementSystem, PaperSize, Weekday};
+        #[cfg(feature = "economics")]
+        use crate::economics::IncomeGroup;
+        #[cfg(feature = "legal")]
+        use crate::legal::LegalSystem;
+        #[cfg(feature = "political")]
+        use crate::gdpr::AdequacyStatus;
+        #[cfg(feature = "political")]
+        use crate::un::UnRegionalGroup;
+        #[cfg(feature = "postal")]
+        use crate::postal::PostalFormat;
+        #[cfg(feature = "power")]
+        use crate::power::PlugType;
+        #[cfg(feature = "localized-names")]
+        #[allow(unused_imports)]
+        use crate::localized::Language;
+        use crate::alpha::{Alpha2, Alpha3};
+        use crate::definition::{Definition, TableStr};
+        #[cfg(feature = "sovereignty")]
+        use crate::territory::TerritoryKind;
+        #[cfg(feature = "sovereignty")]
+        use crate::Jurisdiction;
 
-        #alpha3
-    )
-}
+        /// The strings of the definition table, concatenated, see `TableStr`.
+        pub static GENERATED_STRINGS: &str = #strings;
 
-fn generate_alpha2(definitions: &[CountryRegionDefinition]) -> TokenStream {
-    // Generate enum body
-    let mut enum_body = TokenStream::new();
-    for def in definitions.iter() {
-        let a = Ident::new(&def.alpha2, Span::call_site());
-        enum_body.extend(quote!(
-            #a,
-        ));
-    }
+        /// The number of definitions. The [Alpha2] discriminant of each definition is its
+        /// index within the definition table, as both are generated in the same order.
+        pub const DEFINITION_COUNT: usize = #array_size;
 
-    // Generate From impl body
-    let mut from_match_body = TokenStream::new();
-    for def in definitions.iter() {
-        let a = Ident::new(&def.alpha2, Span::call_site());
-        let cc = u16::from_str(&def.country_code).expect("country code not representable as u16");
-        from_match_body.extend(quote!(
-            Alpha2::#a => #cc,
-        ));
-    }
+        pub static GENERATED_DEFINITIONS: [Definition; #array_size] = [
+            #tokendefs
+        ];
 
-    quote!(
-        /// Two alpha character ISO 3166 country code classification.
-        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-        #[allow(missing_docs)]
-        pub enum Alpha2 {
-            #enum_body
-        }
+        #columns
 
-        impl From<Alpha2> for crate::Jurisdiction {
-            fn from(alpha: Alpha2) -> Self {
-                let country_code = match alpha {
-                    #from_match_body
-                };
+        /// The numeric country code and alpha2 code of each definition, ordered by numeric
+        /// country code.
+        pub static GENERATED_BY_COUNTRY_CODE: [(u16, Alpha2); #array_size] = [
+            #(#by_country_code),*
+        ];
 
-                crate::Jurisdiction::new(country_code)
+        #[cfg(test)]
+        #[test]
+        fn test_country_codes_sorted() {
+            assert!(GENERATED_BY_COUNTRY_CODE.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            for (country_code, alpha2) in GENERATED_BY_COUNTRY_CODE.iter() {
+                assert_eq!(GENERATED_COUNTRY_CODES[*alpha2 as usize], *country_code);
             }
         }
 
-        impl std::fmt::Display for Alpha2 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(
-                    f,
-                    "{}",
-                    serde_plain::to_string(&self).map_err(|_| std::fmt::Error)?
-                )
+        /// The jurisdictions of the definition table, ordered by name.
+        pub static GENERATED_ALL_BY_NAME: [crate::Jurisdiction; #array_size] = [
+            #(#by_name),*
+        ];
+
+        /// The jurisdictions of the definition table, ordered by their index, that is by
+        /// alpha2 code.
+        pub static GENERATED_BY_INDEX: [crate::Jurisdiction; #array_size] = [
+            #(#by_index),*
+        ];
+
+        #[cfg(test)]
+        #[test]
+        fn test_numeric_exhaustive() {
+            for definition in GENERATED_DEFINITIONS.iter() {
+                let numeric = definition.numeric.get();
+                assert_eq!(numeric.len(), 3, "{:?}", definition.alpha2);
+                assert_eq!(numeric.parse(), Ok(definition.country_code()));
             }
         }
     )
 }
 
-fn generate_alpha3(definitions: &[CountryRegionDefinition]) -> TokenStream {
-    // Generate enum body
-    let mut enum_body = TokenStream::new();
-    for def in definitions.iter() {
-        let a = Ident::new(&def.alpha3, Span::call_site());
-        enum_body.extend(quote!(
-            #a,
+fn generate_dataset(
+    supplemental: &SupplementalData,
+    vat: &VatDataset,
+    m49_revision: &str,
+) -> TokenStream {
+    let datasets: &[(&str, Option<&str>, &str, Option<&str>)] = &[
+        (
+            "aviation",
+            Some("aviation"),
+            &supplemental.aviation.source,
+            None,
+        ),
+        (
+            "banking",
+            Some("banking"),
+            &supplemental.banking.source,
+            None,
+        ),
+        (
+            "cldr-containment",
+            Some("cldr-containment"),
+            &supplemental.cldr_containment.source,
+            None,
+        ),
+        (
+            "continent",
+            Some("continent"),
+            &supplemental.continent.source,
+            None,
+        ),
+        ("country-region", None, M49_SOURCE, Some(m49_revision)),
+        (
+            "demonym",
+            Some("demonym"),
+            &supplemental.demonyms.source,
+            None,
+        ),
+        (
+            "emergency",
+            Some("conventions"),
+            &supplemental.emergency.source,
+            None,
+        ),
+        (
+            "eu-adequacy",
+            Some("political"),
+            &supplemental.eu_adequacy.source,
+            Some(&supplemental.eu_adequacy.as_of),
+        ),
+        ("formal-name", None, &supplemental.formal_names.source, None),
+        ("gs1", Some("gs1"), &supplemental.gs1.source, None),
+        (
+            "income-group",
+            Some("economics"),
+            &supplemental.income_group.source,
+            Some(&supplemental.income_group.fiscal_year),
+        ),
+        ("ip", Some("ip"), &supplemental.ip.source, None),
+        (
+            "legal-system",
+            Some("legal"),
+            &supplemental.legal_system.source,
+            None,
+        ),
+        ("locale", Some("locale"), &supplemental.locale.source, None),
+        (
+            "measurement",
+            Some("conventions"),
+            &supplemental.measurement.source,
+            None,
+        ),
+        ("radio", Some("radio"), &supplemental.radio.source, None),
+        ("rail", Some("rail"), &supplemental.rail.source, None),
+        (
+            "sovereignty",
+            Some("sovereignty"),
+            &supplemental.sovereignty.source,
+            None,
+        ),
+        (
+            "statistics",
+            Some("statistics"),
+            &supplemental.statistics.source,
+            Some(&supplemental.statistics.year),
+        ),
+        (
+            "telephony",
+            Some("telephony"),
+            &supplemental.telephony.source,
+            None,
+        ),
+        (
+            "territory-kind",
+            Some("sovereignty"),
+            &supplemental.territory_kinds.source,
+            None,
+        ),
+        (
+            "un-regional-group",
+            Some("political"),
+            &supplemental.un_regional_groups.source,
+            Some(&supplemental.un_regional_groups.as_of),
+        ),
+        ("vat", Some("vat"), &vat.source, None),
+        ("week", Some("conventions"), &supplemental.week.source, None),
+    ];
+
+    let mut tokendefs = TokenStream::new();
+    for (name, feature, source, version) in datasets.iter() {
+        let cfg = match feature {
+            Some(feature) => quote!(#[cfg(feature = #feature)]),
+            None => TokenStream::new(),
+        };
+        let version = match version {
+            Some(version) => quote!(Some(#version)),
+            None => quote!(None),
+        };
+        tokendefs.extend(quote!(
+            #cfg
+            DatasetInfo {
+                name: #name,
+                source: #source,
+                version: #version,
+            },
         ));
     }
 
-    // Generate From impl body
-    let mut from_match_body = TokenStream::new();
-    for def in definitions.iter() {
-        let a = Ident::new(&def.alpha3, Span::call_site());
-        let cc = u16::from_str(&def.country_code).expect("country code not representable as u16");
-        from_match_body.extend(quote!(
-            Alpha3::#a => #cc,
+    quote!(
+        use crate::dataset::DatasetInfo;
+
+        pub static GENERATED_DATASETS: &[DatasetInfo] = &[
+            #tokendefs
+        ];
+    )
+}
+
+fn generate_subdivision(
+    definitions: &[CountryRegionDefinition],
+    subdivisions: &[CountrySubdivisionDefinition],
+) -> TokenStream {
+    // The table is binary searched by code, so it must be emitted in sorted order
+    let mut sorted: Vec<&CountrySubdivisionDefinition> = subdivisions.iter().collect();
+    sorted.sort_by(|a, b| a.code.cmp(&b.code));
+
+    let mut tokendefs = TokenStream::new();
+    for sub in sorted.iter() {
+        let code = &sub.code;
+        let name = &sub.name;
+        let kind = &sub.kind;
+        let prefix = code
+            .split('-')
+            .next()
+            .expect("subdivision code without country prefix");
+        // The owning country is identified by the alpha2 prefix of each subdivision code
+        assert!(
+            definitions.iter().any(|def| def.alpha2 == prefix),
+            "subdivision {} has no matching country",
+            code
+        );
+        let alpha2 = Ident::new(prefix, Span::call_site());
+
+        tokendefs.extend(quote!(
+            SubdivisionDefinition {
+                code: #code,
+                name: #name,
+                kind: #kind,
+                alpha2: Alpha2::#alpha2,
+            },
         ));
     }
 
+    let array_size = sorted.len();
     quote!(
-        /// Three alpha character ISO 3166 country code classification.
-        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
-        #[allow(missing_docs)]
-        pub enum Alpha3 {
-            #enum_body
-        }
+        use crate::alpha::Alpha2;
+        use crate::definition::SubdivisionDefinition;
 
-        impl From<Alpha3> for crate::Jurisdiction {
-            fn from(alpha: Alpha3) -> Self {
-                let country_code = match alpha {
-                    #from_match_body
-                };
+        pub static GENERATED_SUBDIVISIONS: [SubdivisionDefinition; #array_size] = [
+            #tokendefs
+        ];
+    )
+}
 
-                crate::Jurisdiction::new(country_code)
-            }
-        }
+fn generate_sets(
+    political: &PoliticalDataset,
+    un_regional_groups: &UnRegionalGroupDataset,
+) -> TokenStream {
+    let mut tokendefs = TokenStream::new();
+    for grouping in political.groupings.iter() {
+        let name = Ident::new(&grouping.name, Span::call_site());
+        let description = &grouping.description;
+        let members = political
+            .members(grouping)
+            .into_iter()
+            .map(|alpha2| Ident::new(&alpha2, Span::call_site()));
+        tokendefs.extend(quote!(
+            #[doc = #description]
+            pub const #name: JurisdictionSet = JurisdictionSet::from_alpha2(&[
+                #(Alpha2::#members),*
+            ]);
+        ));
+    }
+    for group in un_regional_groups.groups.iter() {
+        let name = Ident::new(&group.set, Span::call_site());
+        let description = &group.description;
+        let members = group
+            .members
+            .iter()
+            .map(|alpha2| Ident::new(alpha2, Span::call_site()));
+        tokendefs.extend(quote!(
+            #[doc = #description]
+            pub const #name: JurisdictionSet = JurisdictionSet::from_alpha2(&[
+                #(Alpha2::#members),*
+            ]);
+        ));
+    }
 
-        impl std::fmt::Display for Alpha3 {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(
-                    f,
-                    "{}",
-                    serde_plain::to_string(&self).map_err(|_| std::fmt::Error)?
-                )
-            }
-        }
+    quote!(
+        use crate::alpha::Alpha2;
+        use crate::JurisdictionSet;
+
+        #tokendefs
     )
 }
 
-fn generate_region(definitions: &[CountryRegionDefinition]) -> TokenStream {
-    // Gather all country codes for each region
-    let mut regions: HashMap<&Region, Vec<u16>> = HashMap::new();
-    let mut subs: HashMap<&SubRegion, Vec<u16>> = HashMap::new();
-    let mut intermediates: HashMap<&IntermediateRegion, Vec<u16>> = HashMap::new();
-    for def in definitions.iter() {
-        let cc = u16::from_str(&def.country_code).expect("country code not representable as u16");
-        regions.entry(&def.region).or_default().push(cc);
-        subs.entry(&def.sub_region).or_default().push(cc);
-        intermediates
-            .entry(&def.intermediate_region)
-            .or_default()
-            .push(cc);
+fn generate_gs1(definitions: &[CountryRegionDefinition], gs1: &Gs1Dataset) -> TokenStream {
+    // The table is binary searched by prefix, so it must be emitted in sorted order
+    let mut sorted: Vec<&Gs1PrefixDefinition> = gs1.prefixes.iter().collect();
+    sorted.sort_by_key(|def| def.range.0);
+    for pair in sorted.windows(2) {
+        assert!(
+            pair[0].range.1 < pair[1].range.0,
+            "overlapping GS1 prefix ranges {:?} and {:?}",
+            pair[0].range,
+            pair[1].range
+        );
     }
 
-    // Generate match arms for region
-    let mut region_match: HashMap<Ident, TokenStream> = HashMap::new();
-    for (region, codes) in regions {
-        let i = Ident::new(&format!("{:?}", &region), Span::call_site());
-        let mut t = TokenStream::new();
-        for code in codes {
-            t.extend(quote!( Jurisdiction::new(#code), ));
-        }
-        region_match.insert(i, t);
-    }
+    let mut tokendefs = TokenStream::new();
+    for def in sorted.iter() {
+        let country = definitions
+            .iter()
+            .find(|country| country.alpha2 == def.member_organization)
+            .expect("gs1 prefix refers to unknown jurisdiction");
+        let jurisdiction = Ident::new(&country.alpha2, Span::call_site());
+        let (start, end) = def.range;
 
-    // Generate match arms for sub region
-    let mut sub_match: HashMap<Ident, TokenStream> = HashMap::new();
-    for (sub, codes) in subs {
-        let i = Ident::new(&format!("{:?}", &sub), Span::call_site());
-        let mut t = TokenStream::new();
-        for code in codes {
-            t.extend(quote!( Jurisdiction::new(#code), ));
-        }
-        sub_match.insert(i, t);
+        tokendefs.extend(quote!(
+            (#start..=#end, Alpha2::#jurisdiction),
+        ));
     }
 
-    // Generate match arms for intermediate region
-    let mut intermediate_match: HashMap<Ident, TokenStream> = HashMap::new();
-    for (inter, codes) in intermediates {
-        let i = Ident::new(&format!("{:?}", &inter), Span::call_site());
-        let mut t = TokenStream::new();
-        for code in codes {
-            t.extend(quote!( Jurisdiction::new(#code), ));
-        }
-        intermediate_match.insert(i, t);
+    let array_size = sorted.len();
+    quote!(
+        use crate::alpha::Alpha2;
+        use std::ops::RangeInclusive;
+
+        pub static GENERATED_GS1_PREFIXES: [(RangeInclusive<u16>, Alpha2); #array_size] = [
+            #tokendefs
+        ];
+    )
+}
+
+fn generate_ip(definitions: &[CountryRegionDefinition], ip: &IpDataset) -> TokenStream {
+    let mut entities: Vec<(&String, TokenStream)> = Vec::new();
+    for (alpha2, code) in ip.codes.iter() {
+        let index = definitions
+            .iter()
+            .position(|def| &def.alpha2 == alpha2)
+            .expect("ST.3 code refers to unknown jurisdiction");
+        entities.push((
+            code,
+            quote!(St3Entity::Jurisdiction(crate::Jurisdiction::from_definition(
+                &crate::generated::definition::GENERATED_DEFINITIONS[#index]
+            ))),
+        ));
+    }
+    for def in ip.organizations.iter() {
+        let value = Ident::new(&def.value, Span::call_site());
+        entities.push((
+            &def.code,
+            quote!(St3Entity::Organization(IpOrganization::#value)),
+        ));
     }
+    // The table is binary searched by code, so it must be emitted in sorted order
+    entities.sort_by(|a, b| a.0.cmp(b.0));
 
-    // Generate region match body
-    let mut region_body = TokenStream::new();
-    for (i, t) in region_match {
-        region_body.extend(quote!(
-            Region::#i => vec![#t],
+    let mut tokendefs = TokenStream::new();
+    for (code, entity) in entities.iter() {
+        tokendefs.extend(quote!(
+            (#code, #entity),
+        ));
+    }
+    let mut organizations = TokenStream::new();
+    for def in ip.organizations.iter() {
+        let value = Ident::new(&def.value, Span::call_site());
+        let code = &def.code;
+        let name = &def.name;
+        organizations.extend(quote!(
+            (IpOrganization::#value, #code, #name),
         ));
     }
 
-    // Generate sub region match body
-    let mut sub_body = TokenStream::new();
-    for (i, t) in sub_match {
-        sub_body.extend(quote!(
-            SubRegion::#i => vec![#t],
+    let array_size = entities.len();
+    let organizations_size = ip.organizations.len();
+    quote!(
+        use crate::ip::{IpOrganization, St3Entity};
+
+        /// The entity of each WIPO ST.3 code, ordered by code.
+        pub static GENERATED_ST3_CODES: [(&str, St3Entity); #array_size] = [
+            #tokendefs
+        ];
+
+        /// The ST.3 code and name of each intergovernmental organization.
+        pub static GENERATED_ST3_ORGANIZATIONS: [(IpOrganization, &str, &str); #organizations_size] = [
+            #organizations
+        ];
+    )
+}
+
+fn generate_aviation(
+    definitions: &[CountryRegionDefinition],
+    aviation: &AviationDataset,
+) -> TokenStream {
+    let mut prefixes: Vec<(&String, &String)> = aviation
+        .prefixes
+        .iter()
+        .flat_map(|(alpha2, prefixes)| prefixes.iter().map(move |prefix| (prefix, alpha2)))
+        .collect();
+    // The table is matched in order, so the longest prefixes must be emitted first
+    prefixes.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+    for pair in prefixes.windows(2) {
+        assert!(
+            pair[0].0 != pair[1].0,
+            "aircraft prefix {:?} assigned to both {} and {}",
+            pair[0].0,
+            pair[0].1,
+            pair[1].1
+        );
+    }
+
+    let mut tokendefs = TokenStream::new();
+    for (prefix, alpha2) in prefixes.iter() {
+        let country = definitions
+            .iter()
+            .find(|country| &&country.alpha2 == alpha2)
+            .expect("aircraft prefix refers to unknown jurisdiction");
+        let jurisdiction = Ident::new(&country.alpha2, Span::call_site());
+
+        tokendefs.extend(quote!(
+            (#prefix, Alpha2::#jurisdiction),
         ));
     }
 
-    // Generate intermediate region match body
-    let mut intermediate_body = TokenStream::new();
-    for (i, t) in intermediate_match {
-        intermediate_body.extend(quote!(
-            IntermediateRegion::#i => vec![#t],
+    let array_size = prefixes.len();
+    quote!(
+        use crate::alpha::Alpha2;
+
+        pub static GENERATED_AIRCRAFT_PREFIXES: [(&str, Alpha2); #array_size] = [
+            #tokendefs
+        ];
+    )
+}
+
+fn generate_radio(definitions: &[CountryRegionDefinition], radio: &RadioDataset) -> TokenStream {
+    for def in radio.series.iter() {
+        let (start, end) = &def.range;
+        assert!(
+            start.len() == end.len() && start <= end,
+            "malformed call sign series {:?}",
+            def.range
+        );
+    }
+    // The table is matched in order, so the longest series must be emitted first
+    let mut sorted: Vec<&RadioSeriesDefinition> = radio.series.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.range
+            .0
+            .len()
+            .cmp(&a.range.0.len())
+            .then_with(|| a.range.0.cmp(&b.range.0))
+    });
+    for pair in sorted.windows(2) {
+        assert!(
+            pair[0].range.0.len() != pair[1].range.0.len() || pair[0].range.1 < pair[1].range.0,
+            "overlapping call sign series {:?} and {:?}",
+            pair[0].range,
+            pair[1].range
+        );
+    }
+
+    let mut tokendefs = TokenStream::new();
+    for def in sorted.iter() {
+        let country = definitions
+            .iter()
+            .find(|country| country.alpha2 == def.jurisdiction)
+            .expect("call sign series refers to unknown jurisdiction");
+        let jurisdiction = Ident::new(&country.alpha2, Span::call_site());
+        let (start, end) = &def.range;
+
+        tokendefs.extend(quote!(
+            (#start..=#end, Alpha2::#jurisdiction),
         ));
     }
 
+    let array_size = sorted.len();
     quote!(
-        use crate::region::{Region, SubRegion, IntermediateRegion};
-        use crate::Jurisdiction;
+        use crate::alpha::Alpha2;
+        use std::ops::RangeInclusive;
 
-        impl Region {
-            #[allow(clippy::trivially_copy_pass_by_ref)]
-            pub(crate) fn jurisdictions(&self) -> Vec<Jurisdiction> {
-                match *self {
-                    #region_body
-                }
-            }
-        }
+        pub static GENERATED_CALLSIGN_SERIES: [(RangeInclusive<&str>, Alpha2); #array_size] = [
+            #tokendefs
+        ];
+    )
+}
 
-        impl SubRegion {
-            #[allow(clippy::trivially_copy_pass_by_ref)]
-            pub(crate) fn jurisdictions(&self) -> Vec<Jurisdiction> {
-                match *self {
-                    #sub_body
-                }
-            }
-        }
+fn generate_cldr(
+    definitions: &[CountryRegionDefinition],
+    cldr: &CldrContainmentDataset,
+) -> TokenStream {
+    let territories: BTreeSet<&str> = cldr
+        .containment
+        .keys()
+        .chain(cldr.groupings.keys())
+        .map(String::as_str)
+        .collect();
 
-        impl IntermediateRegion {
-            #[allow(clippy::trivially_copy_pass_by_ref)]
-            pub(crate) fn jurisdictions(&self) -> Vec<Jurisdiction> {
-                match *self {
-                    #intermediate_body
-                }
+    // The table is binary searched by code, so it must be emitted in sorted order
+    let mut tokendefs = TokenStream::new();
+    for territory in territories.iter() {
+        let children = cldr.children(territory);
+
+        // Collect the jurisdictions beneath the territory, through any of its territories
+        let mut contained = BTreeSet::new();
+        let mut pending = children.clone();
+        while let Some(child) = pending.pop() {
+            if cldr.is_container(child) {
+                pending.extend(cldr.children(child));
+            } else {
+                let index = definitions
+                    .iter()
+                    .position(|def| def.alpha2 == child)
+                    .expect("CLDR territory refers to unknown jurisdiction");
+                contained.insert(index);
             }
         }
+        let contained = contained.iter().map(|index| {
+            quote!(crate::Jurisdiction::from_definition(
+                &crate::generated::definition::GENERATED_DEFINITIONS[#index]
+            ))
+        });
+
+        tokendefs.extend(quote!(
+            (#territory, &[#(#children),*], &[#(#contained),*]),
+        ));
+    }
+
+    let array_size = territories.len();
+    quote!(
+        /// The CLDR territories containing others, along with the territories they directly
+        /// contain and the jurisdictions they contain through any of them, ordered by code.
+        pub static GENERATED_CLDR_TERRITORIES: [(&str, &[&str], &[crate::Jurisdiction]); #array_size] = [
+            #tokendefs
+        ];
     )
 }
 
-fn generate_definition(definitions: &[CountryRegionDefinition]) -> TokenStream {
-    let mut tokendefs = TokenStream::new();
-    for def in definitions.iter() {
-        let name = &def.name;
-        let cc = u16::from_str(&def.country_code).expect("country code not representable as u16");
-        let alpha2 = Ident::new(&def.alpha2, Span::call_site());
-        let alpha3 = Ident::new(&def.alpha3, Span::call_site());
-        let region = Ident::new(&format!("{:?}", &def.region), Span::call_site());
-        let sub_region = Ident::new(&format!("{:?}", &def.sub_region), Span::call_site());
-        let intermediate_region = Ident::new(
-            &format!("{:?}", &def.intermediate_region),
-            Span::call_site(),
+fn generate_telephony(
+    definitions: &[CountryRegionDefinition],
+    telephony: &TelephonyDataset,
+) -> TokenStream {
+    // The table is binary searched by code, so it must be emitted in sorted order
+    let mut sorted: Vec<&MobileCountryCodeDefinition> = telephony.codes.iter().collect();
+    sorted.sort_by_key(|def| def.mcc);
+    for pair in sorted.windows(2) {
+        assert!(
+            pair[0].mcc < pair[1].mcc,
+            "mobile country code {} defined twice",
+            pair[0].mcc
         );
+    }
 
-        let rc = u16::from_str(&def.region_code).unwrap_or(0);
-        let sc = u16::from_str(&def.sub_region_code).unwrap_or(0);
-        let irc = u16::from_str(&def.intermediate_region_code).unwrap_or(0);
+    let mut tokendefs = TokenStream::new();
+    for def in sorted.iter() {
+        let primary = def
+            .jurisdictions
+            .first()
+            .expect("mobile country code without jurisdictions");
+        let country = definitions
+            .iter()
+            .find(|country| &country.alpha2 == primary)
+            .expect("mobile country code refers to unknown jurisdiction");
+        let jurisdiction = Ident::new(&country.alpha2, Span::call_site());
+        let mcc = def.mcc;
 
-        let irc: TokenStream = match irc {
-            0 => proc_macro2::TokenTree::from(Ident::new("None", Span::call_site())).into(),
-            _ => quote!(Some(#irc)),
-        };
+        tokendefs.extend(quote!(
+            (#mcc, Alpha2::#jurisdiction),
+        ));
+    }
+
+    let array_size = sorted.len();
+    quote!(
+        use crate::alpha::Alpha2;
+
+        pub static GENERATED_MOBILE_COUNTRY_CODES: [(u16, Alpha2); #array_size] = [
+            #tokendefs
+        ];
+    )
+}
+
+fn generate_vat(definitions: &[CountryRegionDefinition], vat: &VatDataset) -> TokenStream {
+    let keys = vat.prefixes.iter().map(|def| &def.jurisdiction);
+    assert_known_alpha2(definitions, "data/vat.json", keys);
+
+    let mut tokendefs = TokenStream::new();
+    for def in vat.prefixes.iter() {
+        let country = definitions
+            .iter()
+            .find(|country| country.alpha2 == def.jurisdiction)
+            .expect("vat prefix refers to unknown jurisdiction");
+        let jurisdiction = Ident::new(&country.alpha2, Span::call_site());
+        let prefix = &def.prefix;
+        let format = &def.format;
+        let example = &def.example;
+        let primary = def.primary;
 
         tokendefs.extend(quote!(
-            Definition {
-                country_code: #cc,
-                name: #name,
-                alpha2: Alpha2::#alpha2,
-                alpha3: Alpha3::#alpha3,
-                #[cfg(feature = "region")]
-                region: Region::#region,
-                #[cfg(feature = "region")]
-                sub_region: SubRegion::#sub_region,
-                #[cfg(feature = "region")]
-                intermediate_region: IntermediateRegion::#intermediate_region,
-                #[cfg(feature = "region")]
-                region_code: #rc,
-                #[cfg(feature = "region")]
-                sub_region_code: #sc,
-                #[cfg(feature = "region")]
-                intermediate_region_code: #irc,
+            VatFormat {
+                prefix: #prefix,
+                pattern: #format,
+                example: #example,
+                alpha2: Alpha2::#jurisdiction,
+                primary: #primary,
             },
         ));
     }
 
-    let array_size = definitions.len();
+    let array_size = vat.prefixes.len();
     quote!(
-        #[cfg(feature = "region")]
-        use crate::region::{Region, SubRegion, IntermediateRegion};
-        use crate::alpha::{Alpha2, Alpha3};
-        use crate::definition::Definition;
+        use crate::alpha::Alpha2;
+        use crate::vat::VatFormat;
 
-        pub const GENERATED_DEFINITIONS: [Definition; #array_size] = [
+        pub static GENERATED_VAT_FORMATS: [VatFormat; #array_size] = [
             #tokendefs
         ];
     )
@@ -322,9 +2739,30 @@ fn main() -> Result<()> {
 
     // Ensure that output directory exist
 
-    // Parse the country-region JSON definitions
-    let file = File::open("data/country-region.json")?;
+    // Parse the country-region JSON definitions of the selected M49 revision
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=data");
+    println!("cargo:rerun-if-changed=src/region.rs");
+    println!("cargo:rerun-if-env-changed=JURISDICTION_M49_REVISION");
+    println!("cargo:rerun-if-env-changed=JURISDICTION_M49_DIR");
+    // The revisions are looked up in another directory by the tests of the crate only
+    let m49_dir = std::env::var("JURISDICTION_M49_DIR").unwrap_or_else(|_| "data".to_string());
+    println!("cargo:rerun-if-changed={}", m49_dir);
+    let m49_revision = match std::env::var("JURISDICTION_M49_REVISION") {
+        Ok(revision) => revision,
+        Err(_) => latest_m49_revision(&m49_dir)?,
+    };
+    let path = format!("{}/country-region-{}.json", m49_dir, m49_revision);
+    let file = File::open(&path).map_err(|err| {
+        anyhow::anyhow!(
+            "M49 revision {:?} selected by JURISDICTION_M49_REVISION is not available as {}: {}",
+            m49_revision,
+            path,
+            err
+        )
+    })?;
     let definitions: Vec<CountryRegionDefinition> = serde_json::from_reader(file)?;
+    let supplemental = SupplementalData::load(&definitions)?;
 
     // Alpha
     let generated = generate_alpha(&definitions);
@@ -337,9 +2775,63 @@ fn main() -> Result<()> {
     f.write_all(generated.to_string().as_bytes())?;
 
     // Definition
-    let generated = generate_definition(&definitions);
+    let generated = generate_definition(&definitions, &supplemental);
     let mut f = File::create(format!("{}/definition.rs", dir))?;
     f.write_all(generated.to_string().as_bytes())?;
 
+    // Sets
+    let generated = generate_sets(&supplemental.political, &supplemental.un_regional_groups);
+    let mut f = File::create(format!("{}/sets.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // GS1
+    let generated = generate_gs1(&definitions, &supplemental.gs1);
+    let mut f = File::create(format!("{}/gs1.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Intellectual property
+    let generated = generate_ip(&definitions, &supplemental.ip);
+    let mut f = File::create(format!("{}/ip.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Aviation
+    let generated = generate_aviation(&definitions, &supplemental.aviation);
+    let mut f = File::create(format!("{}/aviation.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Radio
+    let generated = generate_radio(&definitions, &supplemental.radio);
+    let mut f = File::create(format!("{}/radio.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // CLDR territory containment
+    let generated = generate_cldr(&definitions, &supplemental.cldr_containment);
+    let mut f = File::create(format!("{}/cldr.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Telephony
+    let generated = generate_telephony(&definitions, &supplemental.telephony);
+    let mut f = File::create(format!("{}/telephony.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Subdivision
+    let file = File::open("data/iso_3166-2.json")?;
+    let dataset: SubdivisionDataset = serde_json::from_reader(file)?;
+    let generated = generate_subdivision(&definitions, &dataset.subdivisions);
+    let mut f = File::create(format!("{}/subdivision.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // VAT
+    let file = File::open("data/vat.json")?;
+    let dataset: VatDataset = serde_json::from_reader(file)?;
+    let generated = generate_vat(&definitions, &dataset);
+    let mut f = File::create(format!("{}/vat.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
+    // Dataset
+    let generated = generate_dataset(&supplemental, &dataset, &m49_revision);
+    let mut f = File::create(format!("{}/dataset.rs", dir))?;
+    f.write_all(generated.to_string().as_bytes())?;
+
     Ok(())
 }
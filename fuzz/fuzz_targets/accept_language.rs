@@ -0,0 +1,20 @@
+//! Parse arbitrary input as an `Accept-Language` header of BCP 47 language ranges.
+
+#![no_main]
+
+use jurisdiction::http;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let header = String::from_utf8_lossy(data);
+
+    let preferred = http::from_accept_language(&header);
+    // Every jurisdiction is preferred once, by a quality value within 0 and 1
+    assert!(preferred.len() <= header.split(',').count());
+    for (i, (jurisdiction, quality)) in preferred.iter().enumerate() {
+        assert!(*quality > 0.0 && *quality <= 1.0);
+        assert!(preferred[..i]
+            .iter()
+            .all(|(other, _)| other != jurisdiction));
+    }
+});
@@ -0,0 +1,39 @@
+//! Parse arbitrary input as an alpha code, numeric country code or region path.
+
+#![no_main]
+
+use jurisdiction::region::RegionPath;
+use jurisdiction::{Alpha2, Alpha3, Jurisdiction};
+use libfuzzer_sys::fuzz_target;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    let s = match std::str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    if let Ok(jurisdiction) = Jurisdiction::from_str(s) {
+        // Codes parse in any case, and display in upper case
+        assert!(
+            jurisdiction.alpha2().to_string().eq_ignore_ascii_case(s)
+                || jurisdiction.alpha3().to_string().eq_ignore_ascii_case(s)
+        );
+    }
+    if let Ok(alpha2) = serde_plain::from_str::<Alpha2>(s) {
+        assert_eq!(
+            serde_plain::from_str::<Alpha2>(&alpha2.to_string()).unwrap(),
+            alpha2
+        );
+    }
+    let _ = serde_plain::from_str::<Alpha3>(s);
+    if let Ok(country_code) = i64::from_str(s) {
+        let _ = Jurisdiction::try_from(country_code);
+    }
+    if let Ok(path) = RegionPath::from_str(s) {
+        assert_eq!(RegionPath::from_str(&path.to_string()), Ok(path));
+    }
+    let _ = jurisdiction::reserved::parse(s);
+});
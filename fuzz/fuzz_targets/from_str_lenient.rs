@@ -0,0 +1,27 @@
+//! Look up arbitrary input leniently, as an english name or subdivision code in any case.
+
+#![no_main]
+
+use jurisdiction::subdivision::Subdivision;
+use jurisdiction::Jurisdiction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let s = match std::str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    if let Some(jurisdiction) = Jurisdiction::from_name(s) {
+        assert_eq!(
+            Jurisdiction::from_name(jurisdiction.name()),
+            Some(jurisdiction)
+        );
+    }
+    for jurisdiction in Jurisdiction::find_by_name_prefix(s) {
+        assert!(!jurisdiction.name().is_empty());
+    }
+    if let Ok(subdivision) = Subdivision::from_str_lenient(s) {
+        assert!(subdivision.code().eq_ignore_ascii_case(s.trim()));
+    }
+});
@@ -0,0 +1,20 @@
+//! Parse arbitrary input as an ISO 3166-2 subdivision code.
+
+#![no_main]
+
+use jurisdiction::subdivision::Subdivision;
+use libfuzzer_sys::fuzz_target;
+
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    let s = match std::str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    if let Ok(subdivision) = Subdivision::from_str(s) {
+        assert_eq!(subdivision.code(), s);
+        assert!(s.starts_with(subdivision.jurisdiction().iso3166_2_prefix()));
+    }
+});
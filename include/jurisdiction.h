@@ -0,0 +1,105 @@
+#ifndef JURISDICTION_H
+#define JURISDICTION_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The status returned by the functions of the interface.
+ */
+typedef enum JurisdictionStatus {
+  /**
+   * The function succeeded.
+   */
+  JURISDICTION_STATUS_OK = 0,
+  /**
+   * A pointer argument was null.
+   */
+  JURISDICTION_STATUS_NULL_POINTER = 1,
+  /**
+   * The code was not valid UTF-8.
+   */
+  JURISDICTION_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The code was not an alpha2 or alpha3 code of a jurisdiction.
+   */
+  JURISDICTION_STATUS_UNRECOGNIZED_CODE = 3,
+} JurisdictionStatus;
+
+typedef struct JurisdictionDefinition JurisdictionDefinition;
+
+/**
+ * A pointer sized object encoding countries and areas of the world.
+ *
+ * The size of this structure is minimized such that passing it around will be limited
+ * overhead, with implemented methods performing lookup in static table instead.
+ *
+ * # Layout
+ *
+ * A `Jurisdiction` is `#[repr(transparent)]` over a reference into the static definition
+ * table, guaranteeing the size and alignment of a pointer. As the reference is never
+ * null, `Option<Jurisdiction>` is pointer sized as well, with `None` represented by null.
+ * Both guarantees are part of the public contract, and are asserted at compile time.
+ *
+ * The pointer itself is opaque: it may be stored and passed along, e.g. as an FFI
+ * handle, but its pointee is private and its value may differ between builds. Transmuting
+ * other values into a `Jurisdiction` is not supported.
+ */
+typedef const struct JurisdictionDefinition *Jurisdiction;
+
+/**
+ * Parse the NUL terminated alpha2 or alpha3 `code` into the jurisdiction pointed to by
+ * `out`, returning a [JurisdictionStatus](enum.JurisdictionStatus.html).
+ *
+ * `out` is left untouched unless the status is `Ok`.
+ *
+ * # Safety
+ *
+ * `code` must be null or point to a NUL terminated string, and `out` must be null or
+ * be valid for writes.
+ */
+int jurisdiction_from_alpha(const char *code, Jurisdiction *out);
+
+/**
+ * Write the NUL terminated alpha2 code of `jurisdiction` into the three characters
+ * pointed to by `out`, returning a [JurisdictionStatus](enum.JurisdictionStatus.html).
+ *
+ * # Safety
+ *
+ * `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha], and
+ * `out` must be null or be valid for writes of three characters.
+ *
+ * [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+ */
+int jurisdiction_alpha2(Jurisdiction jurisdiction, char *out);
+
+/**
+ * Return the ISO 3166 numeric country code of `jurisdiction`, or 0 if it is null.
+ *
+ * # Safety
+ *
+ * `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha].
+ *
+ * [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+ */
+uint16_t jurisdiction_country_code(Jurisdiction jurisdiction);
+
+/**
+ * Return the NUL terminated common english name of `jurisdiction`, or null if it is null.
+ *
+ * The name is static, and must not be freed.
+ *
+ * # Safety
+ *
+ * `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha].
+ *
+ * [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+ */
+const char *jurisdiction_name(Jurisdiction jurisdiction);
+
+#endif  /* JURISDICTION_H */
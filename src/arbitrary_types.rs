@@ -0,0 +1,231 @@
+//! [arbitrary] support.
+//!
+//! Values are chosen uniformly from the valid values of each type, consuming a single
+//! byte of the `Unstructured` data. No input constructs an invalid value, and exhausted
+//! data chooses the first value, e.g. `Alpha2::AF` for the first jurisdiction of ISO 3166-1.
+//!
+//! [arbitrary]: https://docs.rs/arbitrary
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::generated::definition::GENERATED_DEFINITIONS;
+#[cfg(feature = "region")]
+use crate::region::{IntermediateRegion, Region, SubRegion};
+use crate::Jurisdiction;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Choose an index below `len` from the data of `u`.
+fn choose_index(u: &mut Unstructured<'_>, len: usize) -> Result<usize> {
+    u.int_in_range(0..=len - 1)
+}
+
+/// Return the size hint of choosing an index below `len`, being the bytes of `len - 1`.
+fn index_size_hint(len: usize) -> (usize, Option<usize>) {
+    let bits = (usize::BITS - (len - 1).leading_zeros()) as usize;
+    let bytes = bits.div_ceil(8);
+    (bytes, Some(bytes))
+}
+
+impl<'a> Arbitrary<'a> for Jurisdiction {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Jurisdiction::from_position(choose_index(
+            u,
+            GENERATED_DEFINITIONS.len(),
+        )?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        index_size_hint(GENERATED_DEFINITIONS.len())
+    }
+}
+
+impl<'a> Arbitrary<'a> for Alpha2 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Jurisdiction::arbitrary(u).map(|jurisdiction| jurisdiction.alpha2())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Jurisdiction::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Alpha3 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Jurisdiction::arbitrary(u).map(|jurisdiction| jurisdiction.alpha3())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Jurisdiction::size_hint(depth)
+    }
+}
+
+/// Every variant of [Region], in the order of declaration.
+#[cfg(feature = "region")]
+const REGIONS: [Region; 6] = [
+    Region::Africa,
+    Region::Asia,
+    Region::Europe,
+    Region::Oceania,
+    Region::Americas,
+    Region::Undefined,
+];
+
+/// Every variant of [SubRegion], in the order of declaration.
+#[cfg(feature = "region")]
+const SUB_REGIONS: [SubRegion; 18] = [
+    SubRegion::NorthernAfrica,
+    SubRegion::SubSaharanAfrica,
+    SubRegion::EasternAsia,
+    SubRegion::SouthernAsia,
+    SubRegion::SouthEasternAsia,
+    SubRegion::WesternAsia,
+    SubRegion::CentralAsia,
+    SubRegion::NorthernAmerica,
+    SubRegion::LatinAmericaAndTheCaribbean,
+    SubRegion::NorthernEurope,
+    SubRegion::EasternEurope,
+    SubRegion::SouthernEurope,
+    SubRegion::WesternEurope,
+    SubRegion::Polynesia,
+    SubRegion::Melanesia,
+    SubRegion::Micronesia,
+    SubRegion::AustraliaAndNewZealand,
+    SubRegion::Undefined,
+];
+
+/// Every variant of [IntermediateRegion], in the order of declaration.
+#[cfg(feature = "region")]
+const INTERMEDIATE_REGIONS: [IntermediateRegion; 9] = [
+    IntermediateRegion::EasternAfrica,
+    IntermediateRegion::MiddleAfrica,
+    IntermediateRegion::SouthernAfrica,
+    IntermediateRegion::WesternAfrica,
+    IntermediateRegion::Caribbean,
+    IntermediateRegion::CentralAmerica,
+    IntermediateRegion::SouthAmerica,
+    IntermediateRegion::ChannelIslands,
+    IntermediateRegion::Undefined,
+];
+
+#[cfg(feature = "region")]
+impl<'a> Arbitrary<'a> for Region {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(REGIONS[choose_index(u, REGIONS.len())?])
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        index_size_hint(REGIONS.len())
+    }
+}
+
+#[cfg(feature = "region")]
+impl<'a> Arbitrary<'a> for SubRegion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SUB_REGIONS[choose_index(u, SUB_REGIONS.len())?])
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        index_size_hint(SUB_REGIONS.len())
+    }
+}
+
+#[cfg(feature = "region")]
+impl<'a> Arbitrary<'a> for IntermediateRegion {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(INTERMEDIATE_REGIONS[choose_index(u, INTERMEDIATE_REGIONS.len())?])
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        index_size_hint(INTERMEDIATE_REGIONS.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::str::FromStr;
+
+    /// Return byte strings of random content and length, including the empty string.
+    fn inputs() -> impl Iterator<Item = Vec<u8>> {
+        let mut rng = StdRng::seed_from_u64(3166);
+        (0..2_000).map(move |_| {
+            let len = rng.gen_range(0..16);
+            (0..len).map(|_| rng.gen()).collect()
+        })
+    }
+
+    #[test]
+    fn test_arbitrary_valid() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let jurisdiction = Jurisdiction::arbitrary(&mut u).unwrap();
+            assert!(jurisdiction.index() < GENERATED_DEFINITIONS.len());
+            assert_eq!(Jurisdiction::from(jurisdiction.alpha2()), jurisdiction);
+            assert_eq!(Jurisdiction::from(jurisdiction.alpha3()), jurisdiction);
+            assert_eq!(
+                Jurisdiction::from_str(&jurisdiction.alpha2().to_string()).unwrap(),
+                jurisdiction
+            );
+
+            let alpha2 = Alpha2::arbitrary(&mut u).unwrap();
+            assert_eq!(Jurisdiction::from(alpha2).alpha2(), alpha2);
+            let alpha3 = Alpha3::arbitrary(&mut u).unwrap();
+            assert_eq!(Jurisdiction::from(alpha3).alpha3(), alpha3);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_uniform() {
+        let mut seen = vec![0usize; GENERATED_DEFINITIONS.len()];
+        for byte in 0..=u8::MAX {
+            let input = [byte];
+            let mut u = Unstructured::new(&input);
+            let jurisdiction = Jurisdiction::arbitrary(&mut u).unwrap();
+            assert!(u.is_empty());
+            seen[jurisdiction.index()] += 1;
+        }
+        // Every jurisdiction is reachable, some by two of the 256 bytes
+        assert!(
+            seen.iter().all(|&count| count == 1 || count == 2),
+            "{:?}",
+            seen
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_size_hint() {
+        assert_eq!(Jurisdiction::size_hint(0), (1, Some(1)));
+        assert_eq!(Alpha3::size_hint(0), (1, Some(1)));
+        assert_eq!(index_size_hint(1), (0, Some(0)));
+        assert_eq!(index_size_hint(256), (1, Some(1)));
+        assert_eq!(index_size_hint(257), (2, Some(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_arbitrary_region_variants() {
+        // Strictly ordered variants ending with the last declared variant are exhaustive
+        assert!(REGIONS.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(REGIONS.len(), Region::Undefined as usize + 1);
+        assert!(SUB_REGIONS.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(SUB_REGIONS.len(), SubRegion::Undefined as usize + 1);
+        assert!(INTERMEDIATE_REGIONS.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(
+            INTERMEDIATE_REGIONS.len(),
+            IntermediateRegion::Undefined as usize + 1
+        );
+
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let region = Region::arbitrary(&mut u).unwrap();
+            assert!(REGIONS.contains(&region));
+            let sub_region = SubRegion::arbitrary(&mut u).unwrap();
+            assert!(SUB_REGIONS.contains(&sub_region));
+            let intermediate = IntermediateRegion::arbitrary(&mut u).unwrap();
+            assert!(INTERMEDIATE_REGIONS.contains(&intermediate));
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! [Apache Arrow] conversions.
+//!
+//! Columns of jurisdictions are materialized either as their alpha2 codes, in a
+//! `StringArray` or a `DictionaryArray`, or as their numeric country codes in a
+//! `UInt16Array`. The dictionary of a `DictionaryArray` holds the alpha2 codes of all
+//! jurisdictions in alphabetical order, such that its keys coincide with
+//! [Jurisdiction::index].
+//!
+//! [try_from_array] reads back any of these arrays, whether made by this module or
+//! otherwise, accepting alpha3 codes as well.
+//!
+//! ```rust
+//! use jurisdiction::{arrow, Alpha2, Jurisdiction};
+//!
+//! let jurisdictions = [Jurisdiction::from(Alpha2::NO), Jurisdiction::from(Alpha2::SE)];
+//! let array = arrow::to_dictionary_array(&jurisdictions);
+//! assert_eq!(arrow::try_from_array(&array).unwrap(), jurisdictions);
+//! ```
+//!
+//! [Apache Arrow]: https://docs.rs/arrow
+//! [Jurisdiction::index]: ../struct.Jurisdiction.html#method.index
+//! [try_from_array]: fn.try_from_array.html
+
+use crate::Jurisdiction;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{UInt16Type, UInt8Type};
+use arrow_array::{Array, ArrayRef, DictionaryArray, StringArray, UInt16Array, UInt8Array};
+use arrow_schema::DataType;
+use lazy_static::lazy_static;
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+lazy_static! {
+    /// The alpha2 codes of all jurisdictions, in the order of their index.
+    static ref DICTIONARY: ArrayRef = Arc::new(StringArray::from_iter_values(
+        (0..)
+            .map_while(Jurisdiction::from_index)
+            .map(|jurisdiction| jurisdiction.alpha2().to_string())
+    ));
+}
+
+/// Error returned when an array does not hold jurisdictions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArrayError {
+    /// The array is neither of strings, of a dictionary of strings, nor of `u16`.
+    UnsupportedType(String),
+    /// The value at the row is null.
+    Null {
+        /// The index of the row.
+        row: usize,
+    },
+    /// The value at the row is not an alpha2, alpha3 or numeric country code of a
+    /// jurisdiction.
+    Unrecognized {
+        /// The index of the row.
+        row: usize,
+        /// The value of the row.
+        value: String,
+    },
+}
+
+impl ArrayError {
+    /// Return the index of the first invalid row, unless the whole array is unsupported.
+    pub fn row(&self) -> Option<usize> {
+        match self {
+            ArrayError::UnsupportedType(_) => None,
+            ArrayError::Null { row } | ArrayError::Unrecognized { row, .. } => Some(*row),
+        }
+    }
+}
+
+impl fmt::Display for ArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayError::UnsupportedType(data_type) => {
+                write!(f, "unsupported array of jurisdictions: {}", data_type)
+            }
+            ArrayError::Null { row } => write!(f, "null jurisdiction at row {}", row),
+            ArrayError::Unrecognized { row, value } => {
+                write!(f, "unrecognized jurisdiction {:?} at row {}", value, row)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArrayError {}
+
+/// Return the alpha2 codes of `jurisdictions` as a `StringArray`.
+pub fn to_string_array(jurisdictions: &[Jurisdiction]) -> StringArray {
+    StringArray::from_iter_values(
+        jurisdictions
+            .iter()
+            .map(|jurisdiction| jurisdiction.alpha2().to_string()),
+    )
+}
+
+/// Return the alpha2 codes of `jurisdictions` as a `DictionaryArray`, keyed by
+/// [Jurisdiction::index].
+///
+/// [Jurisdiction::index]: ../struct.Jurisdiction.html#method.index
+pub fn to_dictionary_array(jurisdictions: &[Jurisdiction]) -> DictionaryArray<UInt8Type> {
+    // There are fewer jurisdictions than keys of u8
+    let keys = UInt8Array::from_iter_values(
+        jurisdictions
+            .iter()
+            .map(|jurisdiction| jurisdiction.index() as u8),
+    );
+    DictionaryArray::new(keys, DICTIONARY.clone())
+}
+
+/// Return the numeric country codes of `jurisdictions` as a `UInt16Array`.
+pub fn to_numeric_array(jurisdictions: &[Jurisdiction]) -> UInt16Array {
+    UInt16Array::from_iter_values(
+        jurisdictions
+            .iter()
+            .map(|jurisdiction| jurisdiction.country_code()),
+    )
+}
+
+/// Return the jurisdictions of an array of alpha2 or alpha3 codes, or of numeric country
+/// codes.
+///
+/// Arrays of `Utf8`, `LargeUtf8` and `Utf8View` strings, dictionaries of these and `UInt16`
+/// arrays are supported. Every row is validated, and the first null or unrecognized row
+/// is reported by the error.
+pub fn try_from_array(array: &dyn Array) -> Result<Vec<Jurisdiction>, ArrayError> {
+    first_invalid(decode(array)?)
+}
+
+/// The jurisdiction of a row, or the value of the row if it is not a jurisdiction.
+type Row = Result<Jurisdiction, Option<String>>;
+
+fn decode(array: &dyn Array) -> Result<Vec<Row>, ArrayError> {
+    let rows = match array.data_type() {
+        DataType::Utf8 => array.as_string::<i32>().iter().map(code_row).collect(),
+        DataType::LargeUtf8 => array.as_string::<i64>().iter().map(code_row).collect(),
+        DataType::Utf8View => array.as_string_view().iter().map(code_row).collect(),
+        DataType::UInt16 => array
+            .as_primitive::<UInt16Type>()
+            .iter()
+            .map(numeric_row)
+            .collect(),
+        DataType::Dictionary(_, values) if !matches!(**values, DataType::Dictionary(..)) => {
+            let dictionary = array.as_any_dictionary();
+            let values = decode(dictionary.values().as_ref())?;
+            let keys = dictionary.keys();
+            dictionary
+                .normalized_keys()
+                .into_iter()
+                .enumerate()
+                .map(|(row, key)| {
+                    if keys.is_null(row) {
+                        Err(None)
+                    } else {
+                        values[key].clone()
+                    }
+                })
+                .collect()
+        }
+        data_type => return Err(ArrayError::UnsupportedType(data_type.to_string())),
+    };
+    Ok(rows)
+}
+
+fn code_row(code: Option<&str>) -> Row {
+    let code = code.ok_or(None)?;
+    Jurisdiction::from_str(code).map_err(|_| Some(code.to_string()))
+}
+
+fn numeric_row(country_code: Option<u16>) -> Row {
+    let country_code = country_code.ok_or(None)?;
+    Jurisdiction::from_country_code(country_code).map_err(|_| Some(country_code.to_string()))
+}
+
+/// Return the jurisdictions of alpha2 or alpha3 codes, reporting the first invalid row.
+#[cfg(feature = "polars")]
+pub(crate) fn try_from_codes<'a>(
+    codes: impl Iterator<Item = Option<&'a str>>,
+) -> Result<Vec<Jurisdiction>, ArrayError> {
+    first_invalid(codes.map(code_row))
+}
+
+fn first_invalid(rows: impl IntoIterator<Item = Row>) -> Result<Vec<Jurisdiction>, ArrayError> {
+    rows.into_iter()
+        .enumerate()
+        .map(|(row, jurisdiction)| {
+            jurisdiction.map_err(|value| match value {
+                None => ArrayError::Null { row },
+                Some(value) => ArrayError::Unrecognized { row, value },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Float64Array, StringViewArray};
+
+    #[test]
+    fn test_arrow_round_trip() {
+        let jurisdictions: Vec<_> = Jurisdiction::all().collect();
+
+        let array = to_string_array(&jurisdictions);
+        assert_eq!(array.value(0), "AF");
+        assert_eq!(try_from_array(&array).unwrap(), jurisdictions);
+
+        let array = to_dictionary_array(&jurisdictions);
+        assert_eq!(array.values().len(), jurisdictions.len());
+        assert_eq!(try_from_array(&array).unwrap(), jurisdictions);
+
+        let array = to_numeric_array(&jurisdictions);
+        assert_eq!(array.value(0), 4);
+        assert_eq!(try_from_array(&array).unwrap(), jurisdictions);
+    }
+
+    #[test]
+    fn test_arrow_foreign_arrays() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        let sweden = Jurisdiction::from(Alpha2::SE);
+
+        let array = StringViewArray::from_iter_values(["NOR", "SE"]);
+        assert_eq!(
+            try_from_array(&array).unwrap(),
+            [norway.clone(), sweden.clone()]
+        );
+
+        let array: DictionaryArray<Int32Type> = vec!["SE", "NO", "SE"].into_iter().collect();
+        assert_eq!(
+            try_from_array(&array).unwrap(),
+            [sweden.clone(), norway, sweden]
+        );
+    }
+
+    #[test]
+    fn test_arrow_invalid_values() {
+        let array = StringArray::from(vec![Some("NO"), Some("SE"), Some("XX"), Some("YY")]);
+        let err = try_from_array(&array).unwrap_err();
+        assert_eq!(
+            err,
+            ArrayError::Unrecognized {
+                row: 2,
+                value: "XX".to_string()
+            }
+        );
+        assert_eq!(err.row(), Some(2));
+        assert_eq!(
+            err.to_string(),
+            r#"unrecognized jurisdiction "XX" at row 2"#
+        );
+
+        let array = UInt16Array::from(vec![Some(578), None]);
+        assert_eq!(
+            try_from_array(&array).unwrap_err(),
+            ArrayError::Null { row: 1 }
+        );
+
+        let array = UInt16Array::from(vec![578, 0]);
+        assert_eq!(try_from_array(&array).unwrap_err().row(), Some(1));
+
+        // Invalid values of a dictionary are reported at the first row referencing them
+        let array: DictionaryArray<Int32Type> =
+            vec![Some("NO"), None, Some("XX")].into_iter().collect();
+        assert_eq!(
+            try_from_array(&array).unwrap_err(),
+            ArrayError::Null { row: 1 }
+        );
+        let array: DictionaryArray<Int32Type> = vec!["NO", "XX", "XX"].into_iter().collect();
+        assert_eq!(try_from_array(&array).unwrap_err().row(), Some(1));
+
+        let array = Float64Array::from(vec![578.0]);
+        assert_eq!(
+            try_from_array(&array).unwrap_err(),
+            ArrayError::UnsupportedType("Float64".to_string())
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Aircraft registration prefixes.
+//!
+//! The registration of an aircraft, its tail number, starts with the nationality mark of
+//! the state of registry, such as `LN-` for Norway or `N` for the United States. The marks
+//! are drawn from the ITU call sign allocations rather than the ISO 3166 codes, and a
+//! jurisdiction may be assigned several of them.
+//!
+//! Marks of different lengths may share their leading characters, such as `C-` of Canada
+//! and `CC-` of Chile, so a registration resolves to the jurisdiction of its longest
+//! matching mark.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! let registry = jurisdiction::aviation::from_registration("LN-RKF").unwrap();
+//! assert_eq!(registry, Alpha2::NO);
+//! ```
+//!
+//! Jurisdictions without a register of their own, such as the dependent territories
+//! registering aircraft with their sovereign, have no prefixes.
+
+use crate::generated::aviation::GENERATED_AIRCRAFT_PREFIXES;
+use crate::Jurisdiction;
+
+/// Return the [Jurisdiction] of the state of registry of an aircraft registration.
+///
+/// The registration is matched against the longest nationality mark it starts with,
+/// ignoring ASCII case. Registrations made up of only a nationality mark, or starting
+/// with a mark not assigned to a jurisdiction, return `None`.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn from_registration(registration: &str) -> Option<Jurisdiction> {
+    // The prefixes are ordered by decreasing length, so the first match is the longest
+    GENERATED_AIRCRAFT_PREFIXES
+        .iter()
+        .find(|(prefix, _)| match registration.get(..prefix.len()) {
+            Some(start) => registration.len() > prefix.len() && start.eq_ignore_ascii_case(prefix),
+            None => false,
+        })
+        .map(|(_, alpha2)| Jurisdiction::from(*alpha2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_aviation_from_registration() {
+        assert_eq!(from_registration("LN-ABC"), Some(Alpha2::NO.into()));
+        assert_eq!(from_registration("N123AB"), Some(Alpha2::US.into()));
+        assert_eq!(from_registration("ZK-XYZ"), Some(Alpha2::NZ.into()));
+        assert_eq!(from_registration("D-AIMA"), Some(Alpha2::DE.into()));
+        assert_eq!(from_registration("ja801a"), Some(Alpha2::JP.into()));
+    }
+
+    #[test]
+    fn test_aviation_longest_prefix() {
+        assert_eq!(from_registration("C-GABC"), Some(Alpha2::CA.into()));
+        assert_eq!(from_registration("CC-BAA"), Some(Alpha2::CL.into()));
+        assert_eq!(from_registration("B-1234"), Some(Alpha2::CN.into()));
+        assert_eq!(from_registration("B-HNR"), Some(Alpha2::HK.into()));
+        assert_eq!(from_registration("VP-BAA"), Some(Alpha2::BM.into()));
+    }
+
+    #[test]
+    fn test_aviation_multiple_prefixes() {
+        let brazil = Jurisdiction::from(Alpha2::BR);
+        assert!(brazil.aircraft_prefixes().contains(&"PP-"));
+        assert!(brazil.aircraft_prefixes().contains(&"PR-"));
+        assert_eq!(from_registration("PR-XBA"), Some(brazil.clone()));
+        assert_eq!(from_registration("PT-MXA"), Some(brazil));
+        assert_eq!(Jurisdiction::from(Alpha2::NO).aircraft_prefixes(), &["LN-"]);
+        assert!(Jurisdiction::from(Alpha2::AQ)
+            .aircraft_prefixes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_aviation_unknown_prefix() {
+        assert_eq!(from_registration("QQ-ABC"), None);
+        assert_eq!(from_registration("1-ABC"), None);
+        assert_eq!(from_registration("N"), None);
+        assert_eq!(from_registration("LN-"), None);
+        assert_eq!(from_registration(""), None);
+        assert_eq!(from_registration("Ñ-ABC"), None);
+    }
+}
@@ -0,0 +1,186 @@
+//! International Bank Account Numbers (IBAN) and the Single Euro Payments Area (SEPA).
+//!
+//! The IBAN lengths are sourced from the [IBAN registry] maintained by SWIFT.
+//! Jurisdictions whose accounts are issued under the IBAN country code of another
+//! jurisdiction, such as the French overseas departments using `FR`, have no IBAN
+//! length of their own.
+//!
+//! The SEPA membership is sourced from the list of SEPA countries published by the
+//! [European Payments Council], and includes members outside the European Union,
+//! such as Norway and Switzerland.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! let jurisdiction = jurisdiction::banking::jurisdiction_of_iban("NO93 8601 1117 947").unwrap();
+//! assert_eq!(jurisdiction, Alpha2::NO);
+//! assert!(jurisdiction.is_sepa_member());
+//! ```
+//!
+//! [IBAN registry]: https://www.swift.com/standards/data-standards/iban-international-bank-account-number
+//! [European Payments Council]: https://www.europeanpaymentscouncil.eu/
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use std::fmt;
+
+/// Error returned when an IBAN could not be resolved to a [Jurisdiction].
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseIbanError {
+    /// The input is not made up of a two letter country code, two check digits
+    /// and an alphanumeric account number.
+    Malformed(String),
+    /// The country code does not identify a jurisdiction issuing IBANs.
+    UnknownCountry(String),
+    /// The length differs from the IBAN length of the jurisdiction.
+    InvalidLength {
+        /// The IBAN length of the jurisdiction.
+        expected: u8,
+        /// The length of the input, excluding spaces.
+        found: usize,
+    },
+    /// The check digits do not validate the IBAN.
+    InvalidChecksum(String),
+}
+
+impl fmt::Display for ParseIbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIbanError::Malformed(s) => write!(f, "malformed IBAN: {}", s),
+            ParseIbanError::UnknownCountry(s) => {
+                write!(f, "unrecognized IBAN country code: {}", s)
+            }
+            ParseIbanError::InvalidLength { expected, found } => write!(
+                f,
+                "invalid IBAN length {}, expected {} characters",
+                found, expected
+            ),
+            ParseIbanError::InvalidChecksum(s) => write!(f, "invalid IBAN check digits: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseIbanError {}
+
+/// Resolve the [Jurisdiction] issuing an IBAN.
+///
+/// The IBAN may be given in its electronic form or in its print form, grouped by spaces.
+/// Along with the leading country code, the length and the ISO 7064 mod 97-10 check
+/// digits of the IBAN are validated.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn jurisdiction_of_iban(iban: &str) -> Result<Jurisdiction, ParseIbanError> {
+    let malformed = || ParseIbanError::Malformed(iban.to_string());
+    let compact = || iban.chars().filter(|c| *c != ' ');
+
+    let mut chars = compact();
+    let country: String = chars.by_ref().take(2).collect();
+    let check: String = chars.by_ref().take(2).collect();
+    if country.len() != 2
+        || !country.bytes().all(|b| b.is_ascii_uppercase())
+        || check.len() != 2
+        || !check.bytes().all(|b| b.is_ascii_digit())
+        || !chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return Err(malformed());
+    }
+
+    let jurisdiction = serde_plain::from_str::<Alpha2>(&country)
+        .map(Jurisdiction::from)
+        .map_err(|_| ParseIbanError::UnknownCountry(country.clone()))?;
+    let expected = jurisdiction
+        .iban_length()
+        .ok_or(ParseIbanError::UnknownCountry(country))?;
+
+    let found = compact().count();
+    if found != usize::from(expected) {
+        return Err(ParseIbanError::InvalidLength { expected, found });
+    }
+
+    // Move the country code and check digits to the end, and interpret the
+    // letters as two digit numbers, A = 10 through Z = 35.
+    let remainder = compact()
+        .skip(4)
+        .chain(compact().take(4))
+        .fold(0u32, |remainder, c| {
+            let value = c.to_digit(36).expect("validated alphanumeric");
+            if value < 10 {
+                (remainder * 10 + value) % 97
+            } else {
+                (remainder * 100 + value) % 97
+            }
+        });
+    if remainder != 1 {
+        return Err(ParseIbanError::InvalidChecksum(iban.to_string()));
+    }
+
+    Ok(jurisdiction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iban_length() {
+        assert_eq!(Jurisdiction::from(Alpha2::NO).iban_length(), Some(15));
+        assert_eq!(Jurisdiction::from(Alpha2::DE).iban_length(), Some(22));
+        assert_eq!(Jurisdiction::from(Alpha2::MT).iban_length(), Some(31));
+        assert_eq!(Jurisdiction::from(Alpha2::US).iban_length(), None);
+    }
+
+    #[test]
+    fn test_sepa_member() {
+        for alpha2 in &[Alpha2::NO, Alpha2::CH, Alpha2::DE, Alpha2::GB, Alpha2::SM] {
+            assert!(Jurisdiction::from(*alpha2).is_sepa_member());
+        }
+        for alpha2 in &[Alpha2::US, Alpha2::TR, Alpha2::RS] {
+            assert!(!Jurisdiction::from(*alpha2).is_sepa_member());
+        }
+    }
+
+    #[test]
+    fn test_jurisdiction_of_iban() {
+        let norway = jurisdiction_of_iban("NO9386011117947").unwrap();
+        assert_eq!(norway, Alpha2::NO);
+
+        let germany = jurisdiction_of_iban("DE89 3704 0044 0532 0130 00").unwrap();
+        assert_eq!(germany, Alpha2::DE);
+
+        let malta = jurisdiction_of_iban("MT84MALT011000012345MTLCAST001S").unwrap();
+        assert_eq!(malta, Alpha2::MT);
+    }
+
+    #[test]
+    fn test_jurisdiction_of_iban_invalid() {
+        assert_eq!(
+            jurisdiction_of_iban("NO9386011117948"),
+            Err(ParseIbanError::InvalidChecksum(
+                "NO9386011117948".to_string()
+            ))
+        );
+        assert_eq!(
+            jurisdiction_of_iban("NO938601111794"),
+            Err(ParseIbanError::InvalidLength {
+                expected: 15,
+                found: 14
+            })
+        );
+        assert_eq!(
+            jurisdiction_of_iban("US12345678901234"),
+            Err(ParseIbanError::UnknownCountry("US".to_string()))
+        );
+        assert_eq!(
+            jurisdiction_of_iban("XX12345678901234"),
+            Err(ParseIbanError::UnknownCountry("XX".to_string()))
+        );
+        for input in &["", "NO", "N093", "no9386011117947", "NO93-8601-1117-947"] {
+            assert_eq!(
+                jurisdiction_of_iban(input),
+                Err(ParseIbanError::Malformed(input.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Query jurisdictions from the command line.
+//!
+//! Jurisdictions are looked up by their alpha2 code, alpha3 code, numeric country code or
+//! english name, e.g. `jurisdiction NO`, or listed by region, e.g.
+//! `jurisdiction --region Europe --format json`. Without any query, all jurisdictions
+//! are listed.
+
+use clap::{Parser, ValueEnum};
+use jurisdiction::region::{Region, SubRegion};
+use jurisdiction::Jurisdiction;
+use serde::de::DeserializeOwned;
+
+use std::process::ExitCode;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Plain,
+    Json,
+    Csv,
+}
+
+/// Look up ISO 3166-1 jurisdictions by code or name, or list them by region.
+#[derive(Debug, Parser)]
+#[command(name = "jurisdiction", version)]
+struct Args {
+    /// Alpha2 code, alpha3 code, numeric country code or english name to look up.
+    #[arg(conflicts_with_all = ["region", "sub_region"])]
+    query: Vec<String>,
+
+    /// List the jurisdictions zoning to a region, e.g. `Europe`.
+    #[arg(long, value_parser = |name: &str| parse_region(name, Region::Undefined), conflicts_with = "sub_region")]
+    region: Option<Region>,
+
+    /// List the jurisdictions zoning to a sub region, e.g. `Northern Europe`.
+    #[arg(long, value_parser = |name: &str| parse_region(name, SubRegion::Undefined))]
+    sub_region: Option<SubRegion>,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+}
+
+/// Parse a region classification from its name, rejecting names that are unknown to
+/// it rather than deserializing them as its `undefined` variant.
+fn parse_region<T: DeserializeOwned + PartialEq>(name: &str, undefined: T) -> Result<T, String> {
+    let region: T = serde_plain::from_str(name).map_err(|err| err.to_string())?;
+    if region == undefined && name != "Undefined" {
+        return Err(format!("unrecognized region: {:?}", name));
+    }
+    Ok(region)
+}
+
+/// Look up a jurisdiction by any of its codes, or by its english or formal name ignoring
+/// case.
+fn lookup(query: &str) -> anyhow::Result<Jurisdiction> {
+    Jurisdiction::from_str(query).or_else(|err| {
+        let found = match query.parse::<u16>() {
+            Ok(country_code) => Jurisdiction::all().find(|j| j.country_code() == country_code),
+            Err(_) => Jurisdiction::from_name(query).or_else(|| {
+                Jurisdiction::all().find(|j| j.formal_name().eq_ignore_ascii_case(query))
+            }),
+        };
+        found.ok_or(err)
+    })
+}
+
+/// Quote a CSV field containing separators or quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn json(jurisdiction: &Jurisdiction) -> serde_json::Value {
+    serde_json::json!({
+        "name": jurisdiction.name(),
+        "alpha2": jurisdiction.alpha2().to_string(),
+        "alpha3": jurisdiction.alpha3().to_string(),
+        "numeric": jurisdiction.country_code(),
+        "region": serde_plain::to_string(&jurisdiction.region()).unwrap(),
+        "sub_region": serde_plain::to_string(&jurisdiction.sub_region()).unwrap(),
+    })
+}
+
+/// Print the jurisdictions in a format, printing a single looked up jurisdiction as
+/// an object rather than a list.
+fn print(jurisdictions: &[Jurisdiction], format: Format, single: bool) {
+    match format {
+        Format::Plain => {
+            for jurisdiction in jurisdictions {
+                println!(
+                    "{} {} {:03} {} ({}, {})",
+                    jurisdiction.alpha2(),
+                    jurisdiction.alpha3(),
+                    jurisdiction.country_code(),
+                    jurisdiction.name(),
+                    serde_plain::to_string(&jurisdiction.region()).unwrap(),
+                    serde_plain::to_string(&jurisdiction.sub_region()).unwrap(),
+                );
+            }
+        }
+        Format::Json => {
+            let value = match jurisdictions {
+                [jurisdiction] if single => json(jurisdiction),
+                _ => jurisdictions.iter().map(json).collect(),
+            };
+            println!("{}", serde_json::to_string_pretty(&value).unwrap());
+        }
+        Format::Csv => {
+            println!("name,alpha2,alpha3,numeric,region,sub_region");
+            for jurisdiction in jurisdictions {
+                println!(
+                    "{},{},{},{:03},{},{}",
+                    csv_field(jurisdiction.name()),
+                    jurisdiction.alpha2(),
+                    jurisdiction.alpha3(),
+                    jurisdiction.country_code(),
+                    csv_field(&serde_plain::to_string(&jurisdiction.region()).unwrap()),
+                    csv_field(&serde_plain::to_string(&jurisdiction.sub_region()).unwrap()),
+                );
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let jurisdictions = if let Some(region) = args.region {
+        Jurisdiction::in_region(region)
+    } else if let Some(sub_region) = args.sub_region {
+        Jurisdiction::in_sub_region(sub_region)
+    } else if args.query.is_empty() {
+        Jurisdiction::all().collect()
+    } else {
+        match args.query.iter().map(|query| lookup(query)).collect() {
+            Ok(jurisdictions) => jurisdictions,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return ExitCode::FAILURE;
+            }
+        }
+    };
+
+    print(&jurisdictions, args.format, args.query.len() == 1);
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,5 @@
+//! Generate the foreign bindings of the `uniffi` feature.
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
@@ -0,0 +1,111 @@
+//! [celes] interoperability.
+//!
+//! [Jurisdiction] and [Alpha2] convert to and from the `Country` of celes, matched on their
+//! numeric country code. The conversions are fallible in both directions, as either crate
+//! may define a jurisdiction the other does not.
+//!
+//! ```rust
+//! use celes::Country;
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use std::convert::TryFrom;
+//!
+//! let norway = Jurisdiction::try_from(Country::norway()).unwrap();
+//! assert_eq!(norway, Alpha2::NO);
+//! assert_eq!(Country::try_from(norway).unwrap(), Country::norway());
+//! ```
+//!
+//! [celes]: https://docs.rs/celes
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use celes::Country;
+
+use std::convert::TryFrom;
+
+impl TryFrom<Country> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn try_from(country: Country) -> Result<Self, Self::Error> {
+        let country_code = u16::try_from(country.value).map_err(|_| {
+            format_err!(
+                "unrecognized ISO 3166 numeric country code: {}",
+                country.value
+            )
+        })?;
+        Jurisdiction::from_country_code(country_code)
+    }
+}
+
+impl TryFrom<Jurisdiction> for Country {
+    type Error = anyhow::Error;
+
+    fn try_from(jurisdiction: Jurisdiction) -> Result<Self, Self::Error> {
+        Country::from_value(usize::from(jurisdiction.country_code())).map_err(|_| {
+            format_err!(
+                "jurisdiction {} is not defined by celes",
+                jurisdiction.alpha2()
+            )
+        })
+    }
+}
+
+impl TryFrom<Country> for Alpha2 {
+    type Error = anyhow::Error;
+
+    fn try_from(country: Country) -> Result<Self, Self::Error> {
+        Jurisdiction::try_from(country).map(|jurisdiction| jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Alpha2> for Country {
+    type Error = anyhow::Error;
+
+    fn try_from(alpha2: Alpha2) -> Result<Self, Self::Error> {
+        Country::try_from(Jurisdiction::from(alpha2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_celes_round_trip() {
+        let mut undefined = Vec::new();
+        for jurisdiction in Jurisdiction::all() {
+            let country = match Country::try_from(jurisdiction.clone()) {
+                Ok(country) => country,
+                Err(_) => {
+                    undefined.push(jurisdiction.alpha2());
+                    continue;
+                }
+            };
+            assert_eq!(country.alpha2, jurisdiction.alpha2().to_string());
+            assert_eq!(country.alpha3, jurisdiction.alpha3().to_string());
+            assert_eq!(Jurisdiction::try_from(country).unwrap(), jurisdiction);
+            assert_eq!(Alpha2::try_from(country).unwrap(), jurisdiction.alpha2());
+        }
+        assert!(
+            undefined.is_empty(),
+            "jurisdictions not defined by celes: {:?}",
+            undefined
+        );
+
+        let unrecognized: Vec<_> = Country::get_countries()
+            .iter()
+            .filter(|country| Jurisdiction::try_from(**country).is_err())
+            .map(|country| country.alpha2)
+            .collect();
+        // Kosovo is defined by celes through the user-assigned code XK, which is not part
+        // of ISO 3166-1
+        assert_eq!(
+            unrecognized,
+            ["XK"],
+            "celes countries not defined by jurisdiction"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! [clap] value parsers.
+//!
+//! [Jurisdiction], [Alpha2] and [Alpha3] implement `ValueParserFactory`, such that they
+//! are usable as the type of arguments without further configuration. The codes are
+//! exposed as the possible values of the argument, listed by `--help` and by shell
+//! completions along with the name of each jurisdiction, and unrecognized codes are
+//! reported with suggestions of similar codes. Matching is case sensitive unless
+//! `Arg::ignore_case` is set.
+//!
+//! A `Jurisdiction` is parsed from either an alpha2 or an alpha3 code, whereas only the
+//! alpha2 codes are listed.
+//!
+//! ```rust
+//! use clap::{Arg, Command};
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let matches = Command::new("customers")
+//!     .arg(
+//!         Arg::new("country")
+//!             .long("country")
+//!             .value_parser(clap::value_parser!(Jurisdiction)),
+//!     )
+//!     .get_matches_from(["customers", "--country", "NO"]);
+//! assert_eq!(matches.get_one::<Jurisdiction>("country"), Some(&Alpha2::NO.into()));
+//! ```
+//!
+//! [clap]: https://docs.rs/clap
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser, ValueParserFactory};
+use clap::{Arg, Command, Error};
+
+use std::ffi::OsStr;
+use std::str::FromStr;
+
+/// Parse `value` as one of `possible_values`, returning it in upper case.
+fn parse_code(
+    possible_values: impl Iterator<Item = PossibleValue>,
+    cmd: &Command,
+    arg: Option<&Arg>,
+    value: &OsStr,
+) -> Result<String, Error> {
+    PossibleValuesParser::new(possible_values)
+        .parse_ref(cmd, arg, value)
+        .map(|code| code.to_ascii_uppercase())
+}
+
+fn alpha2_values() -> impl Iterator<Item = PossibleValue> {
+    Jurisdiction::all().map(|jurisdiction| {
+        PossibleValue::new(jurisdiction.alpha2().to_string()).help(jurisdiction.name().to_string())
+    })
+}
+
+fn alpha3_values() -> impl Iterator<Item = PossibleValue> {
+    Jurisdiction::all().map(|jurisdiction| {
+        PossibleValue::new(jurisdiction.alpha3().to_string()).help(jurisdiction.name().to_string())
+    })
+}
+
+fn jurisdiction_values() -> impl Iterator<Item = PossibleValue> {
+    Jurisdiction::all().map(|jurisdiction| {
+        PossibleValue::new(jurisdiction.alpha2().to_string())
+            .alias(jurisdiction.alpha3().to_string())
+            .help(jurisdiction.name().to_string())
+    })
+}
+
+/// Parser of [Jurisdiction] arguments from alpha2 or alpha3 codes.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JurisdictionValueParser;
+
+impl TypedValueParser for JurisdictionValueParser {
+    type Value = Jurisdiction;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let code = parse_code(jurisdiction_values(), cmd, arg, value)?;
+        Ok(Jurisdiction::from_str(&code).expect("possible values are alpha codes"))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(jurisdiction_values()))
+    }
+}
+
+impl ValueParserFactory for Jurisdiction {
+    type Parser = JurisdictionValueParser;
+
+    fn value_parser() -> Self::Parser {
+        JurisdictionValueParser
+    }
+}
+
+/// Parser of [Alpha2] arguments.
+///
+/// [Alpha2]: ../enum.Alpha2.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Alpha2ValueParser;
+
+impl TypedValueParser for Alpha2ValueParser {
+    type Value = Alpha2;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let code = parse_code(alpha2_values(), cmd, arg, value)?;
+        Ok(serde_plain::from_str(&code).expect("possible values are alpha2 codes"))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(alpha2_values()))
+    }
+}
+
+impl ValueParserFactory for Alpha2 {
+    type Parser = Alpha2ValueParser;
+
+    fn value_parser() -> Self::Parser {
+        Alpha2ValueParser
+    }
+}
+
+/// Parser of [Alpha3] arguments.
+///
+/// [Alpha3]: ../enum.Alpha3.html
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Alpha3ValueParser;
+
+impl TypedValueParser for Alpha3ValueParser {
+    type Value = Alpha3;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, Error> {
+        let code = parse_code(alpha3_values(), cmd, arg, value)?;
+        Ok(serde_plain::from_str(&code).expect("possible values are alpha3 codes"))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(alpha3_values()))
+    }
+}
+
+impl ValueParserFactory for Alpha3 {
+    type Parser = Alpha3ValueParser;
+
+    fn value_parser() -> Self::Parser {
+        Alpha3ValueParser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use clap::error::ErrorKind;
+
+    fn command() -> Command {
+        Command::new("customers")
+            .arg(
+                Arg::new("country")
+                    .long("country")
+                    .value_parser(clap::value_parser!(Jurisdiction)),
+            )
+            .arg(
+                Arg::new("alpha2")
+                    .long("alpha2")
+                    .ignore_case(true)
+                    .value_parser(clap::value_parser!(Alpha2)),
+            )
+            .arg(
+                Arg::new("alpha3")
+                    .long("alpha3")
+                    .value_parser(clap::value_parser!(Alpha3)),
+            )
+    }
+
+    #[test]
+    fn test_clap_parse() {
+        let matches = command()
+            .try_get_matches_from([
+                "customers",
+                "--country",
+                "NOR",
+                "--alpha2",
+                "se",
+                "--alpha3",
+                "DNK",
+            ])
+            .unwrap();
+        assert_eq!(
+            matches.get_one::<Jurisdiction>("country"),
+            Some(&Alpha2::NO.into())
+        );
+        assert_eq!(matches.get_one::<Alpha2>("alpha2"), Some(&Alpha2::SE));
+        assert_eq!(matches.get_one::<Alpha3>("alpha3"), Some(&Alpha3::DNK));
+    }
+
+    #[test]
+    fn test_clap_unknown_value() {
+        let err = command()
+            .try_get_matches_from(["customers", "--country", "NOO"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        let message = err.to_string();
+        assert!(
+            message.contains("invalid value 'NOO' for '--country <country>'"),
+            "{}",
+            message
+        );
+        assert!(message.contains("similar value"), "{}", message);
+
+        // Case is only ignored when requested by the argument
+        let err = command()
+            .try_get_matches_from(["customers", "--alpha3", "dnk"])
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn test_clap_possible_values() {
+        let cmd = command();
+        let country = cmd
+            .get_arguments()
+            .find(|arg| arg.get_id() == "country")
+            .unwrap();
+        let values = country.get_possible_values();
+        assert_eq!(values.len(), Jurisdiction::all().count());
+        let norway = values
+            .iter()
+            .find(|value| value.get_name() == "NO")
+            .unwrap();
+        assert_eq!(
+            norway.get_help().map(ToString::to_string),
+            Some("Norway".into())
+        );
+        assert!(norway.matches("NOR", false));
+    }
+}
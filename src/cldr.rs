@@ -0,0 +1,109 @@
+//! Unicode CLDR territory containment.
+//!
+//! The Unicode Common Locale Data Repository (CLDR) arranges the territories of the world
+//! in a containment tree, as an alternative to the [region] hierarchy of UN M49. The tree
+//! closely follows M49, with the intermediate regions as the containers of their
+//! jurisdictions, save that Antarctica is placed in Outlying Oceania, `QO`. Beside the
+//! tree, CLDR groups territories by grouping containers, such as the European Union, `EU`,
+//! and Latin America, `419`, which overlap the containers of the tree.
+//!
+//! Territories are identified by their CLDR code, which is the three digit M49 code for
+//! regions, or an alpha code for the groupings and outlying territories of CLDR.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! use jurisdiction::cldr;
+//!
+//! let eu = cldr::contained_in("EU");
+//! assert_eq!(eu.len(), 27);
+//! assert!(eu.contains(&Alpha2::DE.into()));
+//! assert!(cldr::subterritories("150").contains(&"154"));
+//! ```
+//!
+//! [region]: ../region/index.html
+
+use crate::generated::cldr::GENERATED_CLDR_TERRITORIES;
+use crate::Jurisdiction;
+
+/// Return the position of the CLDR territory in the generated table, if it contains any.
+fn lookup(code: &str) -> Option<usize> {
+    GENERATED_CLDR_TERRITORIES
+        .binary_search_by_key(&code, |(territory, _, _)| territory)
+        .ok()
+}
+
+/// Return every [Jurisdiction] contained in the CLDR territory, in the order of
+/// [Jurisdiction::all].
+///
+/// Jurisdictions are included through any of the territories contained, such that
+/// `contained_in("150")` lists Norway through Northern Europe, `154`. Return an empty
+/// slice for codes not containing other territories, including the alpha codes of
+/// jurisdictions.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+/// [Jurisdiction::all]: ../struct.Jurisdiction.html#method.all
+pub fn contained_in(code: &str) -> &'static [Jurisdiction] {
+    lookup(code).map_or(&[], |index| GENERATED_CLDR_TERRITORIES[index].2)
+}
+
+/// Return the codes of the territories directly contained in the CLDR territory, ordered
+/// by code.
+///
+/// Both the territories of the containment tree and of the groupings are listed, such
+/// that `subterritories("001")` includes the European Union, `EU`. Return an empty slice
+/// for codes not containing other territories.
+pub fn subterritories(code: &str) -> &'static [&'static str] {
+    lookup(code).map_or(&[], |index| GENERATED_CLDR_TERRITORIES[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_cldr_parents() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.cldr_parents(), ["154", "150", "001"]);
+        // Antarctica is within Outlying Oceania rather than undefined by region
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert_eq!(antarctica.cldr_parents(), ["QO", "009", "001"]);
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(jurisdiction.cldr_parents().last(), Some(&"001"));
+        }
+    }
+
+    #[test]
+    fn test_cldr_contained_in() {
+        assert!(contained_in("155").contains(&Alpha2::DE.into()));
+        assert!(contained_in("150").contains(&Alpha2::NO.into()));
+        assert!(!contained_in("155").contains(&Alpha2::NO.into()));
+        assert!(contained_in("001").iter().cloned().eq(Jurisdiction::all()));
+        for jurisdiction in Jurisdiction::all() {
+            for parent in jurisdiction.cldr_parents() {
+                assert!(contained_in(parent).contains(&jurisdiction), "{}", parent);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cldr_groupings() {
+        let eu = contained_in("EU");
+        assert_eq!(eu.len(), 27);
+        assert!(eu.contains(&Alpha2::DE.into()));
+        assert!(!eu.contains(&Alpha2::NO.into()));
+        assert!(subterritories("001").contains(&"EU"));
+        // Latin America overlaps the Americas of the tree
+        assert!(contained_in("419").contains(&Alpha2::BR.into()));
+        assert!(contained_in("419").contains(&Alpha2::MX.into()));
+        assert!(!contained_in("419").contains(&Alpha2::US.into()));
+    }
+
+    #[test]
+    fn test_cldr_unknown() {
+        assert!(contained_in("999").is_empty());
+        assert!(contained_in("NO").is_empty());
+        assert!(contained_in("").is_empty());
+        assert!(subterritories("XX").is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+//! Locale aware ordering of jurisdiction names through [icu_collator].
+//!
+//! Ordering the names with `str::cmp` compares their code points, placing Åland Islands
+//! after Zimbabwe. Collation orders the names by the alphabet of a locale instead. In
+//! english, Åland Islands follows Afghanistan, whereas the Swedish and Norwegian alphabets
+//! place Å as a letter of its own past Z.
+//!
+//! Locales are given as BCP-47 language tags, e.g. `sv` or `nb-NO`. Tags that fail to
+//! parse, or name a locale without collation data of its own, fall back to the root
+//! collation of the Unicode Collation Algorithm, which orders the names like english.
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let mut jurisdictions = vec![
+//!     Jurisdiction::from(Alpha2::ZW),
+//!     Jurisdiction::from(Alpha2::AX),
+//!     Jurisdiction::from(Alpha2::AF),
+//! ];
+//! Jurisdiction::sort_by_display_name(&mut jurisdictions, "en");
+//! assert_eq!(jurisdictions, [Alpha2::AF, Alpha2::AX, Alpha2::ZW]);
+//!
+//! Jurisdiction::sort_by_display_name(&mut jurisdictions, "sv");
+//! assert_eq!(jurisdictions, [Alpha2::AF, Alpha2::ZW, Alpha2::AX]);
+//! ```
+//!
+//! [icu_collator]: https://docs.rs/icu_collator
+
+use crate::Jurisdiction;
+
+use icu_collator::{Collator, CollatorOptions};
+use icu_locid::Locale;
+
+use std::cmp::Ordering;
+
+/// Return a comparator of jurisdictions by the collation of their english names in
+/// `locale`.
+///
+/// Jurisdictions whose names collate equally are ordered as in
+/// [Jurisdiction::all](../struct.Jurisdiction.html#method.all), such that the ordering
+/// is total and deterministic. The comparator holds the collation data of the locale, and
+/// is cheaper to reuse than to construct.
+pub fn cmp_names_collated(locale: &str) -> impl Fn(&Jurisdiction, &Jurisdiction) -> Ordering {
+    let locale = Locale::try_from_bytes(locale.as_bytes()).unwrap_or(Locale::UND);
+    let collator = Collator::try_new(&(&locale).into(), CollatorOptions::new())
+        .or_else(|_| Collator::try_new(&(&Locale::UND).into(), CollatorOptions::new()))
+        .expect("compiled data covers the root collation");
+    move |a, b| {
+        collator
+            .compare(a.name(), b.name())
+            .then_with(|| a.position().cmp(&b.position()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    fn position(jurisdictions: &[Jurisdiction], alpha2: Alpha2) -> usize {
+        jurisdictions.iter().position(|j| *j == alpha2).unwrap()
+    }
+
+    fn sorted(locale: &str) -> Vec<Jurisdiction> {
+        let mut jurisdictions: Vec<_> = Jurisdiction::all().collect();
+        jurisdictions.reverse();
+        Jurisdiction::sort_by_display_name(&mut jurisdictions, locale);
+        jurisdictions
+    }
+
+    #[test]
+    fn test_collation_aland_near_the_top() {
+        for locale in &["en", "en-US", "de", "fr"] {
+            assert_eq!(position(&sorted(locale), Alpha2::AX), 1, "{}", locale);
+        }
+    }
+
+    #[test]
+    fn test_collation_aland_after_z() {
+        // Å is a letter of its own following Z in Swedish and Norwegian
+        for locale in &["sv", "sv-SE", "nb", "nb-NO"] {
+            let jurisdictions = sorted(locale);
+            assert_eq!(
+                position(&jurisdictions, Alpha2::AX),
+                position(&jurisdictions, Alpha2::ZW) + 1,
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_collation_unknown_locale() {
+        let root = sorted("und");
+        assert_eq!(sorted("not a locale!"), root);
+        assert_eq!(sorted("xx"), root);
+        assert_eq!(position(&root, Alpha2::AX), 1);
+    }
+
+    #[test]
+    fn test_collation_deterministic() {
+        let jurisdictions: Vec<_> = Jurisdiction::all().collect();
+        let mut shuffled: Vec<_> = jurisdictions.iter().rev().cloned().collect();
+        shuffled.extend(jurisdictions.iter().cloned());
+        Jurisdiction::sort_by_display_name(&mut shuffled, "sv");
+
+        let mut again = shuffled.clone();
+        again.reverse();
+        Jurisdiction::sort_by_display_name(&mut again, "sv");
+        assert_eq!(shuffled, again);
+        // Equal jurisdictions are adjacent
+        for pair in shuffled.chunks(2) {
+            assert_eq!(pair[0], pair[1]);
+        }
+
+        let cmp = cmp_names_collated("sv");
+        for pair in shuffled.windows(2) {
+            assert_ne!(cmp(&pair[0], &pair[1]), Ordering::Greater);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Continent classification.
+//!
+//! The classic seven-continent model, where North and South America are separate
+//! continents, as opposed to the [Region] of UN M49. The classification is assigned
+//! explicitly per jurisdiction, derived from the M49 sub-regions:
+//!
+//! * Central America and the Caribbean are part of North America.
+//! * The sub-Antarctic Bouvet Island, French Southern Territories, Heard Island and McDonald
+//!   Islands, and South Georgia and the South Sandwich Islands are part of Antarctica.
+//!
+//! Transcontinental jurisdictions are assigned the continent of their M49 region:
+//! the Russian Federation is part of Europe, Egypt is part of Africa, while Turkey,
+//! Kazakhstan, Azerbaijan, Georgia and Cyprus are part of Asia.
+//!
+//! [Region]: ../region/enum.Region.html
+
+use serde::{Deserialize, Serialize};
+
+/// A continent by the seven-continent model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    SouthAmerica,
+    Oceania,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Alpha3, Jurisdiction};
+
+    #[test]
+    fn test_continent_americas() {
+        let brazil = Jurisdiction::from(Alpha2::BR);
+        assert_eq!(brazil.continent(), Continent::SouthAmerica);
+
+        let mexico = Jurisdiction::from(Alpha2::MX);
+        assert_eq!(mexico.continent(), Continent::NorthAmerica);
+
+        let jamaica = Jurisdiction::from(Alpha2::JM);
+        assert_eq!(jamaica.continent(), Continent::NorthAmerica);
+    }
+
+    #[test]
+    fn test_continent_transcontinental() {
+        let russia = Jurisdiction::from(Alpha2::RU);
+        assert_eq!(russia.continent(), Continent::Europe);
+
+        let turkey = Jurisdiction::from(Alpha2::TR);
+        assert_eq!(turkey.continent(), Continent::Asia);
+
+        let kazakhstan = Jurisdiction::from(Alpha2::KZ);
+        assert_eq!(kazakhstan.continent(), Continent::Asia);
+    }
+
+    #[test]
+    fn test_continent_antarctica() {
+        let antarctica = Jurisdiction::from(Alpha3::ATA);
+        assert_eq!(antarctica.continent(), Continent::Antarctica);
+    }
+}
@@ -0,0 +1,180 @@
+//! Local conventions of a jurisdiction.
+//!
+//! This information is typeset from the [CLDR] supplemental data, describing
+//! conventions such as which day the week starts on, which days make up the weekend,
+//! and which units of measurement and paper size are in everyday use, along with the
+//! emergency telephone numbers of each jurisdiction.
+//!
+//! [CLDR]: https://cldr.unicode.org/
+
+use serde::{Deserialize, Serialize};
+
+/// A day of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// The system of units of measurement in everyday use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum MeasurementSystem {
+    /// The metric system, the international system of units (SI).
+    Metric,
+    /// The United States customary units, e.g. miles, pounds and degrees Fahrenheit.
+    UsCustomary,
+    /// The metric system, mixed with imperial units for some everyday uses such as
+    /// road distances in miles and beverages in pints.
+    UkHybrid,
+}
+
+/// The default paper size for printed documents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PaperSize {
+    /// ISO 216 A4, 210 × 297 mm.
+    A4,
+    /// US Letter, 8.5 × 11 inches.
+    Letter,
+}
+
+/// The emergency telephone numbers of a jurisdiction.
+///
+/// Many jurisdictions have both a general number, reaching every emergency service, and
+/// numbers of their own for each service. The number of each service is given even where
+/// it is the general number, such that `police` is the number to dial for the police.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct EmergencyNumbers {
+    /// The number reaching every emergency service, e.g. `112` within the European Union.
+    pub general: Option<&'static str>,
+    /// The number of the police.
+    pub police: Option<&'static str>,
+    /// The number of the ambulance service.
+    pub ambulance: Option<&'static str>,
+    /// The number of the fire service.
+    pub fire: Option<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_week_norway() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.first_day_of_week(), Weekday::Monday);
+        assert_eq!(norway.weekend(), &[Weekday::Saturday, Weekday::Sunday]);
+    }
+
+    #[test]
+    fn test_week_united_states() {
+        let us = Jurisdiction::from(Alpha2::US);
+        assert_eq!(us.first_day_of_week(), Weekday::Sunday);
+        assert_eq!(us.weekend(), &[Weekday::Saturday, Weekday::Sunday]);
+    }
+
+    #[test]
+    fn test_week_saudi_arabia() {
+        let saudi_arabia = Jurisdiction::from(Alpha2::SA);
+        assert_eq!(saudi_arabia.first_day_of_week(), Weekday::Sunday);
+        assert_eq!(
+            saudi_arabia.weekend(),
+            &[Weekday::Friday, Weekday::Saturday]
+        );
+    }
+
+    #[test]
+    fn test_week_israel() {
+        let israel = Jurisdiction::from(Alpha2::IL);
+        assert_eq!(israel.first_day_of_week(), Weekday::Sunday);
+        assert_eq!(israel.weekend(), &[Weekday::Friday, Weekday::Saturday]);
+    }
+
+    #[test]
+    fn test_measurement_system() {
+        for alpha2 in &[Alpha2::US, Alpha2::LR, Alpha2::MM] {
+            let jurisdiction = Jurisdiction::from(*alpha2);
+            assert_eq!(
+                jurisdiction.measurement_system(),
+                MeasurementSystem::UsCustomary
+            );
+        }
+        let uk = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(uk.measurement_system(), MeasurementSystem::UkHybrid);
+
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.measurement_system(), MeasurementSystem::Metric);
+    }
+
+    #[test]
+    fn test_paper_size() {
+        for alpha2 in &[Alpha2::US, Alpha2::CA, Alpha2::MX, Alpha2::PH] {
+            let jurisdiction = Jurisdiction::from(*alpha2);
+            assert_eq!(jurisdiction.paper_size(), PaperSize::Letter);
+        }
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.paper_size(), PaperSize::A4);
+        let uk = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(uk.paper_size(), PaperSize::A4);
+    }
+
+    #[test]
+    fn test_emergency_numbers_norway() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(
+            norway.emergency_numbers(),
+            EmergencyNumbers {
+                general: Some("112"),
+                police: Some("112"),
+                ambulance: Some("113"),
+                fire: Some("110"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_emergency_numbers_united_states() {
+        let numbers = Jurisdiction::from(Alpha2::US).emergency_numbers();
+        for number in &[
+            numbers.general,
+            numbers.police,
+            numbers.ambulance,
+            numbers.fire,
+        ] {
+            assert_eq!(*number, Some("911"));
+        }
+    }
+
+    #[test]
+    fn test_emergency_numbers_united_kingdom() {
+        let numbers = Jurisdiction::from(Alpha2::GB).emergency_numbers();
+        assert_eq!(numbers.general, Some("112"));
+        assert_eq!(numbers.police, Some("999"));
+        assert_eq!(numbers.ambulance, Some("999"));
+        assert_eq!(numbers.fire, Some("999"));
+    }
+
+    #[test]
+    fn test_emergency_numbers_without_general() {
+        let japan = Jurisdiction::from(Alpha2::JP).emergency_numbers();
+        assert_eq!(japan.general, None);
+        assert_eq!(japan.police, Some("110"));
+        assert_eq!(japan.fire, Some("119"));
+
+        let bouvet = Jurisdiction::from(Alpha2::BV).emergency_numbers();
+        assert_eq!(bouvet.general, None);
+        assert_eq!(bouvet.police, None);
+    }
+
+    #[test]
+    fn test_week_single_day_weekend() {
+        let india = Jurisdiction::from(Alpha2::IN);
+        assert_eq!(india.weekend(), &[Weekday::Sunday]);
+    }
+}
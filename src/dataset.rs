@@ -0,0 +1,101 @@
+//! Information about the datasets embedded in this crate.
+//!
+//! Every dataset is a snapshot of its source at the time of the crate release.
+//! Consumers depending on the freshness of a dataset may inspect its source and version.
+//!
+//! ```rust
+//! let m49 = jurisdiction::dataset::get("country-region").unwrap();
+//! assert_eq!(m49.source(), "UN M49 standard country or area codes for statistical use");
+//! ```
+//!
+//! # M49 revision
+//!
+//! The UN M49 country-region definitions underlying every jurisdiction embed the latest
+//! revision by default. Consumers of longitudinal statistics may pin an earlier revision
+//! when building the crate, by setting the `JURISDICTION_M49_REVISION` environment variable
+//! to a revision available as `data/country-region-<revision>.json`, e.g. `2019`. The build
+//! fails if the selected revision is not available. The embedded revision is the version of
+//! the `country-region` dataset:
+//!
+//! ```rust
+//! let m49 = jurisdiction::dataset::get("country-region").unwrap();
+//! assert!(m49.version().is_some());
+//! ```
+//!
+//! The tests of the crate build against the revision fixtures of `tests/fixtures` as well,
+//! selected by setting `JURISDICTION_M49_DIR` to that directory. The fixtures are not M49
+//! as published, and are not meant to be embedded by consumers.
+
+use crate::generated::dataset::GENERATED_DATASETS;
+
+/// The origin of an embedded dataset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DatasetInfo {
+    pub(crate) name: &'static str,
+    pub(crate) source: &'static str,
+    pub(crate) version: Option<&'static str>,
+}
+
+impl DatasetInfo {
+    /// Return the name of the dataset, e.g. `income-group`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return a description of the source of the dataset.
+    pub fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Return the version of the source the dataset is a snapshot of, if versioned,
+    /// e.g. `FY2025` for the World Bank fiscal year of the `income-group` dataset.
+    pub fn version(&self) -> Option<&'static str> {
+        self.version
+    }
+}
+
+/// Return all datasets embedded through the enabled features, ordered by name.
+pub fn all() -> &'static [DatasetInfo] {
+    GENERATED_DATASETS
+}
+
+/// Return the dataset by `name`, if embedded through the enabled features.
+pub fn get(name: &str) -> Option<&'static DatasetInfo> {
+    GENERATED_DATASETS
+        .iter()
+        .find(|dataset| dataset.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_dataset_get() {
+        let dataset = get("formal-name").unwrap();
+        assert_eq!(dataset.name(), "formal-name");
+        assert!(dataset.source().contains("iso-codes"));
+        assert_eq!(get("unknown"), None);
+    }
+
+    #[test]
+    fn test_dataset_all_ordered() {
+        let names: Vec<_> = all().iter().map(DatasetInfo::name).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn test_dataset_m49_revision() {
+        let dataset = get("country-region").unwrap();
+        let eswatini = Jurisdiction::from(Alpha2::SZ);
+        match dataset.version() {
+            // The fixture of tests/fixtures, named as before the renaming in 2018
+            Some("2017") => assert_eq!(eswatini.name(), "Swaziland"),
+            Some("2019") => assert_eq!(eswatini.name(), "Eswatini"),
+            version => panic!("unknown M49 revision {:?}", version),
+        }
+    }
+}
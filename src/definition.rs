@@ -1,40 +1,196 @@
 //! Definitions internal to the crate
 
 use crate::alpha::*;
+#[cfg(feature = "continent")]
+use crate::continent::Continent;
+#[cfg(feature = "conventions")]
+use crate::conventions::{EmergencyNumbers, MeasurementSystem, PaperSize, Weekday};
+#[cfg(feature = "economics")]
+use crate::economics::IncomeGroup;
+#[cfg(feature = "political")]
+use crate::gdpr::AdequacyStatus;
+use crate::generated::definition::{self as generated, GENERATED_STRINGS};
+#[cfg(feature = "legal")]
+use crate::legal::LegalSystem;
+#[cfg(feature = "localized-names")]
+use crate::localized::Language;
+#[cfg(feature = "postal")]
+use crate::postal::PostalFormat;
+#[cfg(feature = "power")]
+use crate::power::PlugType;
 #[cfg(feature = "region")]
 use crate::region::*;
+#[cfg(feature = "sovereignty")]
+use crate::territory::TerritoryKind;
+#[cfg(feature = "political")]
+use crate::un::UnRegionalGroup;
+#[cfg(feature = "sovereignty")]
+use crate::Jurisdiction;
 
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use std::fmt;
+#[cfg(any(feature = "gs1", feature = "radio"))]
+use std::ops::RangeInclusive;
 
+/// A string of the generated string table, by its byte offset and length.
+///
+/// The bulk of the strings of the definitions are stored in a single table, such that
+/// each takes up four bytes within its definition rather than a pointer and length.
+#[derive(Clone, Copy)]
+pub struct TableStr {
+    offset: u16,
+    len: u16,
+}
+
+impl TableStr {
+    pub const fn new(offset: u16, len: u16) -> TableStr {
+        TableStr { offset, len }
+    }
+
+    pub fn get(self) -> &'static str {
+        let start = usize::from(self.offset);
+        // The table is generated along with the offsets, such that the range is always
+        // valid. Not indexing spares the panic machinery of out of bounds ranges.
+        GENERATED_STRINGS
+            .get(start..start + usize::from(self.len))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for TableStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.get(), f)
+    }
+}
+
+impl fmt::Display for TableStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
+// The numeric and enum fields are not stored within the definition, but generated as
+// arrays parallel to the definition table, read through the getters of the same name.
 #[derive(Debug)]
 pub struct Definition {
-    pub country_code: u16,
-    pub name: &'static str,
+    pub numeric: TableStr,
+    pub name: TableStr,
+    pub formal_name: Option<TableStr>,
+    pub name_ascii: TableStr,
+    /// The key of the definition, whose discriminant is its position within the
+    /// definition table and the parallel arrays alike.
     pub alpha2: Alpha2,
-    pub alpha3: Alpha3,
+    pub iso3166_2_prefix: TableStr,
+    #[cfg(feature = "power")]
+    pub plug_types: &'static [PlugType],
+    #[cfg(feature = "power")]
+    pub mains_voltage: &'static [u16],
+    #[cfg(feature = "power")]
+    pub mains_frequency: &'static [u8],
+    #[cfg(feature = "postal")]
+    pub postal_code_format: Option<PostalFormat>,
+    #[cfg(feature = "conventions")]
+    pub weekend: &'static [Weekday],
+    #[cfg(feature = "conventions")]
+    pub emergency_numbers: EmergencyNumbers,
+    #[cfg(feature = "cldr-containment")]
+    pub cldr_parents: &'static [&'static str],
+    #[cfg(feature = "gs1")]
+    pub gs1_prefixes: &'static [RangeInclusive<u16>],
+    #[cfg(feature = "ip")]
+    pub st3_code: Option<&'static str>,
+    #[cfg(feature = "aviation")]
+    pub aircraft_prefixes: &'static [&'static str],
+    #[cfg(feature = "radio")]
+    pub callsign_prefixes: &'static [RangeInclusive<&'static str>],
+    #[cfg(feature = "telephony")]
+    pub mobile_country_codes: &'static [u16],
+    #[cfg(feature = "locale")]
+    pub default_locales: &'static [&'static str],
+    #[cfg(feature = "demonym")]
+    pub demonym: Option<(&'static str, &'static str)>,
+    #[cfg(feature = "sovereignty")]
+    pub dependencies: &'static [Jurisdiction],
+    #[cfg(feature = "localized-names")]
+    pub localized_names: &'static [(Language, &'static str)],
+}
+
+impl Definition {
+    /// Return the position of the definition within the definition table.
+    pub fn position(&self) -> usize {
+        self.alpha2 as usize
+    }
+}
+
+/// Implement a getter of each array generated parallel to the definition table, returning
+/// the entry at the position of the definition.
+macro_rules! columns {
+    ($($(#[$cfg:meta])* $getter:ident: $ty:ty = $column:ident;)*) => {
+        impl Definition {
+            $(
+                $(#[$cfg])*
+                pub fn $getter(&self) -> $ty {
+                    generated::$column[self.position()]
+                }
+            )*
+        }
+    };
+}
+
+columns! {
+    index: u16 = GENERATED_INDICES;
+    country_code: u16 = GENERATED_COUNTRY_CODES;
+    alpha3: Alpha3 = GENERATED_ALPHA3;
     #[cfg(feature = "region")]
-    pub region: Region,
+    region: Region = GENERATED_REGIONS;
     #[cfg(feature = "region")]
-    pub sub_region: SubRegion,
+    sub_region: SubRegion = GENERATED_SUB_REGIONS;
     #[cfg(feature = "region")]
-    pub intermediate_region: IntermediateRegion,
+    intermediate_region: IntermediateRegion = GENERATED_INTERMEDIATE_REGIONS;
     #[cfg(feature = "region")]
-    pub region_code: u16,
+    region_code: u16 = GENERATED_REGION_CODES;
     #[cfg(feature = "region")]
-    pub sub_region_code: u16,
+    sub_region_code: u16 = GENERATED_SUB_REGION_CODES;
     #[cfg(feature = "region")]
-    pub intermediate_region_code: Option<u16>,
+    intermediate_region_code: Option<u16> = GENERATED_INTERMEDIATE_REGION_CODES;
+    #[cfg(feature = "continent")]
+    continent: Continent = GENERATED_CONTINENTS;
+    #[cfg(feature = "economics")]
+    income_group: Option<IncomeGroup> = GENERATED_INCOME_GROUPS;
+    #[cfg(feature = "legal")]
+    legal_system: Option<LegalSystem> = GENERATED_LEGAL_SYSTEMS;
+    #[cfg(feature = "political")]
+    un_regional_group: Option<UnRegionalGroup> = GENERATED_UN_REGIONAL_GROUPS;
+    #[cfg(feature = "political")]
+    eu_adequacy: AdequacyStatus = GENERATED_EU_ADEQUACY;
+    #[cfg(feature = "statistics")]
+    area_km2: Option<u32> = GENERATED_AREAS_KM2;
+    #[cfg(feature = "statistics")]
+    population: Option<u64> = GENERATED_POPULATIONS;
+    #[cfg(feature = "conventions")]
+    first_day_of_week: Weekday = GENERATED_FIRST_DAYS_OF_WEEK;
+    #[cfg(feature = "conventions")]
+    measurement_system: MeasurementSystem = GENERATED_MEASUREMENT_SYSTEMS;
+    #[cfg(feature = "conventions")]
+    paper_size: PaperSize = GENERATED_PAPER_SIZES;
+    #[cfg(feature = "banking")]
+    iban_length: Option<u8> = GENERATED_IBAN_LENGTHS;
+    #[cfg(feature = "banking")]
+    sepa_member: bool = GENERATED_SEPA_MEMBERS;
+    #[cfg(feature = "rail")]
+    uic_code: Option<u8> = GENERATED_UIC_CODES;
+    #[cfg(feature = "sovereignty")]
+    independent: bool = GENERATED_INDEPENDENT;
+    #[cfg(feature = "sovereignty")]
+    sovereign: Option<Alpha2> = GENERATED_SOVEREIGNS;
+    #[cfg(feature = "sovereignty")]
+    territory_kind: TerritoryKind = GENERATED_TERRITORY_KINDS;
 }
 
-lazy_static! {
-    pub static ref DEFINITIONS: HashMap<u16, &'static Definition> = {
-        let mut map = HashMap::new();
-
-        for def in crate::generated::definition::GENERATED_DEFINITIONS.iter() {
-            map.insert(def.country_code, def);
-        }
-
-        map
-    };
+#[cfg(feature = "subdivision")]
+#[derive(Debug)]
+pub struct SubdivisionDefinition {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub kind: &'static str,
+    pub alpha2: Alpha2,
 }
@@ -0,0 +1,72 @@
+//! [defmt] support.
+//!
+//! [Alpha2], [Alpha3] and the region classifications are formatted as the interned name of
+//! their variant, such that logging them transmits a string index rather than the string.
+//! A [Jurisdiction] is formatted as its alpha2 code followed by its english name, e.g.
+//! `NO (Norway)`, where the name is transmitted as is.
+//!
+//! ```rust,no_run
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let jurisdiction = Jurisdiction::from(Alpha2::NO);
+//! defmt::info!("shipping to {}", jurisdiction);
+//! ```
+//!
+//! [defmt]: https://defmt.ferrous-systems.com
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+
+use crate::Jurisdiction;
+
+impl defmt::Format for Jurisdiction {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{} ({=str})", self.alpha2(), self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::{Alpha2, Alpha3};
+    #[cfg(feature = "region")]
+    use crate::region::{IntermediateRegion, Region, SubRegion};
+
+    use defmt::export::fetch_bytes;
+    use defmt::Format;
+
+    fn format<T: Format>(value: &T) -> Vec<u8> {
+        defmt::export::istr(&T::_format_tag());
+        value._format_data();
+        fetch_bytes()
+    }
+
+    #[test]
+    fn test_defmt_jurisdiction_name() {
+        let bytes = format(&Jurisdiction::from(Alpha2::NO));
+        assert!(bytes.windows(6).any(|w| w == b"Norway"));
+        // The alpha2 code is interned rather than transmitted
+        assert!(!bytes.windows(2).any(|w| w == b"NO"));
+    }
+
+    #[test]
+    fn test_defmt_alpha_interned() {
+        // Each variant encodes as a string index and its discriminant, without its name
+        assert!(!format(&Alpha2::NO).windows(2).any(|w| w == b"NO"));
+        assert!(!format(&Alpha3::NOR).windows(3).any(|w| w == b"NOR"));
+        assert_ne!(format(&Alpha2::NO), format(&Alpha2::SE));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_defmt_regions() {
+        assert_ne!(format(&Region::Europe), format(&Region::Asia));
+        assert_ne!(
+            format(&SubRegion::NorthernEurope),
+            format(&SubRegion::SouthernEurope)
+        );
+        assert!(!format(&IntermediateRegion::ChannelIslands)
+            .windows(7)
+            .any(|w| w == b"Channel"));
+    }
+}
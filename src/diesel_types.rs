@@ -0,0 +1,241 @@
+//! [Diesel] support for the Postgres and SQLite backends.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are stored as their alpha code in `Text` columns,
+//! where a `Jurisdiction` is stored as its alpha2 code and is read back from either an
+//! alpha2 or an alpha3 code. The space padding of `CHAR` columns is tolerated. Values that
+//! do not identify a jurisdiction fail to deserialize, with an error naming the value.
+//!
+//! ```rust,no_run
+//! use diesel::prelude::*;
+//! use jurisdiction::Jurisdiction;
+//!
+//! diesel::table! {
+//!     customers (id) {
+//!         id -> Integer,
+//!         name -> Text,
+//!         country -> Text,
+//!     }
+//! }
+//!
+//! #[derive(Queryable)]
+//! struct Customer {
+//!     id: i32,
+//!     name: String,
+//!     country: Jurisdiction,
+//! }
+//!
+//! fn customers_in(
+//!     conn: &mut SqliteConnection,
+//!     jurisdiction: &Jurisdiction,
+//! ) -> QueryResult<Vec<Customer>> {
+//!     customers::table
+//!         .filter(customers::country.eq(jurisdiction))
+//!         .load(conn)
+//! }
+//! ```
+//!
+//! [Diesel]: https://diesel.rs
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{alpha2_from_sql, alpha3_from_sql, jurisdiction_from_sql};
+use crate::Jurisdiction;
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::Pg;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::sqlite::Sqlite;
+
+use std::io::Write;
+
+impl ToSql<Text, Pg> for Alpha2 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Text, Sqlite> for Alpha2 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Alpha2
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(alpha2_from_sql(&value)?)
+    }
+}
+
+impl ToSql<Text, Pg> for Alpha3 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Text, Sqlite> for Alpha3 {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Alpha3
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(alpha3_from_sql(&value)?)
+    }
+}
+
+impl ToSql<Text, Pg> for Jurisdiction {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.alpha2().to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl ToSql<Text, Sqlite> for Jurisdiction {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.alpha2().to_string());
+        Ok(IsNull::No)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for Jurisdiction
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(jurisdiction_from_sql(&value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use diesel::prelude::*;
+    use diesel::sql_types::Nullable;
+
+    diesel::table! {
+        addresses (id) {
+            id -> Integer,
+            country -> Text,
+            alpha2 -> Text,
+            alpha3 -> Text,
+            previous -> Nullable<Text>,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = addresses)]
+    struct Address {
+        id: i32,
+        country: Jurisdiction,
+        alpha2: Alpha2,
+        alpha3: Alpha3,
+        previous: Option<Jurisdiction>,
+    }
+
+    fn connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query(
+            "CREATE TABLE addresses (
+                id INTEGER PRIMARY KEY,
+                country TEXT NOT NULL,
+                alpha2 TEXT NOT NULL,
+                alpha3 TEXT NOT NULL,
+                previous TEXT
+            )",
+        )
+        .execute(&mut conn)
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_diesel_sqlite_round_trip() {
+        let mut conn = connection();
+        let address = Address {
+            id: 1,
+            country: Jurisdiction::from(Alpha2::NO),
+            alpha2: Alpha2::SE,
+            alpha3: Alpha3::DNK,
+            previous: None,
+        };
+        diesel::insert_into(addresses::table)
+            .values(&address)
+            .execute(&mut conn)
+            .unwrap();
+
+        let stored: (String, String, String) = addresses::table
+            .select((addresses::country, addresses::alpha2, addresses::alpha3))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(stored, ("NO".into(), "SE".into(), "DNK".into()));
+
+        let found: Address = addresses::table
+            .filter(addresses::country.eq(Jurisdiction::from(Alpha2::NO)))
+            .first(&mut conn)
+            .unwrap();
+        assert_eq!(found, address);
+    }
+
+    #[test]
+    fn test_diesel_sqlite_alpha3_jurisdiction() {
+        let mut conn = connection();
+        let jurisdiction = diesel::select("NOR".into_sql::<Text>())
+            .get_result::<Jurisdiction>(&mut conn)
+            .unwrap();
+        assert_eq!(jurisdiction, Alpha2::NO);
+
+        let jurisdiction = diesel::select(None::<&str>.into_sql::<Nullable<Text>>())
+            .get_result::<Option<Jurisdiction>>(&mut conn)
+            .unwrap();
+        assert_eq!(jurisdiction, None);
+    }
+
+    /// Return the message of the error raised while deserializing a value.
+    fn deserialization_cause(err: diesel::result::Error) -> String {
+        match err {
+            diesel::result::Error::DeserializationError(err) => err
+                .source()
+                .map_or_else(|| err.to_string(), |source| source.to_string()),
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_diesel_sqlite_unknown_value() {
+        let mut conn = connection();
+        let err = diesel::select("XX".into_sql::<Text>())
+            .get_result::<Jurisdiction>(&mut conn)
+            .unwrap_err();
+        assert_eq!(
+            deserialization_cause(err),
+            r#"unrecognized jurisdiction code: "XX""#
+        );
+
+        let err = diesel::select("NOR".into_sql::<Text>())
+            .get_result::<Alpha2>(&mut conn)
+            .unwrap_err();
+        assert_eq!(
+            deserialization_cause(err),
+            r#"unrecognized alpha2 code: "NOR""#
+        );
+    }
+}
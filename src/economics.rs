@@ -0,0 +1,68 @@
+//! Economic classification of a jurisdiction.
+//!
+//! The income groups are a snapshot of the [World Bank country classifications], revised
+//! by the World Bank every fiscal year from the gross national income (GNI) per capita of
+//! the preceding calendar year. The fiscal year of the snapshot is available through the
+//! `income-group` [dataset](../dataset/index.html).
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::economics::IncomeGroup;
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! assert_eq!(norway.income_group(), Some(IncomeGroup::High));
+//! ```
+//!
+//! [World Bank country classifications]: https://datahelpdesk.worldbank.org/knowledgebase/articles/906519
+
+use serde::{Deserialize, Serialize};
+
+/// The income group of a jurisdiction, by the World Bank classification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum IncomeGroup {
+    /// Low income economies.
+    Low,
+    /// Lower-middle income economies.
+    LowerMiddle,
+    /// Upper-middle income economies.
+    UpperMiddle,
+    /// High income economies.
+    High,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_income_group() {
+        for (alpha2, group) in &[
+            (Alpha2::AF, IncomeGroup::Low),
+            (Alpha2::IN, IncomeGroup::LowerMiddle),
+            (Alpha2::BR, IncomeGroup::UpperMiddle),
+            (Alpha2::NO, IncomeGroup::High),
+        ] {
+            let jurisdiction = Jurisdiction::from(*alpha2);
+            assert_eq!(jurisdiction.income_group(), Some(*group));
+        }
+    }
+
+    #[test]
+    fn test_income_group_unclassified() {
+        assert_eq!(Jurisdiction::from(Alpha2::VE).income_group(), None);
+        assert_eq!(Jurisdiction::from(Alpha2::AQ).income_group(), None);
+    }
+
+    #[test]
+    fn test_income_group_fiscal_year() {
+        let dataset = crate::dataset::get("income-group").unwrap();
+        assert_eq!(dataset.version(), Some("FY2025"));
+    }
+
+    #[test]
+    fn test_income_group_ordering() {
+        assert!(IncomeGroup::Low < IncomeGroup::LowerMiddle);
+        assert!(IncomeGroup::UpperMiddle < IncomeGroup::High);
+    }
+}
@@ -0,0 +1,94 @@
+//! Eurostat country codes.
+//!
+//! The statistical office of the European Union identifies countries by the alpha2 code of
+//! ISO 3166, except for two jurisdictions following the usage of the European Union:
+//!
+//! | Jurisdiction   | ISO 3166 | Eurostat |
+//! |----------------|----------|----------|
+//! | Greece         | `GR`     | `EL`     |
+//! | United Kingdom | `GB`     | `UK`     |
+//!
+//! Neither `EL` nor `UK` is an assigned alpha2 code, such that parsing them as one fails.
+//! `Jurisdiction::from_eurostat` accepts both forms when joining Eurostat data:
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use std::str::FromStr;
+//!
+//! let greece = Jurisdiction::from_eurostat("EL").unwrap();
+//! assert_eq!(greece, Alpha2::GR);
+//! assert_eq!(greece.eurostat_code(), "EL");
+//! assert!(Jurisdiction::from_str("EL").is_err());
+//! ```
+
+use crate::alpha::Alpha2;
+use crate::strict::AlphaCode;
+
+/// The jurisdictions whose Eurostat code differs from their alpha2 code.
+const EXCEPTIONS: &[(Alpha2, &str)] = &[(Alpha2::GR, "EL"), (Alpha2::GB, "UK")];
+
+/// Return the Eurostat code of `alpha2`.
+pub(crate) fn code(alpha2: Alpha2) -> &'static str {
+    EXCEPTIONS
+        .iter()
+        .find(|(exception, _)| *exception == alpha2)
+        .map(|(_, code)| *code)
+        .unwrap_or(Alpha2::VARIANTS[alpha2 as usize])
+}
+
+/// Return the `Alpha2` of a Eurostat or alpha2 code, ignoring case.
+pub(crate) fn resolve(code: &str) -> Option<Alpha2> {
+    EXCEPTIONS
+        .iter()
+        .find(|(_, exception)| exception.eq_ignore_ascii_case(code))
+        .map(|(alpha2, _)| *alpha2)
+        .or_else(|| serde_plain::from_str(code).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alpha2, Jurisdiction};
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_eurostat_exceptions() {
+        let greece = Jurisdiction::from(Alpha2::GR);
+        assert_eq!(greece.eurostat_code(), "EL");
+        assert_eq!(Jurisdiction::from_eurostat("EL"), Some(greece.clone()));
+        assert_eq!(Jurisdiction::from_eurostat("GR"), Some(greece));
+
+        let united_kingdom = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(united_kingdom.eurostat_code(), "UK");
+        assert_eq!(
+            Jurisdiction::from_eurostat("UK"),
+            Some(united_kingdom.clone())
+        );
+        assert_eq!(
+            Jurisdiction::from_eurostat("uk"),
+            Some(united_kingdom.clone())
+        );
+        assert_eq!(Jurisdiction::from_eurostat("GB"), Some(united_kingdom));
+    }
+
+    #[test]
+    fn test_eurostat_alpha2() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.eurostat_code(), "NO");
+        assert_eq!(Jurisdiction::from_eurostat("NO"), Some(norway));
+        assert_eq!(Jurisdiction::from_eurostat("XX"), None);
+        assert_eq!(Jurisdiction::from_eurostat("NOR"), None);
+
+        for jurisdiction in Jurisdiction::all() {
+            let code = jurisdiction.eurostat_code();
+            assert_eq!(Jurisdiction::from_eurostat(code), Some(jurisdiction));
+        }
+    }
+
+    #[test]
+    fn test_eurostat_not_iso() {
+        assert!(Jurisdiction::from_str("EL").is_err());
+        assert!(Jurisdiction::from_str("UK").is_err());
+        assert!(serde_plain::from_str::<Alpha2>("EL").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+//! C foreign function interface.
+//!
+//! A [Jurisdiction] is passed across the interface by value, as an opaque pointer sized
+//! handle. Handles must only be obtained from [jurisdiction_from_alpha], and remain valid
+//! for the lifetime of the program. The functions taking pointers return a
+//! [JurisdictionStatus], failing on null pointers rather than dereferencing them. A null
+//! handle is accepted by every function, which return 0 or null for it where they return
+//! no status.
+//!
+//! The C declarations are found in `include/jurisdiction.h`, generated by [cbindgen]
+//! through `cbindgen --config cbindgen.toml --output include/jurisdiction.h`. Link with a
+//! static library of the crate, e.g. built by `cargo rustc --release --lib --features ffi
+//! --crate-type staticlib`.
+//!
+//! ```c
+//! Jurisdiction jurisdiction;
+//! if (jurisdiction_from_alpha("NOR", &jurisdiction) == JURISDICTION_STATUS_OK) {
+//!     char alpha2[3];
+//!     jurisdiction_alpha2(jurisdiction, alpha2);
+//!     printf("%s %s\n", alpha2, jurisdiction_name(jurisdiction)); // NO Norway
+//! }
+//! ```
+//!
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+//! [JurisdictionStatus]: enum.JurisdictionStatus.html
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+use crate::Jurisdiction;
+
+use lazy_static::lazy_static;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::str::FromStr;
+
+lazy_static! {
+    /// The NUL terminated names of all jurisdictions, in the order of `Jurisdiction::all`.
+    static ref NAMES: Vec<CString> = Jurisdiction::all()
+        .map(|jurisdiction| CString::new(jurisdiction.name()).expect("names contain no NUL"))
+        .collect();
+}
+
+/// The status returned by the functions of the interface.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JurisdictionStatus {
+    /// The function succeeded.
+    Ok = 0,
+    /// A pointer argument was null.
+    NullPointer = 1,
+    /// The code was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The code was not an alpha2 or alpha3 code of a jurisdiction.
+    UnrecognizedCode = 3,
+}
+
+/// Parse the NUL terminated alpha2 or alpha3 `code` into the jurisdiction pointed to by
+/// `out`, returning a [JurisdictionStatus](enum.JurisdictionStatus.html).
+///
+/// `out` is left untouched unless the status is `Ok`.
+///
+/// # Safety
+///
+/// `code` must be null or point to a NUL terminated string, and `out` must be null or
+/// be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn jurisdiction_from_alpha(
+    code: *const c_char,
+    out: *mut Jurisdiction,
+) -> c_int {
+    if code.is_null() || out.is_null() {
+        return JurisdictionStatus::NullPointer as c_int;
+    }
+    let code = match CStr::from_ptr(code).to_str() {
+        Ok(code) => code,
+        Err(_) => return JurisdictionStatus::InvalidUtf8 as c_int,
+    };
+    match Jurisdiction::from_str(code) {
+        Ok(jurisdiction) => {
+            out.write(jurisdiction);
+            JurisdictionStatus::Ok as c_int
+        }
+        Err(_) => JurisdictionStatus::UnrecognizedCode as c_int,
+    }
+}
+
+/// Write the NUL terminated alpha2 code of `jurisdiction` into the three characters
+/// pointed to by `out`, returning a [JurisdictionStatus](enum.JurisdictionStatus.html).
+///
+/// # Safety
+///
+/// `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha], and
+/// `out` must be null or be valid for writes of three characters.
+///
+/// [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+#[no_mangle]
+pub unsafe extern "C" fn jurisdiction_alpha2(
+    jurisdiction: Option<Jurisdiction>,
+    out: *mut c_char,
+) -> c_int {
+    let jurisdiction = match jurisdiction {
+        Some(jurisdiction) if !out.is_null() => jurisdiction,
+        _ => return JurisdictionStatus::NullPointer as c_int,
+    };
+    let alpha2 = jurisdiction.alpha2().to_string();
+    for (i, byte) in alpha2.bytes().chain(std::iter::once(0)).enumerate() {
+        out.add(i).write(byte as c_char);
+    }
+    JurisdictionStatus::Ok as c_int
+}
+
+/// Return the ISO 3166 numeric country code of `jurisdiction`, or 0 if it is null.
+///
+/// # Safety
+///
+/// `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha].
+///
+/// [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+#[no_mangle]
+pub unsafe extern "C" fn jurisdiction_country_code(jurisdiction: Option<Jurisdiction>) -> u16 {
+    jurisdiction.map_or(0, |jurisdiction| jurisdiction.country_code())
+}
+
+/// Return the NUL terminated common english name of `jurisdiction`, or null if it is null.
+///
+/// The name is static, and must not be freed.
+///
+/// # Safety
+///
+/// `jurisdiction` must be null or a handle obtained from [jurisdiction_from_alpha].
+///
+/// [jurisdiction_from_alpha]: fn.jurisdiction_from_alpha.html
+#[no_mangle]
+pub unsafe extern "C" fn jurisdiction_name(jurisdiction: Option<Jurisdiction>) -> *const c_char {
+    match jurisdiction {
+        Some(jurisdiction) => NAMES[jurisdiction.position()].as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    use std::mem::MaybeUninit;
+
+    fn from_alpha(code: &[u8]) -> Result<Jurisdiction, c_int> {
+        let mut out = MaybeUninit::<Jurisdiction>::uninit();
+        let status =
+            unsafe { jurisdiction_from_alpha(code.as_ptr() as *const c_char, out.as_mut_ptr()) };
+        if status == JurisdictionStatus::Ok as c_int {
+            Ok(unsafe { out.assume_init() })
+        } else {
+            Err(status)
+        }
+    }
+
+    #[test]
+    fn test_ffi_from_alpha() {
+        assert_eq!(from_alpha(b"NO\0").unwrap(), Alpha2::NO);
+        assert_eq!(from_alpha(b"NOR\0").unwrap(), Alpha2::NO);
+        assert_eq!(
+            from_alpha(b"XX\0"),
+            Err(JurisdictionStatus::UnrecognizedCode as c_int)
+        );
+        assert_eq!(
+            from_alpha(b"N\xffO\0"),
+            Err(JurisdictionStatus::InvalidUtf8 as c_int)
+        );
+
+        let mut out = MaybeUninit::<Jurisdiction>::uninit();
+        let status = unsafe { jurisdiction_from_alpha(ptr::null(), out.as_mut_ptr()) };
+        assert_eq!(status, JurisdictionStatus::NullPointer as c_int);
+        let code = b"NO\0".as_ptr() as *const c_char;
+        let status = unsafe { jurisdiction_from_alpha(code, ptr::null_mut()) };
+        assert_eq!(status, JurisdictionStatus::NullPointer as c_int);
+    }
+
+    #[test]
+    fn test_ffi_accessors() {
+        let norway = Some(from_alpha(b"NOR\0").unwrap());
+        assert_eq!(unsafe { jurisdiction_country_code(norway.clone()) }, 578);
+
+        let mut alpha2 = [1 as c_char; 3];
+        let status = unsafe { jurisdiction_alpha2(norway.clone(), alpha2.as_mut_ptr()) };
+        assert_eq!(status, JurisdictionStatus::Ok as c_int);
+        assert_eq!(alpha2, [b'N' as c_char, b'O' as c_char, 0]);
+        let status = unsafe { jurisdiction_alpha2(norway.clone(), ptr::null_mut()) };
+        assert_eq!(status, JurisdictionStatus::NullPointer as c_int);
+
+        let name = unsafe { CStr::from_ptr(jurisdiction_name(norway)) };
+        assert_eq!(name.to_str(), Ok("Norway"));
+    }
+
+    #[test]
+    fn test_ffi_null_handle() {
+        // Call through the pointer typed signatures of the C declarations, passing NULL
+        let country_code: unsafe extern "C" fn(*const ()) -> u16 = unsafe {
+            std::mem::transmute(
+                jurisdiction_country_code as unsafe extern "C" fn(Option<Jurisdiction>) -> u16,
+            )
+        };
+        let name: unsafe extern "C" fn(*const ()) -> *const c_char = unsafe {
+            std::mem::transmute(
+                jurisdiction_name as unsafe extern "C" fn(Option<Jurisdiction>) -> *const c_char,
+            )
+        };
+        let alpha2_code: unsafe extern "C" fn(*const (), *mut c_char) -> c_int = unsafe {
+            std::mem::transmute(
+                jurisdiction_alpha2
+                    as unsafe extern "C" fn(Option<Jurisdiction>, *mut c_char) -> c_int,
+            )
+        };
+
+        assert_eq!(unsafe { country_code(ptr::null()) }, 0);
+        assert!(unsafe { name(ptr::null()) }.is_null());
+        let mut alpha2 = [1 as c_char; 3];
+        let status = unsafe { alpha2_code(ptr::null(), alpha2.as_mut_ptr()) };
+        assert_eq!(status, JurisdictionStatus::NullPointer as c_int);
+        assert_eq!(alpha2, [1 as c_char; 3]);
+
+        assert_eq!(unsafe { jurisdiction_country_code(None) }, 0);
+        assert!(unsafe { jurisdiction_name(None) }.is_null());
+    }
+
+    #[test]
+    fn test_ffi_handle_layout() {
+        assert_eq!(
+            std::mem::size_of::<Jurisdiction>(),
+            std::mem::size_of::<*const ()>()
+        );
+    }
+
+    #[test]
+    fn test_ffi_header() {
+        let crate_dir = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap();
+        let mut header = Vec::new();
+        cbindgen::Builder::new()
+            .with_crate(crate_dir)
+            .with_config(config)
+            .generate()
+            .unwrap()
+            .write(&mut header);
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            include_str!("../include/jurisdiction.h"),
+            "regenerate include/jurisdiction.h with cbindgen"
+        );
+    }
+}
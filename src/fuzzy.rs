@@ -0,0 +1,205 @@
+//! Fuzzy search of jurisdictions by name.
+//!
+//! Queries are scored against the [ASCII folded](struct.Jurisdiction.html#method.name_ascii)
+//! names by their Jaro-Winkler similarity, from `0.0` for nothing in common to `1.0` for
+//! an exact match. As the ISO 3166 names are often longer than the names in everyday use,
+//! such as `united states of america`, a query is scored against the leading words of a
+//! name as well, as many as the query has, weighted slightly below a match of the whole
+//! name. The scores are computed on the stack, without allocating beyond the folded query.
+
+use crate::search::fold_query;
+use crate::Jurisdiction;
+
+use std::cmp::Ordering;
+
+/// The score below which a name is not considered a match.
+pub(crate) const THRESHOLD: f32 = 0.85;
+
+/// The weight of a match of the leading words of a name, below a match of the whole name.
+const PARTIAL_WEIGHT: f32 = 0.95;
+
+/// The number of bytes compared of queries and names, more than the longest name.
+const MAX_LEN: usize = 64;
+
+/// Return the Jaro-Winkler similarity of `a` and `b`, comparing at most [MAX_LEN] bytes.
+fn jaro_winkler(a: &[u8], b: &[u8]) -> f32 {
+    let a = &a[..a.len().min(MAX_LEN)];
+    let b = &b[..b.len().min(MAX_LEN)];
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = [false; MAX_LEN];
+    let mut b_matched = [false; MAX_LEN];
+    let mut matches = 0;
+    for (i, byte) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *byte {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // The matched bytes of `b`, in order, against the matched bytes of `a`
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, matched)| **matched);
+    let transpositions = a
+        .iter()
+        .zip(&a_matched)
+        .filter(|(_, matched)| **matched)
+        .zip(b_matches)
+        .filter(|((a, _), (b, _))| a != b)
+        .count();
+
+    let m = matches as f32;
+    let jaro =
+        (m / a.len() as f32 + m / b.len() as f32 + (m - transpositions as f32 / 2.0) / m) / 3.0;
+    let prefix = a.iter().zip(b).take(4).take_while(|(a, b)| a == b).count();
+    jaro + prefix as f32 * 0.1 * (1.0 - jaro)
+}
+
+/// A scored match, ordered by score and then by the similarity of the whole name.
+struct Score {
+    score: f32,
+    whole: f32,
+    position: usize,
+}
+
+impl Score {
+    fn of(query: &str, words: usize, jurisdiction: &Jurisdiction) -> Score {
+        let name = jurisdiction.name_ascii();
+        let whole = jaro_winkler(query.as_bytes(), name.as_bytes());
+        // The leading words of the name, as many as the query has
+        let partial = match name.match_indices(' ').nth(words - 1) {
+            Some((end, _)) => {
+                PARTIAL_WEIGHT * jaro_winkler(query.as_bytes(), &name.as_bytes()[..end])
+            }
+            None => 0.0,
+        };
+        Score {
+            score: whole.max(partial),
+            whole,
+            position: jurisdiction.position(),
+        }
+    }
+
+    /// Order the better match first, breaking ties by the position of the jurisdiction.
+    fn rank(&self, other: &Score) -> Ordering {
+        // The scores are never NaN
+        other
+            .score
+            .partial_cmp(&self.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                other
+                    .whole
+                    .partial_cmp(&self.whole)
+                    .unwrap_or(Ordering::Equal)
+            })
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+/// Return the scores of every jurisdiction matching `query` at or above the threshold.
+fn scores(query: &str) -> impl Iterator<Item = (Score, Jurisdiction)> {
+    let mut query = fold_query(query);
+    query.truncate(query.trim_end().len());
+    let words = query.split(' ').count();
+    // An empty query scores zero against every name
+    Jurisdiction::all()
+        .map(move |jurisdiction| (Score::of(&query, words, &jurisdiction), jurisdiction))
+        .filter(|(score, _)| score.score >= THRESHOLD)
+}
+
+/// Return the jurisdiction best matching `query`, along with its score.
+pub(crate) fn best_match(query: &str) -> Option<(Jurisdiction, f32)> {
+    scores(query)
+        .min_by(|(a, _), (b, _)| a.rank(b))
+        .map(|(score, jurisdiction)| (jurisdiction, score.score))
+}
+
+/// Return the at most `limit` jurisdictions best matching `query`, best match first.
+pub(crate) fn matches(query: &str, limit: usize) -> Vec<(Jurisdiction, f32)> {
+    let mut found: Vec<_> = scores(query).collect();
+    found.sort_by(|(a, _), (b, _)| a.rank(b));
+    found.truncate(limit);
+    found
+        .into_iter()
+        .map(|(score, jurisdiction)| (jurisdiction, score.score))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    fn best(query: &str) -> Option<Alpha2> {
+        best_match(query).map(|(jurisdiction, _)| jurisdiction.alpha2())
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        assert_eq!(jaro_winkler(b"norway", b"norway"), 1.0);
+        assert_eq!(jaro_winkler(b"", b"norway"), 0.0);
+        assert_eq!(jaro_winkler(b"abc", b"xyz"), 0.0);
+        let martha = jaro_winkler(b"martha", b"marhta");
+        assert!((martha - 0.961).abs() < 0.001, "{}", martha);
+        assert_eq!(
+            jaro_winkler(b"norway", b"yawron"),
+            jaro_winkler(b"yawron", b"norway")
+        );
+    }
+
+    #[test]
+    fn test_best_match_misspelled() {
+        assert_eq!(best("Untied States"), Some(Alpha2::US));
+        assert_eq!(best("united states"), Some(Alpha2::US));
+        assert_eq!(best("Viet Nam"), Some(Alpha2::VN));
+        assert_eq!(best("Vietnam"), Some(Alpha2::VN));
+        assert_eq!(best("Norwya"), Some(Alpha2::NO));
+        assert_eq!(best("Niger"), Some(Alpha2::NE));
+    }
+
+    #[test]
+    fn test_best_match_score() {
+        let (norway, score) = best_match("norway").unwrap();
+        assert_eq!(norway, Alpha2::NO);
+        assert_eq!(score, 1.0);
+        let (_, score) = best_match("Untied States").unwrap();
+        assert!((THRESHOLD..1.0).contains(&score), "{}", score);
+    }
+
+    #[test]
+    fn test_matches_ranked() {
+        let found: Vec<_> = matches("congo", 5)
+            .into_iter()
+            .map(|(jurisdiction, _)| jurisdiction.alpha2())
+            .collect();
+        assert_eq!(&found[..2], [Alpha2::CG, Alpha2::CD]);
+
+        let found = matches("korea", 5);
+        let korea: Vec<_> = found.iter().map(|(j, _)| j.alpha2()).collect();
+        assert_eq!(&korea[..2], [Alpha2::KR, Alpha2::KP]);
+        assert!(found.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        assert_eq!(matches("united", 2).len(), 2);
+        assert!(matches("united", 0).is_empty());
+    }
+
+    #[test]
+    fn test_matches_gibberish() {
+        for query in &["xqzvbkw", "qqqq", "", "   ", "zzzyyyxxx", "12345"] {
+            assert_eq!(best_match(query), None, "{:?}", query);
+            assert!(matches(query, 10).is_empty(), "{:?}", query);
+        }
+    }
+}
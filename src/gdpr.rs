@@ -0,0 +1,116 @@
+//! Adequacy decisions of the European Union under the GDPR.
+//!
+//! Personal data may be transferred from the European Economic Area to a third country
+//! without further safeguards once the European Commission has decided that the country
+//! ensures an adequate level of protection, under Article 45 of the GDPR. Some decisions
+//! are partial, covering only certain recipients, such as the commercial organisations of
+//! Canada or the organisations of the United States certified under the EU-US Data Privacy
+//! Framework.
+//!
+//! The statuses are a snapshot as of the version of the `eu-adequacy`
+//! [dataset](../dataset/index.html). Decisions are reviewed and may be struck down, so the
+//! snapshot is **not** legal advice on whether a transfer is lawful today.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::gdpr::AdequacyStatus;
+//!
+//! let switzerland = Jurisdiction::from(Alpha2::CH);
+//! assert_eq!(
+//!     switzerland.eu_adequacy(),
+//!     AdequacyStatus::Adequate { since: "2000-07-26" }
+//! );
+//! ```
+
+use serde::Serialize;
+
+/// The status of a jurisdiction by the adequacy decisions of the European Commission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub enum AdequacyStatus {
+    /// Adequate by a decision of the Commission.
+    Adequate {
+        /// The date the decision was adopted, as `YYYY-MM-DD`.
+        since: &'static str,
+    },
+    /// Adequate only for the recipients within the scope of a decision of the Commission.
+    PartiallyAdequate {
+        /// The date the decision was adopted, as `YYYY-MM-DD`.
+        since: &'static str,
+        /// The recipients covered by the decision.
+        scope: &'static str,
+    },
+    /// No adequacy decision is in force.
+    ///
+    /// Jurisdictions within the scope of the GDPR itself, such as the member states of
+    /// the European Union, need no decision and have this status.
+    NotAdequate,
+}
+
+impl AdequacyStatus {
+    /// Return whether any adequacy decision is in force, including partial ones.
+    pub fn is_adequate(&self) -> bool {
+        !matches!(self, AdequacyStatus::NotAdequate)
+    }
+
+    /// Return the date the adequacy decision was adopted, as `YYYY-MM-DD`.
+    pub fn since(&self) -> Option<&'static str> {
+        match self {
+            AdequacyStatus::Adequate { since } => Some(since),
+            AdequacyStatus::PartiallyAdequate { since, .. } => Some(since),
+            AdequacyStatus::NotAdequate => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_eu_adequacy_adequate() {
+        assert_eq!(
+            Jurisdiction::from(Alpha2::CH).eu_adequacy(),
+            AdequacyStatus::Adequate {
+                since: "2000-07-26"
+            }
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::JP).eu_adequacy(),
+            AdequacyStatus::Adequate {
+                since: "2019-01-23"
+            }
+        );
+    }
+
+    #[test]
+    fn test_eu_adequacy_partial() {
+        let us = Jurisdiction::from(Alpha2::US).eu_adequacy();
+        assert!(matches!(us, AdequacyStatus::PartiallyAdequate { .. }));
+        assert!(us.is_adequate());
+        assert_eq!(us.since(), Some("2023-07-10"));
+
+        let canada = Jurisdiction::from(Alpha2::CA).eu_adequacy();
+        assert!(matches!(canada, AdequacyStatus::PartiallyAdequate { .. }));
+    }
+
+    #[test]
+    fn test_eu_adequacy_not_adequate() {
+        let china = Jurisdiction::from(Alpha2::CN).eu_adequacy();
+        assert_eq!(china, AdequacyStatus::NotAdequate);
+        assert!(!china.is_adequate());
+        assert_eq!(china.since(), None);
+
+        // Within the scope of the GDPR itself
+        assert_eq!(
+            Jurisdiction::from(Alpha2::NO).eu_adequacy(),
+            AdequacyStatus::NotAdequate
+        );
+    }
+
+    #[test]
+    fn test_eu_adequacy_snapshot() {
+        let dataset = crate::dataset::get("eu-adequacy").unwrap();
+        assert_eq!(dataset.version(), Some("2025-01-01"));
+    }
+}
@@ -3,10 +3,49 @@
 pub mod alpha {
     include!(concat!(env!("OUT_DIR"), "/src/generated/alpha.rs"));
 }
+#[cfg(feature = "aviation")]
+pub mod aviation {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/aviation.rs"));
+}
+#[cfg(feature = "cldr-containment")]
+pub mod cldr {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/cldr.rs"));
+}
+pub mod dataset {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/dataset.rs"));
+}
 pub mod definition {
     include!(concat!(env!("OUT_DIR"), "/src/generated/definition.rs"));
 }
+#[cfg(feature = "gs1")]
+pub mod gs1 {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/gs1.rs"));
+}
+#[cfg(feature = "ip")]
+pub mod ip {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/ip.rs"));
+}
+#[cfg(feature = "radio")]
+pub mod radio {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/radio.rs"));
+}
 #[cfg(feature = "region")]
 pub mod region {
     include!(concat!(env!("OUT_DIR"), "/src/generated/region.rs"));
 }
+#[cfg(feature = "political")]
+pub mod sets {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/sets.rs"));
+}
+#[cfg(feature = "subdivision")]
+pub mod subdivision {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/subdivision.rs"));
+}
+#[cfg(feature = "telephony")]
+pub mod telephony {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/telephony.rs"));
+}
+#[cfg(feature = "vat")]
+pub mod vat {
+    include!(concat!(env!("OUT_DIR"), "/src/generated/vat.rs"));
+}
@@ -0,0 +1,217 @@
+//! [async-graphql] support.
+//!
+//! [Jurisdiction], [Alpha2] and [Alpha3] are scalars of the same name, represented by a
+//! string holding their alpha code. A `Jurisdiction` is output as its alpha2 code, and is
+//! input from either an alpha2 or an alpha3 code. The region classifications are enums,
+//! named after their variants in screaming snake case, e.g. `NORTHERN_EUROPE`.
+//!
+//! [async-graphql]: https://docs.rs/async-graphql
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use std::str::FromStr;
+
+/// A jurisdiction, input as its ISO 3166-1 alpha-2 or alpha-3 code and output as its
+/// alpha-2 code, e.g. `NO`.
+#[Scalar(
+    name = "Jurisdiction",
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+impl ScalarType for Jurisdiction {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(code) => Jurisdiction::from_str(&code).map_err(InputValueError::custom),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.alpha2().to_string())
+    }
+}
+
+/// An ISO 3166-1 alpha-2 country code, e.g. `NO`.
+#[Scalar(
+    name = "Alpha2",
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+impl ScalarType for Alpha2 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(code) => serde_plain::from_str(&code).map_err(|_| {
+                InputValueError::custom(format!("unrecognized alpha2 code: {}", code))
+            }),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// An ISO 3166-1 alpha-3 country code, e.g. `NOR`.
+#[Scalar(
+    name = "Alpha3",
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+impl ScalarType for Alpha3 {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match value {
+            Value::String(code) => serde_plain::from_str(&code).map_err(|_| {
+                InputValueError::custom(format!("unrecognized alpha3 code: {}", code))
+            }),
+            value => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Request, Schema, Variables};
+    use futures_executor::block_on;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn jurisdiction(&self, country: Jurisdiction) -> Jurisdiction {
+            country
+        }
+
+        async fn alpha3(&self, country: Alpha2) -> Alpha3 {
+            Jurisdiction::from(country).alpha3()
+        }
+
+        #[cfg(feature = "region")]
+        async fn region(&self, country: Jurisdiction) -> crate::region::Region {
+            country.region()
+        }
+    }
+
+    fn execute(query: &str, variables: serde_json::Value) -> serde_json::Value {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let request = Request::new(query).variables(Variables::from_json(variables));
+        let response = block_on(schema.execute(request));
+        serde_json::to_value(response).unwrap()
+    }
+
+    #[test]
+    fn test_graphql_round_trip() {
+        let response = execute(
+            r#"query($country: Jurisdiction!) {
+                jurisdiction(country: $country)
+                alpha3(country: "SE")
+            }"#,
+            serde_json::json!({ "country": "NOR" }),
+        );
+        assert_eq!(
+            response,
+            serde_json::json!({ "data": { "jurisdiction": "NO", "alpha3": "SWE" } })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_graphql_region_enum() {
+        let response = execute(r#"{ region(country: "NO") }"#, serde_json::Value::Null);
+        assert_eq!(
+            response,
+            serde_json::json!({ "data": { "region": "EUROPE" } })
+        );
+    }
+
+    #[test]
+    fn test_graphql_invalid_input() {
+        let response = execute(
+            r#"query($country: Jurisdiction!) { jurisdiction(country: $country) }"#,
+            serde_json::json!({ "country": "XX" }),
+        );
+        let message = response["errors"][0]["message"].as_str().unwrap();
+        assert!(message.contains("XX"), "{}", message);
+        assert!(
+            message.contains(r#"Failed to parse "Jurisdiction""#),
+            "{}",
+            message
+        );
+
+        let response = execute(r#"{ alpha3(country: "NOR") }"#, serde_json::Value::Null);
+        let message = response["errors"][0]["message"].as_str().unwrap();
+        assert!(
+            message.contains("unrecognized alpha2 code: NOR"),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_graphql_sdl() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let sdl = schema.sdl();
+        // Compare the types of the schema, leaving out the built-in directives
+        let types = &sdl[..sdl.find("\"\"\"\nDirects").unwrap()];
+        assert_eq!(
+            types,
+            r#""""
+An ISO 3166-1 alpha-2 country code, e.g. `NO`.
+"""
+scalar Alpha2
+
+"""
+An ISO 3166-1 alpha-3 country code, e.g. `NOR`.
+"""
+scalar Alpha3
+
+"""
+A jurisdiction, input as its ISO 3166-1 alpha-2 or alpha-3 code and output as its
+alpha-2 code, e.g. `NO`.
+"""
+scalar Jurisdiction
+
+type Query {
+	jurisdiction(country: Jurisdiction!): Jurisdiction!
+	alpha3(country: Alpha2!): Alpha3!
+	region(country: Jurisdiction!): Region!
+}
+
+"""
+The high level region a Jurisdiction may zone to.
+"""
+enum Region {
+	AFRICA
+	ASIA
+	EUROPE
+	OCEANIA
+	AMERICAS
+	"""
+	Outside of any region, as M49 places Antarctica directly beneath the world.
+	"""
+	UNDEFINED
+}
+
+"#
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Group jurisdictions by their region classification.
+
+use crate::generated::region::REGION_COUNTS;
+use crate::region::{IntermediateRegion, Region, SubRegion};
+use crate::Jurisdiction;
+
+use std::collections::BTreeMap;
+
+/// Group jurisdictions by their [Region].
+///
+/// Each group retains the order in which its jurisdictions were given.
+///
+/// ```rust
+/// use jurisdiction::{Alpha2, Jurisdiction};
+/// use jurisdiction::region::Region;
+///
+/// let jurisdictions = vec![Alpha2::NO, Alpha2::JP, Alpha2::SE].into_iter().map(Jurisdiction::from);
+/// let groups = jurisdiction::group_by_region(jurisdictions);
+/// assert_eq!(groups[&Region::Europe], &[Alpha2::NO, Alpha2::SE]);
+/// assert_eq!(groups[&Region::Asia], &[Alpha2::JP]);
+/// ```
+///
+/// [Region]: region/enum.Region.html
+pub fn group_by_region<I>(jurisdictions: I) -> BTreeMap<Region, Vec<Jurisdiction>>
+where
+    I: IntoIterator<Item = Jurisdiction>,
+{
+    group_by(jurisdictions, |jurisdiction| jurisdiction.region())
+}
+
+/// Group jurisdictions by their [SubRegion].
+///
+/// Each group retains the order in which its jurisdictions were given.
+///
+/// [SubRegion]: region/enum.SubRegion.html
+pub fn group_by_sub_region<I>(jurisdictions: I) -> BTreeMap<SubRegion, Vec<Jurisdiction>>
+where
+    I: IntoIterator<Item = Jurisdiction>,
+{
+    group_by(jurisdictions, |jurisdiction| jurisdiction.sub_region())
+}
+
+/// Group jurisdictions by their [IntermediateRegion].
+///
+/// Each group retains the order in which its jurisdictions were given. Jurisdictions
+/// without an intermediate region are grouped by [Undefined].
+///
+/// [IntermediateRegion]: region/enum.IntermediateRegion.html
+/// [Undefined]: region/enum.IntermediateRegion.html#variant.Undefined
+pub fn group_by_intermediate_region<I>(
+    jurisdictions: I,
+) -> BTreeMap<IntermediateRegion, Vec<Jurisdiction>>
+where
+    I: IntoIterator<Item = Jurisdiction>,
+{
+    group_by(jurisdictions, |jurisdiction| {
+        jurisdiction.intermediate_region()
+    })
+}
+
+/// Return the number of jurisdictions zoning to each [Region], in the order of the
+/// regions, without listing the jurisdictions.
+///
+/// The counts are computed by the build script. See `Region::jurisdiction_count` and its
+/// equivalents of the sub and intermediate regions for a single region.
+///
+/// ```rust
+/// use jurisdiction::region::Region;
+///
+/// let counts = jurisdiction::count_by_region();
+/// assert_eq!(counts[0], (Region::Africa, Region::Africa.jurisdiction_count()));
+/// assert_eq!(
+///     counts.iter().map(|(_, count)| count).sum::<usize>(),
+///     jurisdiction::Jurisdiction::all().count()
+/// );
+/// ```
+///
+/// [Region]: region/enum.Region.html
+pub fn count_by_region() -> &'static [(Region, usize)] {
+    REGION_COUNTS
+}
+
+fn group_by<K, I, F>(jurisdictions: I, key: F) -> BTreeMap<K, Vec<Jurisdiction>>
+where
+    K: Ord,
+    I: IntoIterator<Item = Jurisdiction>,
+    F: Fn(&Jurisdiction) -> K,
+{
+    let mut groups = BTreeMap::new();
+    for jurisdiction in jurisdictions {
+        groups
+            .entry(key(&jurisdiction))
+            .or_insert_with(Vec::new)
+            .push(jurisdiction);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_group_by_region_counts() {
+        let groups = group_by_region(Jurisdiction::all());
+        let count: usize = groups.values().map(Vec::len).sum();
+        assert_eq!(count, Jurisdiction::all().count());
+
+        let groups = group_by_sub_region(Jurisdiction::all());
+        let count: usize = groups.values().map(Vec::len).sum();
+        assert_eq!(count, Jurisdiction::all().count());
+
+        let groups = group_by_intermediate_region(Jurisdiction::all());
+        let count: usize = groups.values().map(Vec::len).sum();
+        assert_eq!(count, Jurisdiction::all().count());
+    }
+
+    #[test]
+    fn test_group_by_region_ordering() {
+        let groups = Jurisdiction::all_grouped_by_region();
+        for (region, jurisdictions) in groups.iter() {
+            assert!(jurisdictions.iter().all(|j| j.region() == *region));
+            let expected: Vec<_> = Jurisdiction::all()
+                .filter(|j| j.region() == *region)
+                .collect();
+            assert_eq!(jurisdictions, &expected);
+        }
+        assert_eq!(groups, Jurisdiction::all_grouped_by_region());
+    }
+
+    #[test]
+    fn test_group_by_sub_region_subset() {
+        let nordics = vec![Alpha2::SE, Alpha2::DK, Alpha2::NO];
+        let groups = group_by_sub_region(nordics.into_iter().map(Jurisdiction::from));
+        assert_eq!(groups.len(), 1);
+        assert_eq!(
+            groups[&SubRegion::NorthernEurope],
+            &[Alpha2::SE, Alpha2::DK, Alpha2::NO]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! [GS1] barcode prefixes.
+//!
+//! The first three digits of an EAN/UPC barcode, the GS1 prefix, identify the GS1 member
+//! organisation that issued the company prefix of the barcode. Each member organisation
+//! is allocated one or more ranges of prefixes, and some ranges are shared by several
+//! jurisdictions, such as `000`–`019` for the United States and Canada.
+//!
+//! Note that the prefix identifies the organisation issuing the barcode, and therefore the
+//! jurisdiction the brand owner registered with, **not** the country of manufacture or origin
+//! of the product.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! let issuer = jurisdiction::gs1::issuer_of("7038010009457").unwrap();
+//! assert_eq!(issuer, Alpha2::NO);
+//! ```
+//!
+//! [GS1]: https://www.gs1.org/standards/id-keys/company-prefix
+
+use crate::generated::gs1::GENERATED_GS1_PREFIXES;
+use crate::Jurisdiction;
+
+use std::cmp::Ordering;
+
+/// Return the [Jurisdiction] of the GS1 member organisation issuing a barcode.
+///
+/// Accepts GTIN-8, GTIN-12 (UPC-A), GTIN-13 (EAN-13) and GTIN-14 barcodes made up of
+/// digits only; the check digit is not validated. Ranges shared by several jurisdictions
+/// resolve to the jurisdiction of the member organisation administering them, e.g. the
+/// United States for `000`–`019`. Prefixes not allocated to a jurisdiction, including
+/// restricted circulation numbers, coupons, ISBN and ISSN, return `None`.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn issuer_of(barcode: &str) -> Option<Jurisdiction> {
+    if !barcode.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let prefix = match barcode.len() {
+        8 | 13 => barcode[..3].parse::<u16>().ok()?,
+        // UPC-A is an EAN-13 with an implicit leading zero
+        12 => barcode[..2].parse::<u16>().ok()?,
+        // GTIN-14 is an EAN-13 preceded by a packaging indicator digit
+        14 => barcode[1..4].parse::<u16>().ok()?,
+        _ => return None,
+    };
+
+    GENERATED_GS1_PREFIXES
+        .binary_search_by(|(range, _)| {
+            if *range.end() < prefix {
+                Ordering::Less
+            } else if *range.start() > prefix {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| Jurisdiction::from(GENERATED_GS1_PREFIXES[index].1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_gs1_prefixes_norway() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.gs1_prefixes(), &[700..=709]);
+
+        assert_eq!(issuer_of("7038010009457"), Some(norway.clone()));
+        assert_eq!(issuer_of("7090000000003"), Some(norway));
+    }
+
+    #[test]
+    fn test_gs1_prefixes_shared_range() {
+        let us = Jurisdiction::from(Alpha2::US);
+        let canada = Jurisdiction::from(Alpha2::CA);
+        assert!(us.gs1_prefixes().contains(&(0..=19)));
+        assert!(canada.gs1_prefixes().contains(&(0..=19)));
+        assert!(canada.gs1_prefixes().contains(&(754..=755)));
+
+        // The shared range resolves to the member organisation, the United States
+        assert_eq!(issuer_of("0012345678905"), Some(us.clone()));
+        assert_eq!(issuer_of("012345678905"), Some(us));
+        assert_eq!(issuer_of("7541234567890"), Some(canada));
+    }
+
+    #[test]
+    fn test_gs1_unassigned_prefix() {
+        // Restricted circulation, unallocated and ISBN ranges
+        assert_eq!(issuer_of("2001234567890"), None);
+        assert_eq!(issuer_of("6701234567890"), None);
+        assert_eq!(issuer_of("9780306406157"), None);
+        assert!(Jurisdiction::from(Alpha2::AQ).gs1_prefixes().is_empty());
+    }
+
+    #[test]
+    fn test_gs1_issuer_of_gtin14() {
+        assert_eq!(issuer_of("17038010009454"), Some(Alpha2::NO.into()));
+    }
+
+    #[test]
+    fn test_gs1_issuer_of_invalid() {
+        assert_eq!(issuer_of(""), None);
+        assert_eq!(issuer_of("703"), None);
+        assert_eq!(issuer_of("703801000945X"), None);
+        assert_eq!(issuer_of("703801000945٣"), None);
+    }
+}
@@ -0,0 +1,194 @@
+//! Jurisdictions of HTTP headers.
+//!
+//! [from_accept_language] picks the jurisdictions preferred by a client from its
+//! `Accept-Language` header, as specified by [RFC 9110], by the region subtags of the
+//! [BCP 47] language tags of the header.
+//!
+//! ```rust
+//! use jurisdiction::{http, Alpha2, Jurisdiction};
+//!
+//! let preferred = http::from_accept_language("nb-NO, en-GB;q=0.8, en;q=0.5");
+//! assert_eq!(
+//!     preferred,
+//!     [(Jurisdiction::from(Alpha2::NO), 1.0), (Jurisdiction::from(Alpha2::GB), 0.8)]
+//! );
+//! ```
+//!
+//! [from_accept_language]: fn.from_accept_language.html
+//! [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+//! [BCP 47]: https://www.rfc-editor.org/info/bcp47
+
+use crate::Jurisdiction;
+
+use std::str::FromStr;
+
+/// Return the jurisdictions of the language ranges of an `Accept-Language` header, along
+/// with their quality value, in order of preference.
+///
+/// Language ranges without a region subtag, such as `en` or `*`, are left out, as are
+/// regions that are not jurisdictions, e.g. `es-419` for Latin America. Language ranges of
+/// the same jurisdiction are deduplicated by their highest quality value, and ranges of
+/// equal quality keep the order of the header. Ranges weighted `q=0`, being
+/// unacceptable, are left out.
+///
+/// Malformed language ranges are skipped, leaving the remaining ranges of the header.
+pub fn from_accept_language(header: &str) -> Vec<(Jurisdiction, f32)> {
+    let mut preferred: Vec<(Jurisdiction, f32)> = Vec::new();
+    for (jurisdiction, quality) in header.split(',').filter_map(parse_language_range) {
+        match preferred.iter_mut().find(|(j, _)| *j == jurisdiction) {
+            Some(entry) => entry.1 = entry.1.max(quality),
+            None => preferred.push((jurisdiction, quality)),
+        }
+    }
+    preferred.retain(|(_, quality)| *quality > 0.0);
+    // The sort is stable, keeping the order of the header among equal quality values
+    preferred.sort_by(|(_, a), (_, b)| b.partial_cmp(a).expect("quality values are finite"));
+    preferred
+}
+
+/// Parse a language range of the header with its optional weight, returning the
+/// jurisdiction of its region subtag.
+fn parse_language_range(range: &str) -> Option<(Jurisdiction, f32)> {
+    let mut parameters = range.split(';');
+    let tag = parameters.next()?.trim();
+    let quality = match parameters.next() {
+        None => 1.0,
+        Some(weight) => parse_weight(weight.trim())?,
+    };
+    if parameters.next().is_some() {
+        return None;
+    }
+    region_of_tag(tag).map(|jurisdiction| (jurisdiction, quality))
+}
+
+/// Parse the `q=` weight of a language range.
+fn parse_weight(weight: &str) -> Option<f32> {
+    let value = weight
+        .strip_prefix("q=")
+        .or_else(|| weight.strip_prefix("Q="))?;
+    let (integer, fraction) = match value.find('.') {
+        Some(dot) => (&value[..dot], &value[dot + 1..]),
+        None => (value, ""),
+    };
+    let valid = fraction.len() <= 3
+        && fraction.bytes().all(|b| b.is_ascii_digit())
+        && match integer {
+            "0" => true,
+            "1" => fraction.bytes().all(|b| b == b'0'),
+            _ => false,
+        };
+    if !valid {
+        return None;
+    }
+    f32::from_str(value).ok()
+}
+
+/// Return the jurisdiction of the region subtag of a language tag.
+///
+/// The region follows the primary language subtag, and the optional extended language
+/// and script subtags. Extensions and private use subtags are never regions.
+fn region_of_tag(tag: &str) -> Option<Jurisdiction> {
+    let mut subtags = tag.split('-');
+    let language = subtags.next()?;
+    if !(2..=8).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut extlangs = 0;
+    for subtag in subtags {
+        if subtag.is_empty()
+            || subtag.len() > 8
+            || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        let alphabetic = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+        match subtag.len() {
+            2 if alphabetic => {
+                return Jurisdiction::from_str(&subtag.to_ascii_uppercase()).ok();
+            }
+            3 if subtag.bytes().all(|b| b.is_ascii_digit()) => {
+                let code = u16::from_str(subtag).ok()?;
+                return Jurisdiction::from_country_code(code).ok();
+            }
+            // Extended language subtags, e.g. zh-yue-HK
+            3 if alphabetic && extlangs < 3 => extlangs += 1,
+            // Script subtags, e.g. sr-Latn-RS, end the subtags preceding the region
+            4 if alphabetic => extlangs = 3,
+            _ => return None,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    fn jurisdiction(alpha2: Alpha2) -> Jurisdiction {
+        Jurisdiction::from(alpha2)
+    }
+
+    #[test]
+    fn test_http_accept_language() {
+        assert_eq!(
+            from_accept_language("en-GB,en;q=0.8,nb-NO;q=0.9"),
+            [
+                (jurisdiction(Alpha2::GB), 1.0),
+                (jurisdiction(Alpha2::NO), 0.9)
+            ]
+        );
+        // Optional whitespace, case insensitive subtags and scripts
+        assert_eq!(
+            from_accept_language("sr-latn-rs ; q=0.5 , zh-Hant-TW, de-ch;Q=0.70"),
+            [
+                (jurisdiction(Alpha2::TW), 1.0),
+                (jurisdiction(Alpha2::CH), 0.7),
+                (jurisdiction(Alpha2::RS), 0.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_http_accept_language_dedup() {
+        assert_eq!(
+            from_accept_language("en-US;q=0.5, es-US;q=0.9, fr-CA, en-CA;q=0.1"),
+            [
+                (jurisdiction(Alpha2::CA), 1.0),
+                (jurisdiction(Alpha2::US), 0.9)
+            ]
+        );
+        // Unacceptable ranges are left out, unless acceptable by another range
+        assert_eq!(from_accept_language("nb-NO;q=0, da-DK;q=0"), []);
+        assert_eq!(
+            from_accept_language("nb-NO;q=0, nn-NO;q=0.3"),
+            [(jurisdiction(Alpha2::NO), 0.3)]
+        );
+    }
+
+    #[test]
+    fn test_http_accept_language_without_region() {
+        assert_eq!(from_accept_language("en, fr;q=0.9, *;q=0.1"), []);
+        assert_eq!(from_accept_language(""), []);
+        // Areas are not jurisdictions, whereas numeric codes of countries are
+        assert_eq!(
+            from_accept_language("es-419, es-724;q=0.5"),
+            [(jurisdiction(Alpha2::ES), 0.5)]
+        );
+        // Private use subtags are not regions
+        assert_eq!(from_accept_language("en-x-GB"), []);
+    }
+
+    #[test]
+    fn test_http_accept_language_malformed() {
+        assert_eq!(
+            from_accept_language("🦀, en-GB;q=2, ;;, nb-NO;q=0.9;q=0.8, de-AT;q=0.8, x"),
+            [(jurisdiction(Alpha2::AT), 0.8)]
+        );
+        assert_eq!(
+            from_accept_language("sv-SE;q=0.1234, da-DK;q=.5, fi-FI;q=0.500, en--GB"),
+            [(jurisdiction(Alpha2::FI), 0.5)]
+        );
+        assert_eq!(from_accept_language(",,,;q=1"), []);
+    }
+}
@@ -0,0 +1,102 @@
+//! [icu_locid] interoperability.
+//!
+//! An [Alpha2] converts into an ICU4X region subtag, as does a [Jurisdiction] through
+//! `Jurisdiction::to_locale_region`. A `Jurisdiction` is converted from either an
+//! alphabetic region subtag or a numeric UN M49 region subtag, whereas subtags of areas
+//! spanning several jurisdictions, such as `419` for Latin America and the Caribbean or
+//! `001` for the world, fail to convert.
+//!
+//! ```rust
+//! use icu_locid::locale;
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use std::convert::TryFrom;
+//!
+//! let locale = locale!("nb-NO");
+//! let norway = Jurisdiction::try_from(locale.id.region.unwrap()).unwrap();
+//! assert_eq!(norway, Alpha2::NO);
+//! assert_eq!(norway.to_locale_region(), locale.id.region.unwrap());
+//! ```
+//!
+//! [icu_locid]: https://docs.rs/icu_locid
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use icu_locid::subtags::Region;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+impl TryFrom<Region> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn try_from(region: Region) -> Result<Self, Self::Error> {
+        let subtag = region.as_str();
+        if region.is_alphabetic() {
+            Jurisdiction::from_str(subtag)
+                .map_err(|_| format_err!("unrecognized jurisdiction region subtag: {}", subtag))
+        } else {
+            // Numeric subtags are made up of three digits, the M49 codes of countries
+            // coinciding with their ISO 3166 numeric country code
+            let code = u16::from_str(subtag)?;
+            Jurisdiction::from_country_code(code).map_err(|_| {
+                format_err!(
+                    "region subtag {} is not a jurisdiction, but possibly an area of several",
+                    subtag
+                )
+            })
+        }
+    }
+}
+
+impl From<Alpha2> for Region {
+    fn from(alpha2: Alpha2) -> Self {
+        Region::try_from_bytes(alpha2.to_string().as_bytes())
+            .expect("alpha2 codes are region subtags")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use icu_locid::subtags::region;
+
+    #[test]
+    fn test_icu_round_trip() {
+        for jurisdiction in Jurisdiction::all() {
+            let region = jurisdiction.to_locale_region();
+            assert_eq!(region.as_str(), jurisdiction.alpha2().to_string());
+            assert_eq!(Jurisdiction::try_from(region).unwrap(), jurisdiction);
+        }
+    }
+
+    #[test]
+    fn test_icu_numeric() {
+        assert_eq!(Jurisdiction::try_from(region!("578")).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(region!("004")).unwrap(), Alpha2::AF);
+        assert_eq!(Region::from(Alpha2::SE), region!("SE"));
+    }
+
+    #[test]
+    fn test_icu_areas() {
+        for area in &[region!("419"), region!("001"), region!("150")] {
+            let err = Jurisdiction::try_from(*area).unwrap_err();
+            assert!(err.to_string().contains(area.as_str()), "{}", err);
+        }
+        let err = Jurisdiction::try_from(region!("419")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "region subtag 419 is not a jurisdiction, but possibly an area of several"
+        );
+        // Alphabetic subtags outside ISO 3166-1, e.g. the European Union
+        let err = Jurisdiction::try_from(region!("EU")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unrecognized jurisdiction region subtag: EU"
+        );
+    }
+}
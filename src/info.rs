@@ -0,0 +1,143 @@
+//! A snapshot of the definition of a jurisdiction.
+
+use crate::alpha::{Alpha2, Alpha3};
+#[cfg(feature = "region")]
+use crate::region::{IntermediateRegion, Region, SubRegion};
+use crate::Jurisdiction;
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The definition of a [Jurisdiction] as a plain record, such as to hand over a complete
+/// jurisdiction across an API boundary.
+///
+/// The names borrow the static definition of the jurisdiction they are constructed from,
+/// and are owned when deserialized. The region classifications are only present with the
+/// `region` feature.
+///
+/// ```rust
+/// use jurisdiction::{Alpha2, Jurisdiction};
+///
+/// let info = Jurisdiction::from(Alpha2::NO).info();
+/// assert_eq!(info.name, "Norway");
+/// assert_eq!(info.numeric, 578);
+/// ```
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct JurisdictionInfo {
+    /// The english name, see [Jurisdiction::name](../struct.Jurisdiction.html#method.name).
+    pub name: Cow<'static, str>,
+    /// The formal name, see
+    /// [Jurisdiction::formal_name](../struct.Jurisdiction.html#method.formal_name).
+    pub formal_name: Cow<'static, str>,
+    /// The ISO 3166 alpha2 code.
+    pub alpha2: Alpha2,
+    /// The ISO 3166 alpha3 code.
+    pub alpha3: Alpha3,
+    /// The ISO 3166 numeric country code.
+    pub numeric: u16,
+    /// The UN M49 region.
+    #[cfg(feature = "region")]
+    pub region: Region,
+    /// The UN M49 sub region.
+    #[cfg(feature = "region")]
+    pub sub_region: SubRegion,
+    /// The UN M49 intermediate region.
+    #[cfg(feature = "region")]
+    pub intermediate_region: IntermediateRegion,
+    /// The UN M49 numeric code of the region.
+    #[cfg(feature = "region")]
+    pub region_code: u16,
+    /// The UN M49 numeric code of the sub region.
+    #[cfg(feature = "region")]
+    pub sub_region_code: u16,
+    /// The UN M49 numeric code of the intermediate region, if any.
+    #[cfg(feature = "region")]
+    pub intermediate_region_code: Option<u16>,
+}
+
+impl From<Jurisdiction> for JurisdictionInfo {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        JurisdictionInfo::from(&jurisdiction)
+    }
+}
+
+impl From<&Jurisdiction> for JurisdictionInfo {
+    fn from(jurisdiction: &Jurisdiction) -> Self {
+        JurisdictionInfo {
+            name: Cow::Borrowed(jurisdiction.name()),
+            formal_name: Cow::Borrowed(jurisdiction.formal_name()),
+            alpha2: jurisdiction.alpha2(),
+            alpha3: jurisdiction.alpha3(),
+            numeric: jurisdiction.country_code(),
+            #[cfg(feature = "region")]
+            region: jurisdiction.region(),
+            #[cfg(feature = "region")]
+            sub_region: jurisdiction.sub_region(),
+            #[cfg(feature = "region")]
+            intermediate_region: jurisdiction.intermediate_region(),
+            #[cfg(feature = "region")]
+            region_code: jurisdiction.region_code(),
+            #[cfg(feature = "region")]
+            sub_region_code: jurisdiction.sub_region_code(),
+            #[cfg(feature = "region")]
+            intermediate_region_code: jurisdiction.intermediate_region_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_agrees_with_accessors() {
+        for jurisdiction in Jurisdiction::all() {
+            let info = jurisdiction.info();
+            assert_eq!(info.name, jurisdiction.name());
+            assert_eq!(info.formal_name, jurisdiction.formal_name());
+            assert_eq!(info.alpha2, jurisdiction.alpha2());
+            assert_eq!(info.alpha3, jurisdiction.alpha3());
+            assert_eq!(info.numeric, jurisdiction.country_code());
+            #[cfg(feature = "region")]
+            {
+                assert_eq!(info.region, jurisdiction.region());
+                assert_eq!(info.sub_region, jurisdiction.sub_region());
+                assert_eq!(info.intermediate_region, jurisdiction.intermediate_region());
+                assert_eq!(info.region_code, jurisdiction.region_code());
+                assert_eq!(info.sub_region_code, jurisdiction.sub_region_code());
+                assert_eq!(
+                    info.intermediate_region_code,
+                    jurisdiction.intermediate_region_code()
+                );
+            }
+            assert_eq!(JurisdictionInfo::from(jurisdiction), info);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_info_json() {
+        let info = Jurisdiction::from(Alpha2::GG).info();
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Guernsey",
+                "formal_name": "Guernsey",
+                "alpha2": "GG",
+                "alpha3": "GGY",
+                "numeric": 831,
+                "region": "Europe",
+                "sub_region": "Northern Europe",
+                "intermediate_region": "Channel Islands",
+                "region_code": 150,
+                "sub_region_code": 154,
+                "intermediate_region_code": 830
+            })
+        );
+        let parsed: JurisdictionInfo = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, info);
+    }
+}
@@ -0,0 +1,161 @@
+//! WIPO ST.3 codes of intellectual property jurisdictions.
+//!
+//! Patent and trademark documents identify the office or state concerned through the
+//! two-letter codes of [WIPO Standard ST.3]. The codes of states follow the alpha2 codes of
+//! ISO 3166, while a number of codes are assigned to intergovernmental organizations
+//! granting rights of their own, such as `EP` for the European Patent Office, `EM` for the
+//! European Union Intellectual Property Office and `WO` for WIPO itself.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::ip::{self, IpOrganization, St3Entity};
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! assert_eq!(ip::resolve("NO"), Some(St3Entity::Jurisdiction(norway)));
+//! assert_eq!(
+//!     ip::resolve("EP"),
+//!     Some(St3Entity::Organization(IpOrganization::EuropeanPatentOffice))
+//! );
+//! ```
+//!
+//! [WIPO Standard ST.3]: https://www.wipo.int/standards/en/st3.html
+
+use crate::generated::ip::{GENERATED_ST3_CODES, GENERATED_ST3_ORGANIZATIONS};
+use crate::Jurisdiction;
+
+use serde::{Deserialize, Serialize};
+
+/// An intergovernmental organization assigned a WIPO ST.3 code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum IpOrganization {
+    /// The African Intellectual Property Organization (OAPI), `OA`.
+    AfricanIntellectualPropertyOrganization,
+    /// The African Regional Intellectual Property Organization (ARIPO), `AP`.
+    AfricanRegionalIntellectualPropertyOrganization,
+    /// The Benelux Office for Intellectual Property (BOIP), `BX`.
+    BeneluxOfficeForIntellectualProperty,
+    /// The Community Plant Variety Office (CPVO) of the European Union, `QZ`.
+    CommunityPlantVarietyOffice,
+    /// The Eurasian Patent Organization (EAPO), `EA`.
+    EurasianPatentOrganization,
+    /// The European Patent Office (EPO), `EP`.
+    EuropeanPatentOffice,
+    /// The European Union Intellectual Property Office (EUIPO), `EM`.
+    EuropeanUnionIntellectualPropertyOffice,
+    /// The Patent Office of the Gulf Cooperation Council, `GC`.
+    GccPatentOffice,
+    /// The International Bureau of WIPO, `IB`.
+    InternationalBureau,
+    /// The Nordic Patent Institute (NPI), `XN`.
+    NordicPatentInstitute,
+    /// The International Union for the Protection of New Varieties of Plants (UPOV), `XU`.
+    Upov,
+    /// The Visegrad Patent Institute (VPI), `XV`.
+    VisegradPatentInstitute,
+    /// The World Intellectual Property Organization (WIPO), `WO`.
+    WorldIntellectualPropertyOrganization,
+}
+
+impl IpOrganization {
+    /// Return the ST.3 code of the organization, e.g. `EP` for the European Patent Office.
+    pub fn st3_code(&self) -> &'static str {
+        self.entry().1
+    }
+
+    /// Return the English name of the organization.
+    pub fn name(&self) -> &'static str {
+        self.entry().2
+    }
+
+    fn entry(&self) -> &'static (IpOrganization, &'static str, &'static str) {
+        GENERATED_ST3_ORGANIZATIONS
+            .iter()
+            .find(|(organization, _, _)| organization == self)
+            .expect("every IpOrganization has an ST.3 code")
+    }
+}
+
+/// The entity identified by a WIPO ST.3 code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum St3Entity {
+    /// A state or territory, such as `NO` for Norway.
+    Jurisdiction(Jurisdiction),
+    /// An intergovernmental organization, such as `EP` for the European Patent Office.
+    Organization(IpOrganization),
+}
+
+/// Return the [St3Entity] identified by a WIPO ST.3 code.
+///
+/// The code is matched ignoring ASCII case. Codes not assigned by ST.3 return `None`.
+pub fn resolve(code: &str) -> Option<St3Entity> {
+    if code.len() != 2 {
+        return None;
+    }
+    GENERATED_ST3_CODES
+        .binary_search_by(|(st3, _)| {
+            st3.bytes()
+                .cmp(code.bytes().map(|b| b.to_ascii_uppercase()))
+        })
+        .ok()
+        .map(|index| GENERATED_ST3_CODES[index].1.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_ip_st3_code_jurisdiction() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.st3_code(), Some("NO"));
+        assert_eq!(resolve("NO"), Some(St3Entity::Jurisdiction(norway.clone())));
+        assert_eq!(resolve("no"), Some(St3Entity::Jurisdiction(norway)));
+    }
+
+    #[test]
+    fn test_ip_resolve_organizations() {
+        assert_eq!(
+            resolve("EP"),
+            Some(St3Entity::Organization(
+                IpOrganization::EuropeanPatentOffice
+            ))
+        );
+        assert_eq!(
+            resolve("WO"),
+            Some(St3Entity::Organization(
+                IpOrganization::WorldIntellectualPropertyOrganization
+            ))
+        );
+        assert_eq!(
+            resolve("EM"),
+            Some(St3Entity::Organization(
+                IpOrganization::EuropeanUnionIntellectualPropertyOffice
+            ))
+        );
+        assert_eq!(IpOrganization::EuropeanPatentOffice.st3_code(), "EP");
+        assert_eq!(
+            IpOrganization::EuropeanPatentOffice.name(),
+            "European Patent Office (EPO)"
+        );
+    }
+
+    #[test]
+    fn test_ip_resolve_invalid() {
+        assert_eq!(resolve("ZZ"), None);
+        assert_eq!(resolve(""), None);
+        assert_eq!(resolve("NOR"), None);
+        assert_eq!(resolve("É"), None);
+    }
+
+    #[test]
+    fn test_ip_st3_codes_resolve() {
+        for jurisdiction in Jurisdiction::all() {
+            let code = jurisdiction.st3_code().unwrap();
+            assert_eq!(
+                resolve(code),
+                Some(St3Entity::Jurisdiction(jurisdiction.clone()))
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! [iso_currency] interoperability.
+//!
+//! [Jurisdiction] and [Alpha2] convert to and from the `Country` of iso_currency, matched on
+//! their numeric country code, and `Jurisdiction::currencies_iso` returns the currencies
+//! in use within a jurisdiction. The currencies of each jurisdiction are computed once,
+//! on first use, by inverting the `used_by` countries of every currency.
+//!
+//! ```rust
+//! use iso_currency::{Country, Currency};
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use std::convert::TryFrom;
+//!
+//! let bhutan = Jurisdiction::try_from(Country::BT).unwrap();
+//! assert_eq!(bhutan, Alpha2::BT);
+//! assert_eq!(bhutan.currencies_iso(), [Currency::BTN, Currency::INR]);
+//! ```
+//!
+//! [iso_currency]: https://docs.rs/iso_currency
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::map::JurisdictionMap;
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use iso_currency::{Country, Currency, IntoEnumIterator};
+use lazy_static::lazy_static;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+lazy_static! {
+    /// The currencies of each jurisdiction, ordered by their alpha code.
+    static ref CURRENCIES: JurisdictionMap<Vec<Currency>> = {
+        let mut currencies = JurisdictionMap::new();
+        for currency in Currency::iter() {
+            for country in currency.used_by() {
+                // Countries not defined by this crate are left out
+                if let Ok(jurisdiction) = Jurisdiction::try_from(country) {
+                    currencies
+                        .entry(jurisdiction)
+                        .or_insert_with(Vec::new)
+                        .push(currency);
+                }
+            }
+        }
+        for (_, list) in currencies.iter_mut() {
+            list.sort_by_key(|currency| currency.code());
+        }
+        currencies
+    };
+}
+
+/// Return the currencies in use within `jurisdiction`.
+pub(crate) fn currencies_of(jurisdiction: &Jurisdiction) -> Vec<Currency> {
+    CURRENCIES.get(jurisdiction).cloned().unwrap_or_default()
+}
+
+impl TryFrom<Country> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn try_from(country: Country) -> Result<Self, Self::Error> {
+        // The variants of Country are discriminated by their numeric country code
+        Jurisdiction::from_country_code(country as u16)
+    }
+}
+
+impl TryFrom<Jurisdiction> for Country {
+    type Error = anyhow::Error;
+
+    fn try_from(jurisdiction: Jurisdiction) -> Result<Self, Self::Error> {
+        Country::try_from(jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Country> for Alpha2 {
+    type Error = anyhow::Error;
+
+    fn try_from(country: Country) -> Result<Self, Self::Error> {
+        Jurisdiction::try_from(country).map(|jurisdiction| jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Alpha2> for Country {
+    type Error = anyhow::Error;
+
+    fn try_from(alpha2: Alpha2) -> Result<Self, Self::Error> {
+        Country::from_str(&alpha2.to_string())
+            .map_err(|_| format_err!("jurisdiction {} is not defined by iso_currency", alpha2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_currency_round_trip() {
+        let mut undefined = Vec::new();
+        for jurisdiction in Jurisdiction::all() {
+            let country = match Country::try_from(jurisdiction.clone()) {
+                Ok(country) => country,
+                Err(_) => {
+                    undefined.push(jurisdiction.alpha2());
+                    continue;
+                }
+            };
+            assert_eq!(country as u16, jurisdiction.country_code());
+            assert_eq!(Jurisdiction::try_from(country).unwrap(), jurisdiction);
+            assert_eq!(Alpha2::try_from(country).unwrap(), jurisdiction.alpha2());
+        }
+        assert!(
+            undefined.is_empty(),
+            "jurisdictions not defined by iso_currency: {:?}",
+            undefined
+        );
+        assert!(Jurisdiction::try_from(Country::Unspecified).is_err());
+    }
+
+    #[test]
+    fn test_iso_currency_eurozone() {
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert_eq!(germany.currencies_iso(), [Currency::EUR]);
+    }
+
+    #[test]
+    fn test_iso_currency_multiple() {
+        let panama = Jurisdiction::from(Alpha2::PA);
+        assert_eq!(panama.currencies_iso(), [Currency::PAB, Currency::USD]);
+        // Funds are listed alongside the currency they complement
+        let usa = Jurisdiction::from(Alpha2::US);
+        assert_eq!(usa.currencies_iso(), [Currency::USD, Currency::USN]);
+    }
+
+    #[test]
+    fn test_iso_currency_unknown() {
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert_eq!(antarctica.currencies_iso(), []);
+    }
+}
@@ -0,0 +1,102 @@
+//! [isocountry] interoperability.
+//!
+//! [Jurisdiction] and [Alpha2] convert to and from the `CountryCode` of isocountry, matched
+//! on their numeric country code. The conversions are fallible in both directions, as
+//! either crate may define a jurisdiction the other does not.
+//!
+//! ```rust
+//! use isocountry::CountryCode;
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use std::convert::TryFrom;
+//!
+//! let norway = Jurisdiction::try_from(CountryCode::NOR).unwrap();
+//! assert_eq!(norway, Alpha2::NO);
+//! assert_eq!(CountryCode::try_from(norway).unwrap(), CountryCode::NOR);
+//! ```
+//!
+//! [isocountry]: https://docs.rs/isocountry
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use isocountry::CountryCode;
+
+use std::convert::TryFrom;
+
+impl TryFrom<CountryCode> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn try_from(code: CountryCode) -> Result<Self, Self::Error> {
+        // Numeric country codes are made up of three digits
+        Jurisdiction::from_country_code(code.numeric_id() as u16)
+    }
+}
+
+impl TryFrom<Jurisdiction> for CountryCode {
+    type Error = anyhow::Error;
+
+    fn try_from(jurisdiction: Jurisdiction) -> Result<Self, Self::Error> {
+        CountryCode::for_id(u32::from(jurisdiction.country_code())).map_err(|_| {
+            format_err!(
+                "jurisdiction {} is not defined by isocountry",
+                jurisdiction.alpha2()
+            )
+        })
+    }
+}
+
+impl TryFrom<CountryCode> for Alpha2 {
+    type Error = anyhow::Error;
+
+    fn try_from(code: CountryCode) -> Result<Self, Self::Error> {
+        Jurisdiction::try_from(code).map(|jurisdiction| jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Alpha2> for CountryCode {
+    type Error = anyhow::Error;
+
+    fn try_from(alpha2: Alpha2) -> Result<Self, Self::Error> {
+        CountryCode::try_from(Jurisdiction::from(alpha2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isocountry_round_trip() {
+        let mut undefined = Vec::new();
+        for jurisdiction in Jurisdiction::all() {
+            let code = match CountryCode::try_from(jurisdiction.clone()) {
+                Ok(code) => code,
+                Err(_) => {
+                    undefined.push(jurisdiction.alpha2());
+                    continue;
+                }
+            };
+            assert_eq!(code.alpha2(), jurisdiction.alpha2().to_string());
+            assert_eq!(code.alpha3(), jurisdiction.alpha3().to_string());
+            assert_eq!(Jurisdiction::try_from(code).unwrap(), jurisdiction);
+            assert_eq!(Alpha2::try_from(code).unwrap(), jurisdiction.alpha2());
+        }
+        assert!(
+            undefined.is_empty(),
+            "jurisdictions not defined by isocountry: {:?}",
+            undefined
+        );
+
+        let unrecognized: Vec<_> = CountryCode::iter()
+            .filter(|code| Jurisdiction::try_from(**code).is_err())
+            .collect();
+        assert!(
+            unrecognized.is_empty(),
+            "isocountry codes not defined by jurisdiction: {:?}",
+            unrecognized
+        );
+    }
+}
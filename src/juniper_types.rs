@@ -0,0 +1,212 @@
+//! [juniper] support.
+//!
+//! [Jurisdiction], [Alpha2] and [Alpha3] are scalars of the same name, represented by a
+//! string holding their alpha code. A `Jurisdiction` is output as its alpha2 code, and is
+//! input from either an alpha2 or an alpha3 code. The region classifications are enums,
+//! named after their variants in screaming snake case, e.g. `NORTHERN_EUROPE`.
+//!
+//! Codes that fail to parse are rejected by juniper as invalid input while validating the
+//! query, before any field resolves, detailing the parse error for inputs by variable.
+//!
+//! [juniper]: https://docs.rs/juniper
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+use std::str::FromStr;
+
+/// A jurisdiction, input as its ISO 3166-1 alpha-2 or alpha-3 code and output as its
+/// alpha-2 code, e.g. `NO`.
+#[graphql_scalar]
+// The alias only names the type to the macro, being local
+#[allow(dead_code)]
+#[graphql(
+    name = "Jurisdiction",
+    with = jurisdiction_scalar,
+    parse_token(String),
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+type JurisdictionScalar = Jurisdiction;
+
+mod jurisdiction_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(jurisdiction: &Jurisdiction) -> Value<S> {
+        Value::scalar(jurisdiction.alpha2().to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(
+        value: &InputValue<S>,
+    ) -> Result<Jurisdiction, String> {
+        let code = value
+            .as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {}", value))?;
+        Jurisdiction::from_str(code).map_err(|err| err.to_string())
+    }
+}
+
+/// An ISO 3166-1 alpha-2 country code, e.g. `NO`.
+#[graphql_scalar]
+// The alias only names the type to the macro, being local
+#[allow(dead_code)]
+#[graphql(
+    name = "Alpha2",
+    with = alpha2_scalar,
+    parse_token(String),
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+type Alpha2Scalar = Alpha2;
+
+mod alpha2_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(alpha2: &Alpha2) -> Value<S> {
+        Value::scalar(alpha2.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(value: &InputValue<S>) -> Result<Alpha2, String> {
+        let code = value
+            .as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {}", value))?;
+        serde_plain::from_str(code).map_err(|_| format!("unrecognized alpha2 code: {}", code))
+    }
+}
+
+/// An ISO 3166-1 alpha-3 country code, e.g. `NOR`.
+#[graphql_scalar]
+// The alias only names the type to the macro, being local
+#[allow(dead_code)]
+#[graphql(
+    name = "Alpha3",
+    with = alpha3_scalar,
+    parse_token(String),
+    specified_by_url = "https://www.iso.org/iso-3166-country-codes.html"
+)]
+type Alpha3Scalar = Alpha3;
+
+mod alpha3_scalar {
+    use super::*;
+
+    pub(super) fn to_output<S: ScalarValue>(alpha3: &Alpha3) -> Value<S> {
+        Value::scalar(alpha3.to_string())
+    }
+
+    pub(super) fn from_input<S: ScalarValue>(value: &InputValue<S>) -> Result<Alpha3, String> {
+        let code = value
+            .as_string_value()
+            .ok_or_else(|| format!("Expected `String`, found: {}", value))?;
+        serde_plain::from_str(code).map_err(|_| format!("unrecognized alpha3 code: {}", code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use juniper::{
+        graphql_object, graphql_value, EmptyMutation, EmptySubscription, GraphQLError, RootNode,
+        Variables,
+    };
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn jurisdiction(country: Jurisdiction) -> Jurisdiction {
+            country
+        }
+
+        fn alpha3(country: Alpha2) -> Alpha3 {
+            Jurisdiction::from(country).alpha3()
+        }
+    }
+
+    // The resolvers of an object are not subject to cfg attributes, so the region enums
+    // are queried through an object of their own
+    #[cfg(feature = "region")]
+    struct RegionQuery;
+
+    #[cfg(feature = "region")]
+    #[graphql_object]
+    impl RegionQuery {
+        fn region(country: Jurisdiction) -> crate::region::Region {
+            country.region()
+        }
+    }
+
+    type Schema = RootNode<'static, Query, EmptyMutation, EmptySubscription>;
+
+    fn execute(query: &str, variables: Variables) -> Result<Value, GraphQLError> {
+        let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+        juniper::execute_sync(query, None, &schema, &variables, &()).map(|(value, errors)| {
+            assert!(errors.is_empty(), "{:?}", errors);
+            value
+        })
+    }
+
+    #[test]
+    fn test_juniper_round_trip() {
+        let mut variables = Variables::new();
+        variables.insert("country".to_string(), InputValue::scalar("NOR"));
+        let value = execute(
+            r#"query($country: Jurisdiction!) {
+                jurisdiction(country: $country)
+                alpha3(country: "SE")
+            }"#,
+            variables,
+        )
+        .unwrap();
+        assert_eq!(
+            value,
+            graphql_value!({ "jurisdiction": "NO", "alpha3": "SWE" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_juniper_region_enum() {
+        let schema = RootNode::new(
+            RegionQuery,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        let (value, errors) = juniper::execute_sync(
+            r#"{ region(country: "NO") }"#,
+            None,
+            &schema,
+            &Variables::new(),
+            &(),
+        )
+        .unwrap();
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(value, graphql_value!({ "region": "EUROPE" }));
+    }
+
+    #[test]
+    fn test_juniper_invalid_input() {
+        let mut variables = Variables::new();
+        variables.insert("country".to_string(), InputValue::scalar("XX"));
+        let err = execute(
+            r#"query($country: Jurisdiction!) { jurisdiction(country: $country) }"#,
+            variables,
+        )
+        .unwrap_err();
+        let message = format!("{:?}", err);
+        assert!(
+            message.contains("unrecognized ISO 3166 alpha country code: XX"),
+            "{}",
+            message
+        );
+
+        // Arguments given inline are rejected without the details of the parse error
+        let err = execute(r#"{ alpha3(country: "NOR") }"#, Variables::new()).unwrap_err();
+        let message = format!("{:?}", err);
+        assert!(
+            message.contains(r#"Invalid value \"\"NOR\"\" for type \"Alpha2\""#),
+            "{}",
+            message
+        );
+    }
+}
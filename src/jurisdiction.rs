@@ -1,25 +1,113 @@
 //! The main lightweight object used to identify a jurisdiction/country and its metadata.
 
 use crate::alpha::*;
-use crate::definition::{Definition, DEFINITIONS};
+#[cfg(feature = "continent")]
+use crate::continent::Continent;
+#[cfg(feature = "conventions")]
+use crate::conventions::*;
+use crate::definition::Definition;
+#[cfg(feature = "economics")]
+use crate::economics::IncomeGroup;
+#[cfg(feature = "political")]
+use crate::gdpr::AdequacyStatus;
+use crate::generated::definition::{
+    GENERATED_ALL_BY_NAME, GENERATED_BY_COUNTRY_CODE, GENERATED_BY_INDEX, GENERATED_DEFINITIONS,
+};
+use crate::info::JurisdictionInfo;
+#[cfg(feature = "legal")]
+use crate::legal::LegalSystem;
+use crate::list::ParseListError;
+#[cfg(feature = "localized-names")]
+use crate::localized::Language;
+#[cfg(feature = "postal")]
+use crate::postal::PostalFormat;
+#[cfg(feature = "power")]
+use crate::power::PlugType;
+use crate::query::JurisdictionQuery;
 #[cfg(feature = "region")]
 use crate::region::*;
+#[cfg(feature = "subdivision")]
+use crate::subdivision::Subdivision;
+#[cfg(feature = "sovereignty")]
+use crate::territory::TerritoryKind;
+#[cfg(feature = "political")]
+use crate::un::UnRegionalGroup;
+#[cfg(feature = "vat")]
+use crate::vat::VatFormat;
 
 use anyhow::format_err;
+#[cfg(feature = "rand")]
+use rand::Rng;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+#[cfg(feature = "region")]
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+#[cfg(any(feature = "gs1", feature = "radio"))]
+use std::ops::RangeInclusive;
 use std::str::FromStr;
 
+/// All jurisdictions, ordered by their english name as by
+/// [Jurisdiction::cmp_by_name](struct.Jurisdiction.html#method.cmp_by_name).
+///
+/// The order is computed by the build script, such that listing the jurisdictions by
+/// name, e.g. for a dropdown, costs no sorting at runtime.
+pub static ALL_BY_NAME: &[Jurisdiction] = &GENERATED_ALL_BY_NAME;
+
 /// A pointer sized object encoding countries and areas of the world.
 ///
 /// The size of this structure is minimized such that passing it around will be limited
 /// overhead, with implemented methods performing lookup in static table instead.
+///
+/// # Layout
+///
+/// A `Jurisdiction` is `#[repr(transparent)]` over a reference into the static definition
+/// table, guaranteeing the size and alignment of a pointer. As the reference is never
+/// null, `Option<Jurisdiction>` is pointer sized as well, with `None` represented by null.
+/// Both guarantees are part of the public contract, and are asserted at compile time.
+///
+/// The pointer itself is opaque: it may be stored and passed along, e.g. as an FFI
+/// handle, but its pointee is private and its value may differ between builds. Transmuting
+/// other values into a `Jurisdiction` is not supported.
 #[derive(Clone, Debug)]
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 pub struct Jurisdiction {
     definition: &'static Definition,
 }
 
 impl std::cmp::PartialEq<Jurisdiction> for Jurisdiction {
     fn eq(&self, other: &Jurisdiction) -> bool {
-        self.definition.country_code == other.definition.country_code
+        self.definition.country_code() == other.definition.country_code()
+    }
+}
+
+impl std::cmp::Eq for Jurisdiction {}
+
+/// Jurisdictions hash as their numeric [country code](#method.country_code), writing a
+/// single `u16` to the hasher.
+///
+/// The country codes are unique and stable across releases, such that they double as
+/// small integer keys. Integer hashers, such as the `IntMap` of the `nohash` feature or
+/// `FxHashMap`, hash them at the cost of an integer multiplication or less.
+///
+/// ```rust
+/// # use jurisdiction::{Alpha2, Jurisdiction};
+/// use std::collections::HashMap;
+///
+/// let mut populations = HashMap::new();
+/// populations.insert(Jurisdiction::from(Alpha2::NO), 5_550_203);
+/// assert_eq!(populations[&Jurisdiction::from(Alpha2::NO)], 5_550_203);
+/// ```
+impl std::hash::Hash for Jurisdiction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u16(self.definition.country_code());
     }
 }
 
@@ -31,7 +119,7 @@ impl std::cmp::PartialEq<Alpha2> for Jurisdiction {
 
 impl std::cmp::PartialEq<Alpha3> for Jurisdiction {
     fn eq(&self, other: &Alpha3) -> bool {
-        &self.definition.alpha3 == other
+        &self.definition.alpha3() == other
     }
 }
 
@@ -52,21 +140,569 @@ impl FromStr for Jurisdiction {
     }
 }
 
+/// Jurisdictions are ordered as in [all](struct.Jurisdiction.html#method.all), alike
+/// [Alpha2] and [Alpha3], such that they may key a `BTreeMap` or `BTreeSet`.
+impl std::cmp::PartialOrd for Jurisdiction {
+    fn partial_cmp(&self, other: &Jurisdiction) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for Jurisdiction {
+    fn cmp(&self, other: &Jurisdiction) -> Ordering {
+        self.position().cmp(&other.position())
+    }
+}
+
+/// Jurisdictions serialize as the string of their alpha2 code, and deserialize from the
+/// string of their alpha2 or alpha3 code, ignoring case.
+///
+/// As strings, jurisdictions may key maps of any format, such as JSON objects or TOML
+/// tables:
+///
+/// ```rust
+/// # use jurisdiction::{Jurisdiction, Alpha2};
+/// use std::collections::BTreeMap;
+///
+/// let capitals: BTreeMap<Jurisdiction, String> =
+///     serde_json::from_str(r#"{"NO": "Oslo", "SWE": "Stockholm"}"#).unwrap();
+/// assert_eq!(capitals[&Jurisdiction::from(Alpha2::SE)], "Stockholm");
+/// assert_eq!(
+///     serde_json::to_string(&capitals).unwrap(),
+///     r#"{"NO":"Oslo","SE":"Stockholm"}"#
+/// );
+/// ```
+impl Serialize for Jurisdiction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.alpha2().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Jurisdiction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CodeVisitor;
+
+        impl Visitor<'_> for CodeVisitor {
+            type Value = Jurisdiction;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO 3166 alpha2 or alpha3 code")
+            }
+
+            fn visit_str<E: de::Error>(self, code: &str) -> Result<Self::Value, E> {
+                code.parse()
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(code), &self))
+            }
+        }
+
+        deserializer.deserialize_str(CodeVisitor)
+    }
+}
+
+/// Error returned when an integer is not the numeric country code of a jurisdiction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CountryCodeError {
+    /// The integer is negative or exceeds `u16::MAX`, and is no numeric country code at all.
+    OutOfRange(i64),
+    /// The numeric country code is not assigned to any jurisdiction.
+    Unassigned(u16),
+}
+
+impl fmt::Display for CountryCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountryCodeError::OutOfRange(value) => {
+                write!(f, "numeric country code out of range: {}", value)
+            }
+            CountryCodeError::Unassigned(country_code) => write!(
+                f,
+                "unrecognized ISO 3166 numeric country code: {:03}",
+                country_code
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CountryCodeError {}
+
+impl TryFrom<u16> for Jurisdiction {
+    type Error = CountryCodeError;
+
+    fn try_from(country_code: u16) -> Result<Self, Self::Error> {
+        GENERATED_BY_COUNTRY_CODE
+            .binary_search_by_key(&country_code, |(country_code, _)| *country_code)
+            .map(|index| Jurisdiction::from(GENERATED_BY_COUNTRY_CODE[index].1))
+            .map_err(|_| CountryCodeError::Unassigned(country_code))
+    }
+}
+
+/// Implement `TryFrom` of an integer type by way of its `u16` conversion.
+macro_rules! try_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl TryFrom<$int> for Jurisdiction {
+                type Error = CountryCodeError;
+
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    let country_code = u16::try_from(value)
+                        .map_err(|_| CountryCodeError::OutOfRange(i64::from(value)))?;
+                    Jurisdiction::try_from(country_code)
+                }
+            }
+        )*
+    };
+}
+
+try_from_integer!(i16, i32, i64, u32);
+
+impl From<Jurisdiction> for Alpha2 {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        jurisdiction.alpha2()
+    }
+}
+
+impl From<Jurisdiction> for Alpha3 {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        jurisdiction.alpha3()
+    }
+}
+
+impl From<Jurisdiction> for u16 {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        jurisdiction.country_code()
+    }
+}
+
+impl From<Jurisdiction> for i32 {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        i32::from(jurisdiction.country_code())
+    }
+}
+
+impl From<Jurisdiction> for i64 {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        i64::from(jurisdiction.country_code())
+    }
+}
+
 impl Jurisdiction {
-    pub(crate) fn new(country_code: u16) -> Jurisdiction {
-        let def = DEFINITIONS.get(&country_code);
-        debug_assert!(
-            def.is_some(),
-            "passed country code is not defined in DEFINITIONS"
-        );
-        Jurisdiction {
-            definition: def.unwrap(),
+    /// Construct a `Jurisdiction` from its ISO 3166 numeric country code.
+    #[cfg(any(
+        feature = "arrow",
+        feature = "compat-celes",
+        feature = "compat-isocountry",
+        feature = "compat-rust-iso3166",
+        feature = "http",
+        feature = "icu",
+        feature = "iso-currency"
+    ))]
+    pub(crate) fn from_country_code(country_code: u16) -> anyhow::Result<Jurisdiction> {
+        Jurisdiction::try_from(country_code).map_err(|err| format_err!("{}", err))
+    }
+
+    /// Construct a `Jurisdiction` from its alpha2 or alpha3 code in constant expressions,
+    /// backing the [jurisdiction!](macro.jurisdiction.html) macro.
+    #[doc(hidden)]
+    pub const fn __from_code(code: &str) -> Option<Jurisdiction> {
+        match Alpha2::from_code(code) {
+            Some(alpha2) => Some(Jurisdiction::from_definition(
+                &GENERATED_DEFINITIONS[alpha2 as usize],
+            )),
+            None => None,
         }
     }
 
+    /// Construct a `Jurisdiction` from a definition within the static definition table.
+    pub(crate) const fn from_definition(definition: &'static Definition) -> Jurisdiction {
+        Jurisdiction { definition }
+    }
+
+    /// Return the dense index of this jurisdiction, in alphabetical order of the
+    /// [alpha2](#method.alpha2) codes.
+    ///
+    /// The indices run contiguously from zero up to the number of jurisdictions, and may be
+    /// used to index plain arrays with an element per jurisdiction, or be serialized in place
+    /// of the jurisdiction.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let andorra = Jurisdiction::from(Alpha2::AD);
+    /// assert_eq!(andorra.index(), 0);
+    /// assert_eq!(Jurisdiction::from_index(0), Some(andorra));
+    /// ```
+    ///
+    /// # Stability
+    ///
+    /// The index of a jurisdiction is stable across releases which do not add or withdraw
+    /// ISO 3166-1 codes. As the index follows the alpha2 codes, adding or withdrawing a
+    /// code renumbers the jurisdictions ordered after it, and is released as a breaking
+    /// change of this crate.
+    pub fn index(&self) -> usize {
+        usize::from(self.definition.index())
+    }
+
+    /// Return the jurisdiction of a dense [index](#method.index), unless out of range.
+    pub fn from_index(index: usize) -> Option<Jurisdiction> {
+        GENERATED_BY_INDEX.get(index).cloned()
+    }
+
+    /// Return the position of this jurisdiction in the order of [all](#method.all), its
+    /// position within the static definition table.
+    pub(crate) fn position(&self) -> usize {
+        self.definition.position()
+    }
+
+    /// Construct a `Jurisdiction` from its position within the static definition table.
+    pub(crate) fn from_position(position: usize) -> Jurisdiction {
+        Jurisdiction::from_definition(&GENERATED_DEFINITIONS[position])
+    }
+
+    /// Return all jurisdictions, in the order of ISO 3166-1.
+    ///
+    /// ISO 3166-1 orders the jurisdictions alphabetically by english name, disregarding
+    /// diacritics, e.g. Åland Islands follows Afghanistan.
+    pub fn all() -> impl Iterator<Item = Jurisdiction> {
+        GENERATED_DEFINITIONS
+            .iter()
+            .map(Jurisdiction::from_definition)
+    }
+
+    /// Return all jurisdictions, ordered by their english name, see
+    /// [ALL_BY_NAME](static.ALL_BY_NAME.html).
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let names: Vec<_> = Jurisdiction::all_sorted_by_name()[..3]
+    ///     .iter()
+    ///     .map(Jurisdiction::name)
+    ///     .collect();
+    /// assert_eq!(names, ["Afghanistan", "Åland Islands", "Albania"]);
+    /// ```
+    pub fn all_sorted_by_name() -> &'static [Jurisdiction] {
+        ALL_BY_NAME
+    }
+
+    /// Return a [JurisdictionQuery] listing all jurisdictions, to narrow by its filters.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, JurisdictionSet, Alpha2};
+    /// let nordics = JurisdictionSet::from_alpha2(&[Alpha2::DK, Alpha2::NO, Alpha2::SE]);
+    /// let jurisdictions: Vec<_> = Jurisdiction::query()
+    ///     .member_of(&nordics)
+    ///     .exclude([Alpha2::SE])
+    ///     .collect();
+    /// assert_eq!(jurisdictions, [Alpha2::DK, Alpha2::NO]);
+    /// ```
+    ///
+    /// [JurisdictionQuery]: struct.JurisdictionQuery.html
+    pub fn query() -> JurisdictionQuery {
+        JurisdictionQuery::new()
+    }
+
+    /// Compare jurisdictions by their english name.
+    ///
+    /// The names are compared by their [ASCII folded](#method.name_ascii) form, such that
+    /// diacritics and case do not affect the order, e.g. Åland Islands follows Afghanistan.
+    /// Names that fold equally are ordered by their unfolded form. For the alphabet of a
+    /// particular locale, see [sort_by_display_name](#method.sort_by_display_name).
+    pub fn cmp_by_name(&self, other: &Jurisdiction) -> Ordering {
+        self.name_ascii()
+            .cmp(other.name_ascii())
+            .then_with(|| self.name().cmp(other.name()))
+    }
+
+    /// Return all independent, sovereign states, in the order of [all](#method.all).
+    ///
+    /// See [is_sovereign_state](#method.is_sovereign_state).
+    #[cfg(feature = "sovereignty")]
+    pub fn all_sovereign() -> impl Iterator<Item = Jurisdiction> {
+        Jurisdiction::all().filter(Jurisdiction::is_sovereign_state)
+    }
+
+    /// Sort jurisdictions by the collation of their english [name](#method.name) in
+    /// `locale`, a BCP-47 language tag such as `sv`.
+    ///
+    /// Unknown locales fall back to the root collation, see the [collation] module.
+    ///
+    /// [collation]: collation/index.html
+    #[cfg(feature = "collation")]
+    pub fn sort_by_display_name(jurisdictions: &mut [Jurisdiction], locale: &str) {
+        jurisdictions.sort_by(crate::collation::cmp_names_collated(locale));
+    }
+
+    /// Construct a `Jurisdiction` from its flag emoji.
+    ///
+    /// A flag emoji is a pair of regional indicator symbols spelling the alpha2 code of its
+    /// jurisdiction, e.g. 🇳🇴 spells NO. A trailing emoji variation selector (U+FE0F), as
+    /// appended by some keyboards and chat clients, is tolerated. Any other input, and
+    /// pairs spelling an unassigned code, are rejected.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// assert_eq!(Jurisdiction::from_flag("🇳🇴").unwrap(), Alpha2::NO);
+    /// assert_eq!(Jurisdiction::from_flag("🇳🇴\u{FE0F}").unwrap(), Alpha2::NO);
+    /// assert!(Jurisdiction::from_flag("🇽🇽").is_err());
+    /// assert!(Jurisdiction::from_flag("NO").is_err());
+    /// ```
+    pub fn from_flag(flag: &str) -> anyhow::Result<Jurisdiction> {
+        let mut symbols = flag.strip_suffix('\u{FE0F}').unwrap_or(flag).chars();
+        let letters = match (symbols.next(), symbols.next(), symbols.next()) {
+            (Some(first), Some(second), None) => {
+                regional_indicator_letter(first).zip(regional_indicator_letter(second))
+            }
+            _ => None,
+        };
+        let (first, second) = letters.ok_or_else(|| {
+            format_err!("not a flag of two regional indicator symbols: {:?}", flag)
+        })?;
+        let code = [first, second];
+        let code = std::str::from_utf8(&code).expect("regional indicators are ASCII letters");
+        Alpha2::from_code(code)
+            .map(Jurisdiction::from)
+            .ok_or_else(|| format_err!("unrecognized ISO 3166 alpha2 code of flag: {}", code))
+    }
+
+    /// Return the jurisdiction of an english [name](#method.name), ignoring case.
+    ///
+    /// Names without any match are looked up among the [ASCII folded](#method.name_ascii)
+    /// names as well, such that `cote d'ivoire` finds Côte d'Ivoire.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// assert_eq!(Jurisdiction::from_name("norway"), Some(Alpha2::NO.into()));
+    /// assert_eq!(Jurisdiction::from_name("curacao"), Some(Alpha2::CW.into()));
+    /// assert_eq!(Jurisdiction::from_name("Norw"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Jurisdiction> {
+        crate::search::by_name(name)
+    }
+
+    /// Return the jurisdictions whose english [name](#method.name) starts with `prefix`,
+    /// ignoring case, ordered alphabetically by name.
+    ///
+    /// Case is folded by Unicode lower case mapping, such that `åland` matches
+    /// Åland Islands. Prefixes without any match are searched among the
+    /// [ASCII folded](#method.name_ascii) names as well, such that `cote` matches
+    /// Côte d'Ivoire. An empty prefix matches every jurisdiction. The names are indexed
+    /// on the first search, such that repeated searches are cheap.
+    ///
+    /// ```rust
+    /// # use jurisdiction::Jurisdiction;
+    /// let names: Vec<_> = Jurisdiction::find_by_name_prefix("new")
+    ///     .map(|jurisdiction| jurisdiction.name())
+    ///     .collect();
+    /// assert_eq!(names, ["New Caledonia", "New Zealand"]);
+    /// ```
+    pub fn find_by_name_prefix(prefix: &str) -> impl Iterator<Item = Jurisdiction> {
+        crate::search::by_name_prefix(prefix)
+    }
+
+    /// Return the jurisdiction whose english [name](#method.name) best matches `query`,
+    /// tolerating misspellings, along with its score of similarity.
+    ///
+    /// The query is scored against the [ASCII folded](#method.name_ascii) names by their
+    /// Jaro-Winkler similarity, from `0.0` to `1.0` for an exact match, and against the
+    /// leading words of the names, such that `united states` matches the United States of
+    /// America. Matches scoring below `0.85` are discarded, returning `None` when nothing
+    /// matches. Ties are broken by the similarity to the whole name, and then by
+    /// [index](#method.index), such that the result is deterministic.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let (jurisdiction, score) = Jurisdiction::find_best_match("Untied States").unwrap();
+    /// assert_eq!(jurisdiction, Alpha2::US);
+    /// assert!(score < 1.0);
+    /// assert_eq!(Jurisdiction::find_best_match("xqzvbkw"), None);
+    /// ```
+    pub fn find_best_match(query: &str) -> Option<(Jurisdiction, f32)> {
+        crate::fuzzy::best_match(query)
+    }
+
+    /// Return the at most `limit` jurisdictions best matching `query`, along with their
+    /// scores, best match first.
+    ///
+    /// The matches are scored and ranked as in [find_best_match](#method.find_best_match).
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let matches = Jurisdiction::find_matches("congo", 2);
+    /// assert_eq!(matches[0].0, Alpha2::CG);
+    /// assert_eq!(matches[1].0, Alpha2::CD);
+    /// ```
+    pub fn find_matches(query: &str, limit: usize) -> Vec<(Jurisdiction, f32)> {
+        crate::fuzzy::matches(query, limit)
+    }
+
+    /// Parse a list of alpha2 or alpha3 codes, ignoring case, separated by commas,
+    /// semicolons or whitespace, such as `NO,SE, DK ;FI`.
+    ///
+    /// Empty entries are skipped, and duplicates are kept. Every invalid entry is reported
+    /// along with its position, see the [list](list/index.html) module.
+    pub fn parse_list(list: &str) -> Result<Vec<Jurisdiction>, ParseListError> {
+        crate::list::parse(list, false)
+    }
+
+    /// Parse a list of codes as by [parse_list](#method.parse_list), keeping only the first
+    /// occurrence of each jurisdiction.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let allowed = Jurisdiction::parse_list_unique("NO;SE;NOR;DK").unwrap();
+    /// assert_eq!(allowed, [Alpha2::NO, Alpha2::SE, Alpha2::DK]);
+    /// ```
+    pub fn parse_list_unique(list: &str) -> Result<Vec<Jurisdiction>, ParseListError> {
+        crate::list::parse(list, true)
+    }
+
     /// Return the english name of this jurisdiction.
-    pub fn name(&self) -> &str {
-        self.definition.name
+    pub fn name(&self) -> &'static str {
+        self.definition.name.get()
+    }
+
+    /// Return the english name of this jurisdiction folded to lower case ASCII for search,
+    /// dropping diacritics and punctuation.
+    ///
+    /// Hyphens separate words like whitespace, such that the name of Guinea-Bissau folds to
+    /// `guinea bissau`. The folded names are computed ahead of time rather than through
+    /// Unicode tables at runtime.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let ivory_coast = Jurisdiction::from(Alpha2::CI);
+    /// assert_eq!(ivory_coast.name(), "Côte d'Ivoire");
+    /// assert_eq!(ivory_coast.name_ascii(), "cote divoire");
+    /// ```
+    pub fn name_ascii(&self) -> &'static str {
+        self.definition.name_ascii.get()
+    }
+
+    /// Return the formal name of this jurisdiction, as used in legal documents.
+    ///
+    /// Jurisdictions whose formal name is identical to the [name](#method.name) return
+    /// the very same static string.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.name(), "Norway");
+    /// assert_eq!(norway.formal_name(), "Kingdom of Norway");
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is sourced from the ISO 3166-1 official names of the Debian iso-codes project.
+    pub fn formal_name(&self) -> &'static str {
+        self.definition
+            .formal_name
+            .unwrap_or(self.definition.name)
+            .get()
+    }
+
+    /// Return the english demonym of this jurisdiction, e.g. `Norwegian` for Norway.
+    ///
+    /// Return `None` for jurisdictions without a conventional demonym, such as
+    /// uninhabited territories.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let netherlands = Jurisdiction::from(Alpha2::NL);
+    /// assert_eq!(netherlands.demonym(), Some("Dutch"));
+    /// ```
+    #[cfg(feature = "demonym")]
+    pub fn demonym(&self) -> Option<&'static str> {
+        self.definition.demonym.map(|(demonym, _)| demonym)
+    }
+
+    /// Return the english plural noun for the people of this jurisdiction,
+    /// e.g. `Germans` for Germany and `Swiss` for Switzerland.
+    ///
+    /// Return `None` where [demonym](#method.demonym) returns `None`.
+    #[cfg(feature = "demonym")]
+    pub fn demonym_plural(&self) -> Option<&'static str> {
+        self.definition.demonym.map(|(_, plural)| plural)
+    }
+
+    /// Return whether this jurisdiction is an independent, sovereign state.
+    ///
+    /// # Origin
+    /// The definition follows the independent flag of ISO 3166-1.
+    #[cfg(feature = "sovereignty")]
+    pub fn is_sovereign_state(&self) -> bool {
+        self.definition.independent()
+    }
+
+    /// Return the sovereign state of this dependent territory, e.g. Denmark for Greenland.
+    ///
+    /// Return `None` for sovereign states themselves, and for territories without
+    /// an undisputed sovereign, such as Antarctica and Western Sahara.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let greenland = Jurisdiction::from(Alpha2::GL);
+    /// assert_eq!(greenland.sovereign().unwrap(), Alpha2::DK);
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn sovereign(&self) -> Option<Jurisdiction> {
+        self.definition.sovereign().map(Jurisdiction::from)
+    }
+
+    /// Return the dependent territories whose sovereign state is this jurisdiction,
+    /// in the order of [all](#method.all).
+    ///
+    /// Jurisdictions without dependent territories return an empty list.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let uk = Jurisdiction::from(Alpha2::GB);
+    /// assert!(uk.dependencies().contains(&Alpha2::GI.into()));
+    /// ```
+    #[cfg(feature = "sovereignty")]
+    pub fn dependencies(&self) -> &'static [Jurisdiction] {
+        self.definition.dependencies
+    }
+
+    /// Return the [TerritoryKind] of this jurisdiction, e.g. a dependent territory for
+    /// Greenland.
+    ///
+    /// Where [sovereign](#method.sovereign) answers who governs a jurisdiction, the kind
+    /// answers what it is. See the [territory] module on the kinds.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// use jurisdiction::territory::TerritoryKind;
+    ///
+    /// let hong_kong = Jurisdiction::from(Alpha2::HK);
+    /// assert_eq!(hong_kong.territory_kind(), TerritoryKind::SpecialAdministrativeRegion);
+    /// ```
+    ///
+    /// [TerritoryKind]: territory/enum.TerritoryKind.html
+    /// [territory]: territory/index.html
+    #[cfg(feature = "sovereignty")]
+    pub fn territory_kind(&self) -> TerritoryKind {
+        self.definition.territory_kind()
+    }
+
+    /// Return the name of this jurisdiction in `language`.
+    ///
+    /// Falls back to the english [name](#method.name) if the names in `language` are not
+    /// embedded, see [Language], as well as for a missing translation in an embedded language.
+    ///
+    /// # Origin
+    /// The definition is sourced from the ISO 3166-1 translations of the Debian iso-codes project.
+    ///
+    /// [Language]: localized/enum.Language.html
+    #[cfg(feature = "localized-names")]
+    pub fn name_in(&self, language: Language) -> Option<&'static str> {
+        if !language.is_embedded() {
+            return Some(self.definition.name.get());
+        }
+
+        let localized = self
+            .definition
+            .localized_names
+            .iter()
+            .find(|(lang, _)| *lang == language)
+            .map(|(_, name)| *name);
+        Some(localized.unwrap_or_else(|| self.definition.name.get()))
     }
 
     /// Return the ISO-3166 numeric country code made up of 3 characters.
@@ -74,7 +710,23 @@ impl Jurisdiction {
     /// # Origin
     /// The definition is sourced from ISO-3166 standard.
     pub fn country_code(&self) -> u16 {
-        self.definition.country_code
+        self.definition.country_code()
+    }
+
+    /// Return the ISO-3166 numeric [country code](#method.country_code) in its canonical
+    /// form, zero-padded to 3 digits.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let austria = Jurisdiction::from(Alpha2::AT);
+    /// assert_eq!(austria.country_code(), 40);
+    /// assert_eq!(austria.numeric_str(), "040");
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is sourced from ISO-3166 standard.
+    pub fn numeric_str(&self) -> &'static str {
+        self.definition.numeric.get()
     }
 
     /// Return the two letter [Alpha2] representation for this `Jurisdiction`.
@@ -94,7 +746,29 @@ impl Jurisdiction {
     ///
     /// [Alpha3]: enum.Alpha3.html
     pub fn alpha3(&self) -> Alpha3 {
-        self.definition.alpha3
+        self.definition.alpha3()
+    }
+
+    /// Return a [JurisdictionInfo] snapshot of the definition of this `Jurisdiction`.
+    ///
+    /// [JurisdictionInfo]: info/struct.JurisdictionInfo.html
+    pub fn info(&self) -> JurisdictionInfo {
+        JurisdictionInfo::from(self)
+    }
+
+    /// Return the country prefix of the ISO 3166-2 subdivision codes of this `Jurisdiction`,
+    /// e.g. `NO` for the `NO-03` code of Oslo.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.iso3166_2_prefix(), "NO");
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is sourced from the ISO 3166-2 reference of the ISO-3166 standard.
+    pub fn iso3166_2_prefix(&self) -> &'static str {
+        self.definition.iso3166_2_prefix.get()
     }
 
     /// Return the [Region] on earth this `Jurisdiction` is situated in.
@@ -106,7 +780,7 @@ impl Jurisdiction {
     /// [Region]: region/enum.Region.html
     #[cfg(feature = "region")]
     pub fn region(&self) -> Region {
-        self.definition.region
+        self.definition.region()
     }
 
     /// Return the [SubRegion] of a [Region] this `Jurisdiction` is situated in.
@@ -119,7 +793,7 @@ impl Jurisdiction {
     /// [SubRegion]: region/enum.SubRegion.html
     #[cfg(feature = "region")]
     pub fn sub_region(&self) -> SubRegion {
-        self.definition.sub_region
+        self.definition.sub_region()
     }
 
     /// Return the [IntermediateRegion] of a [SubRegion] this `Jurisdiction` is situated in.
@@ -135,19 +809,49 @@ impl Jurisdiction {
     /// [SubRegion]: region/enum.SubRegion.html
     #[cfg(feature = "region")]
     pub fn intermediate_region(&self) -> IntermediateRegion {
-        self.definition.intermediate_region
+        self.definition.intermediate_region()
+    }
+
+    /// Return the [RegionPath] of this `Jurisdiction`, from its region down to its
+    /// intermediate region, if any.
+    ///
+    /// The path displays as the slash separated names of the regions, e.g.
+    /// `Europe/Northern Europe/Channel Islands` for Guernsey.
+    ///
+    /// [RegionPath]: region/struct.RegionPath.html
+    #[cfg(feature = "region")]
+    pub fn region_path(&self) -> RegionPath {
+        RegionPath {
+            region: self.definition.region(),
+            sub_region: self.definition.sub_region(),
+            intermediate_region: self
+                .definition
+                .intermediate_region_code()
+                .map(|_| self.definition.intermediate_region()),
+        }
     }
 
     /// Return the 3 character numeric identifier for the [Region] this `Jurisdiction` is situated in.
     ///
+    /// M49 places Antarctica outside of any region, directly beneath the world, as a top
+    /// level area of its own. Its region is thus [Undefined], and its region code is its
+    /// own area code, 010.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// assert_eq!(Jurisdiction::from(Alpha2::NO).region_code(), 150);
+    /// assert_eq!(Jurisdiction::from(Alpha2::AQ).region_code(), 10);
+    /// ```
+    ///
     /// # Origin
     /// The definition is sourced from the statistics division of the UN
     /// for standard country and area codes for statistical use (M49).
     ///
     /// [Region]: region/enum.Region.html
+    /// [Undefined]: region/enum.Region.html#variant.Undefined
     #[cfg(feature = "region")]
     pub fn region_code(&self) -> u16 {
-        self.definition.region_code
+        self.definition.region_code()
     }
 
     /// Return the 3 character numeric identifier for the [SubRegion] this `Jurisdiction` is situated in.
@@ -159,7 +863,7 @@ impl Jurisdiction {
     /// [SubRegion]: region/enum.SubRegion.html
     #[cfg(feature = "region")]
     pub fn sub_region_code(&self) -> u16 {
-        self.definition.sub_region_code
+        self.definition.sub_region_code()
     }
 
     /// Return the 3 character numeric identifier for the [IntermediateRegion]
@@ -172,75 +876,888 @@ impl Jurisdiction {
     /// [IntermediateRegion]: region/enum.IntermediateRegion.html
     #[cfg(feature = "region")]
     pub fn intermediate_region_code(&self) -> Option<u16> {
-        self.definition.intermediate_region_code
+        self.definition.intermediate_region_code()
     }
 
-    /// Return all Jurisdictions zoning to specified region.
+    /// Return whether this and the other `Jurisdiction` are situated in the same [Region].
+    ///
+    /// Jurisdictions outside of any region, such as Antarctica, share no region with any
+    /// jurisdiction, themselves included.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert!(norway.same_region(&Alpha2::DE.into()));
+    /// assert!(!norway.same_region(&Alpha2::JP.into()));
+    /// ```
+    ///
+    /// [Region]: region/enum.Region.html
     #[cfg(feature = "region")]
-    pub fn in_region(region: Region) -> Vec<Jurisdiction> {
-        region.jurisdictions()
+    pub fn same_region(&self, other: &Jurisdiction) -> bool {
+        self.region() != Region::Undefined && self.region() == other.region()
     }
 
-    /// Return all Jurisdictions zoning to specified sub region.
+    /// Return whether this and the other `Jurisdiction` are situated in the same
+    /// [SubRegion].
+    ///
+    /// Jurisdictions outside of any sub region, such as Antarctica, share no sub region
+    /// with any jurisdiction, themselves included.
+    ///
+    /// [SubRegion]: region/enum.SubRegion.html
     #[cfg(feature = "region")]
-    pub fn in_sub_region(sub: SubRegion) -> Vec<Jurisdiction> {
-        sub.jurisdictions()
+    pub fn same_sub_region(&self, other: &Jurisdiction) -> bool {
+        self.sub_region() != SubRegion::Undefined && self.sub_region() == other.sub_region()
     }
 
-    /// Return all Jurisdictions zoning to specified sub region.
+    /// Return whether this and the other `Jurisdiction` are situated in the same
+    /// [IntermediateRegion].
+    ///
+    /// Jurisdictions without an intermediate region share none with any jurisdiction,
+    /// themselves included, such that Norway and Sweden share their sub region, but not
+    /// an intermediate region.
+    ///
+    /// [IntermediateRegion]: region/enum.IntermediateRegion.html
     #[cfg(feature = "region")]
-    pub fn in_intermediate_region(inter: IntermediateRegion) -> Vec<Jurisdiction> {
-        inter.jurisdictions()
+    pub fn same_intermediate_region(&self, other: &Jurisdiction) -> bool {
+        self.intermediate_region_code().is_some()
+            && self.intermediate_region_code() == other.intermediate_region_code()
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_from_trait_alpha2() {
-        let no = Jurisdiction::from(Alpha2::NO);
-        assert_eq!(no.name(), "Norway");
+    /// Return the [Weekday] a calendar week starts on within this `Jurisdiction`.
+    ///
+    /// # Origin
+    /// The definition is sourced from the CLDR supplemental week data.
+    ///
+    /// [Weekday]: conventions/enum.Weekday.html
+    #[cfg(feature = "conventions")]
+    pub fn first_day_of_week(&self) -> Weekday {
+        self.definition.first_day_of_week()
     }
 
-    #[test]
-    fn test_from_trait_alpha3() {
-        let no = Jurisdiction::from(Alpha3::NOR);
-        assert_eq!(no.name(), "Norway");
+    /// Return the [Weekday]s making up the weekend within this `Jurisdiction`, in order.
+    ///
+    /// # Origin
+    /// The definition is sourced from the CLDR supplemental week data.
+    ///
+    /// [Weekday]: conventions/enum.Weekday.html
+    #[cfg(feature = "conventions")]
+    pub fn weekend(&self) -> &'static [Weekday] {
+        self.definition.weekend
     }
 
-    #[test]
-    fn test_compare_jurisdiction_with_alpha2() {
-        let no = Jurisdiction::from(Alpha2::NO);
-
-        assert_eq!(no, Alpha2::NO);
+    /// Return the [MeasurementSystem] in everyday use within this `Jurisdiction`.
+    ///
+    /// # Origin
+    /// The definition is sourced from the CLDR supplemental measurement data.
+    ///
+    /// [MeasurementSystem]: conventions/enum.MeasurementSystem.html
+    #[cfg(feature = "conventions")]
+    pub fn measurement_system(&self) -> MeasurementSystem {
+        self.definition.measurement_system()
     }
 
-    #[test]
-    fn test_compare_jurisdiction_with_alpha3() {
-        let no = Jurisdiction::from(Alpha3::NOR);
+    /// Return the default [PaperSize] for printed documents within this `Jurisdiction`.
+    ///
+    /// # Origin
+    /// The definition is sourced from the CLDR supplemental measurement data.
+    ///
+    /// [PaperSize]: conventions/enum.PaperSize.html
+    #[cfg(feature = "conventions")]
+    pub fn paper_size(&self) -> PaperSize {
+        self.definition.paper_size()
+    }
 
-        assert_eq!(no, Alpha3::NOR);
+    /// Return the [EmergencyNumbers] to dial within this `Jurisdiction`.
+    ///
+    /// Jurisdictions without numbers in the dataset, such as uninhabited territories,
+    /// return numbers that are all `None`.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.emergency_numbers().ambulance, Some("113"));
+    /// ```
+    ///
+    /// [EmergencyNumbers]: conventions/struct.EmergencyNumbers.html
+    #[cfg(feature = "conventions")]
+    pub fn emergency_numbers(&self) -> EmergencyNumbers {
+        self.definition.emergency_numbers
     }
 
-    #[test]
-    fn test_in_region() {
-        let europe = Jurisdiction::in_region(Region::Europe);
-        assert!(europe.contains(&Jurisdiction::from(Alpha2::NO)));
+    /// Return the length of the IBANs issued by this `Jurisdiction`.
+    ///
+    /// Jurisdictions not issuing IBANs under their own country code return `None`.
+    ///
+    /// # Origin
+    /// The definition is sourced from the IBAN registry maintained by SWIFT.
+    #[cfg(feature = "banking")]
+    pub fn iban_length(&self) -> Option<u8> {
+        self.definition.iban_length()
+    }
 
-        let undefined = Jurisdiction::in_region(Region::Undefined);
-        assert_eq!(undefined.len(), 1);
-        assert!(undefined.contains(&Jurisdiction::from(Alpha3::ATA)));
+    /// Return whether this `Jurisdiction` is part of the Single Euro Payments Area (SEPA).
+    ///
+    /// # Origin
+    /// The definition is sourced from the list of SEPA countries published by the
+    /// European Payments Council.
+    #[cfg(feature = "banking")]
+    pub fn is_sepa_member(&self) -> bool {
+        self.definition.sepa_member()
     }
 
-    #[test]
+    /// Return the UIC railway country code of this `Jurisdiction`, e.g. `76` for Norway.
+    ///
+    /// Return `None` for jurisdictions without a code of their own, which are most
+    /// jurisdictions outside of Europe and Asia. See the [rail] module on the code.
+    ///
+    /// [rail]: rail/index.html
+    #[cfg(feature = "rail")]
+    pub fn uic_code(&self) -> Option<u8> {
+        self.definition.uic_code()
+    }
+
+    /// Return the codes of the CLDR territories containing this `Jurisdiction`, nearest
+    /// first, up to the world, `001`.
+    ///
+    /// Only the containment tree of CLDR is followed, leaving out its groupings such as the
+    /// European Union. See the [cldr] module on the containment.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.cldr_parents(), ["154", "150", "001"]);
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is sourced from the territory containment of the Unicode CLDR
+    /// supplemental data.
+    ///
+    /// [cldr]: cldr/index.html
+    #[cfg(feature = "cldr-containment")]
+    pub fn cldr_parents(&self) -> &'static [&'static str] {
+        self.definition.cldr_parents
+    }
+
+    /// Return the ranges of GS1 barcode prefixes allocated to this `Jurisdiction`.
+    ///
+    /// Ranges shared with other jurisdictions are included, such as `000`–`019` for both
+    /// the United States and Canada. See the [gs1] module on what the prefix identifies.
+    ///
+    /// [gs1]: gs1/index.html
+    #[cfg(feature = "gs1")]
+    pub fn gs1_prefixes(&self) -> &'static [RangeInclusive<u16>] {
+        self.definition.gs1_prefixes
+    }
+
+    /// Return the WIPO ST.3 code of this `Jurisdiction`, used by patent and trademark offices.
+    ///
+    /// The code follows the alpha2 code of ISO 3166, yet is given by its own table. See
+    /// the [ip] module on resolving a code.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.st3_code(), Some("NO"));
+    /// ```
+    ///
+    /// [ip]: ip/index.html
+    #[cfg(feature = "ip")]
+    pub fn st3_code(&self) -> Option<&'static str> {
+        self.definition.st3_code
+    }
+
+    /// Return the Eurostat country code of this `Jurisdiction`.
+    ///
+    /// The code is the alpha2 code of ISO 3166, except for the exceptions listed by the
+    /// [eurostat] module, such as `EL` for Greece.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// assert_eq!(Jurisdiction::from(Alpha2::GR).eurostat_code(), "EL");
+    /// assert_eq!(Jurisdiction::from(Alpha2::NO).eurostat_code(), "NO");
+    /// ```
+    ///
+    /// [eurostat]: eurostat/index.html
+    #[cfg(feature = "eurostat")]
+    pub fn eurostat_code(&self) -> &'static str {
+        crate::eurostat::code(self.definition.alpha2)
+    }
+
+    /// Return the `Jurisdiction` of a Eurostat country code, or of an ISO 3166 alpha2 code,
+    /// ignoring case.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// assert_eq!(Jurisdiction::from_eurostat("UK"), Some(Alpha2::GB.into()));
+    /// assert_eq!(Jurisdiction::from_eurostat("GB"), Some(Alpha2::GB.into()));
+    /// ```
+    #[cfg(feature = "eurostat")]
+    pub fn from_eurostat(code: &str) -> Option<Jurisdiction> {
+        crate::eurostat::resolve(code).map(Jurisdiction::from)
+    }
+
+    /// Return whether this `Jurisdiction` is covered by the NUTS classification of
+    /// statistical regions.
+    ///
+    /// Equivalent to membership of [nuts::COVERED](nuts/constant.COVERED.html).
+    #[cfg(feature = "nuts")]
+    pub fn has_nuts(&self) -> bool {
+        crate::nuts::COVERED.contains(self)
+    }
+
+    /// Return the aircraft registration prefixes of this `Jurisdiction`, as written in
+    /// registrations, e.g. `LN-` for Norway and `N` for the United States.
+    ///
+    /// The nationality mark is followed by a hyphen unless the registrations of the
+    /// jurisdiction omit it. See the [aviation] module on resolving a registration.
+    ///
+    /// [aviation]: aviation/index.html
+    #[cfg(feature = "aviation")]
+    pub fn aircraft_prefixes(&self) -> &'static [&'static str] {
+        self.definition.aircraft_prefixes
+    }
+
+    /// Return the ITU call sign series allocated to this `Jurisdiction`, e.g. `"LA"..="LN"`
+    /// for Norway.
+    ///
+    /// Each range spans the prefixes of equal length from its start to its end, such that
+    /// `"W"..="W"` is the entire series of call signs starting with `W`. See the [radio]
+    /// module on resolving a call sign.
+    ///
+    /// [radio]: radio/index.html
+    #[cfg(feature = "radio")]
+    pub fn callsign_prefixes(&self) -> &'static [RangeInclusive<&'static str>] {
+        self.definition.callsign_prefixes
+    }
+
+    /// Return the E.212 mobile country codes of this `Jurisdiction`, e.g. `242` for Norway.
+    ///
+    /// Codes shared with other jurisdictions are included, such as `340` for the French
+    /// Antilles. See the [telephony] module on resolving a code.
+    ///
+    /// [telephony]: telephony/index.html
+    #[cfg(feature = "telephony")]
+    pub fn mobile_country_codes(&self) -> &'static [u16] {
+        self.definition.mobile_country_codes
+    }
+
+    /// Return the BCP-47 tags of the locales likely in use within this `Jurisdiction`,
+    /// in priority order.
+    ///
+    /// Dependent territories without languages of their own inherit the locales of
+    /// their sovereign, and uninhabited Antarctica returns an empty list.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.default_locales(), &["nb-NO", "nn-NO"]);
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is sourced from the CLDR likely subtags and territory language data.
+    #[cfg(feature = "locale")]
+    pub fn default_locales(&self) -> &'static [&'static str] {
+        self.definition.default_locales
+    }
+
+    /// Return the BCP-47 tag of the primary locale within this `Jurisdiction`.
+    ///
+    /// This is the first of the [default_locales](#method.default_locales).
+    #[cfg(feature = "locale")]
+    pub fn primary_locale(&self) -> Option<&'static str> {
+        self.definition.default_locales.first().copied()
+    }
+
+    /// Return the [PostalFormat] of the postal codes within this `Jurisdiction`.
+    ///
+    /// Jurisdictions without a postal code system return `None`.
+    ///
+    /// [PostalFormat]: postal/struct.PostalFormat.html
+    #[cfg(feature = "postal")]
+    pub fn postal_code_format(&self) -> Option<PostalFormat> {
+        self.definition.postal_code_format
+    }
+
+    /// Return whether `code` is a plausible postal code within this `Jurisdiction`.
+    ///
+    /// Only the format of the postal code is validated, not whether it is assigned.
+    /// Jurisdictions without a postal code system impose no format, and accept any `code`.
+    #[cfg(feature = "postal")]
+    pub fn validate_postal_code(&self, code: &str) -> bool {
+        match self.definition.postal_code_format {
+            Some(format) => format.is_match(code),
+            None => true,
+        }
+    }
+
+    /// Return the prefix of the VAT identification numbers issued by this `Jurisdiction`.
+    ///
+    /// This is usually the [Alpha2] code, but Greece uses `EL`. Jurisdictions
+    /// outside the European Union VAT area return `None`.
+    ///
+    /// [Alpha2]: enum.Alpha2.html
+    #[cfg(feature = "vat")]
+    pub fn vat_prefix(&self) -> Option<&'static str> {
+        self.vat_number_format().map(|format| format.prefix())
+    }
+
+    /// Return the [VatFormat] of the VAT identification numbers issued by this `Jurisdiction`.
+    ///
+    /// Jurisdictions outside the European Union VAT area return `None`.
+    ///
+    /// [VatFormat]: vat/struct.VatFormat.html
+    #[cfg(feature = "vat")]
+    pub fn vat_number_format(&self) -> Option<VatFormat> {
+        crate::vat::primary_format(self.definition.alpha2)
+    }
+
+    /// Return all ISO 3166-2 [Subdivision]s of this `Jurisdiction`, ordered by code.
+    ///
+    /// Jurisdictions without any defined subdivisions return an empty list.
+    ///
+    /// [Subdivision]: subdivision/struct.Subdivision.html
+    #[cfg(feature = "subdivision")]
+    pub fn subdivisions(&self) -> Vec<Subdivision> {
+        crate::subdivision::of_country(self.definition.alpha2)
+    }
+
+    /// Return the [Continent] of this jurisdiction, by the seven-continent model.
+    ///
+    /// See the [continent] module on the assignment of transcontinental jurisdictions.
+    ///
+    /// [Continent]: continent/enum.Continent.html
+    /// [continent]: continent/index.html
+    #[cfg(feature = "continent")]
+    pub fn continent(&self) -> Continent {
+        self.definition.continent()
+    }
+
+    /// Return the World Bank [IncomeGroup] of this jurisdiction.
+    ///
+    /// Return `None` for jurisdictions not classified by the World Bank.
+    ///
+    /// # Origin
+    /// The definition is a snapshot of the World Bank country classifications by income level.
+    /// The fiscal year of the snapshot is given by the version of the `income-group`
+    /// [dataset](dataset/index.html).
+    ///
+    /// [IncomeGroup]: economics/enum.IncomeGroup.html
+    #[cfg(feature = "economics")]
+    pub fn income_group(&self) -> Option<IncomeGroup> {
+        self.definition.income_group()
+    }
+
+    /// Return the [LegalSystem] of this jurisdiction.
+    ///
+    /// Jurisdictions combining several legal traditions return [LegalSystem::Mixed].
+    /// Return `None` for uninhabited territories, which have no legal system of their own.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// use jurisdiction::legal::LegalSystem;
+    ///
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.legal_system(), Some(LegalSystem::CivilLaw));
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is a curated classification, with the reference backing each
+    /// classification kept alongside it in `data/legal-system.json`.
+    ///
+    /// [LegalSystem]: legal/enum.LegalSystem.html
+    /// [LegalSystem::Mixed]: legal/enum.LegalSystem.html#variant.Mixed
+    #[cfg(feature = "legal")]
+    pub fn legal_system(&self) -> Option<LegalSystem> {
+        self.definition.legal_system()
+    }
+
+    /// Return the area of this jurisdiction in square kilometres, including inland waters.
+    ///
+    /// The area is rounded to whole square kilometres, yet at least `1` such that the
+    /// microstates, e.g. Vatican City of less than half a square kilometre, never have an
+    /// area of zero.
+    ///
+    /// ```rust
+    /// # use jurisdiction::{Jurisdiction, Alpha2};
+    /// let norway = Jurisdiction::from(Alpha2::NO);
+    /// assert_eq!(norway.area_km2(), Some(385_207));
+    /// ```
+    ///
+    /// # Origin
+    /// The definition is a snapshot of United Nations and World Bank estimates. The year of
+    /// the snapshot is given by the version of the `statistics` [dataset](dataset/index.html).
+    #[cfg(feature = "statistics")]
+    pub fn area_km2(&self) -> Option<u32> {
+        self.definition.area_km2()
+    }
+
+    /// Return the permanent population of this jurisdiction.
+    ///
+    /// Return `None` for jurisdictions without a permanent population, such as Antarctica and
+    /// the territories only inhabited by research or military staff, rather than a
+    /// population of zero to divide by.
+    ///
+    /// # Origin
+    /// The definition is a snapshot of United Nations and World Bank estimates. The year of
+    /// the snapshot is given by the version of the `statistics` [dataset](dataset/index.html).
+    #[cfg(feature = "statistics")]
+    pub fn population(&self) -> Option<u64> {
+        self.definition.population()
+    }
+
+    /// Return the electrical [PlugType]s in use within this jurisdiction.
+    ///
+    /// Uninhabited jurisdictions return an empty list.
+    ///
+    /// # Origin
+    /// The definition is sourced from IEC World Plugs.
+    ///
+    /// [PlugType]: power/enum.PlugType.html
+    #[cfg(feature = "power")]
+    pub fn plug_types(&self) -> &'static [PlugType] {
+        self.definition.plug_types
+    }
+
+    /// Return the mains voltages in use within this jurisdiction, in volts.
+    ///
+    /// Jurisdictions with mixed voltages, such as Brazil, return several values.
+    #[cfg(feature = "power")]
+    pub fn mains_voltage(&self) -> &'static [u16] {
+        self.definition.mains_voltage
+    }
+
+    /// Return the mains frequencies in use within this jurisdiction, in hertz.
+    ///
+    /// Jurisdictions with split grids, such as Japan, return several values.
+    #[cfg(feature = "power")]
+    pub fn mains_frequency(&self) -> &'static [u8] {
+        self.definition.mains_frequency
+    }
+
+    /// Return whether this jurisdiction is a member state of the European Union.
+    ///
+    /// Equivalent to membership of [sets::EU](sets/constant.EU.html).
+    #[cfg(feature = "political")]
+    pub fn is_eu_member(&self) -> bool {
+        crate::sets::EU.contains(self)
+    }
+
+    /// Return whether this jurisdiction is a member state of the European Economic Area.
+    ///
+    /// Equivalent to membership of [sets::EEA](sets/constant.EEA.html).
+    #[cfg(feature = "political")]
+    pub fn is_eea_member(&self) -> bool {
+        crate::sets::EEA.contains(self)
+    }
+
+    /// Return whether this jurisdiction is a member state of the European Free Trade Association.
+    ///
+    /// Equivalent to membership of [sets::EFTA](sets/constant.EFTA.html).
+    #[cfg(feature = "political")]
+    pub fn is_efta_member(&self) -> bool {
+        crate::sets::EFTA.contains(self)
+    }
+
+    /// Return whether this jurisdiction is a member state of the Schengen Area.
+    ///
+    /// Equivalent to membership of [sets::SCHENGEN](sets/constant.SCHENGEN.html).
+    #[cfg(feature = "political")]
+    pub fn is_schengen_member(&self) -> bool {
+        crate::sets::SCHENGEN.contains(self)
+    }
+
+    /// Return the UN [UnRegionalGroup] of this jurisdiction.
+    ///
+    /// Return `None` for jurisdictions that are not member states of the United Nations.
+    /// See the [un] module on the groups.
+    ///
+    /// # Origin
+    /// The definition is a snapshot of the regional groups of UN member states. The date of
+    /// the snapshot is given by the version of the `un-regional-group`
+    /// [dataset](dataset/index.html).
+    ///
+    /// [UnRegionalGroup]: un/enum.UnRegionalGroup.html
+    /// [un]: un/index.html
+    #[cfg(feature = "political")]
+    pub fn un_regional_group(&self) -> Option<UnRegionalGroup> {
+        self.definition.un_regional_group()
+    }
+
+    /// Return the [AdequacyStatus] of this jurisdiction by the adequacy decisions of the
+    /// European Commission under the GDPR.
+    ///
+    /// Jurisdictions within the scope of the GDPR, such as the member states of the
+    /// European Union, return `AdequacyStatus::NotAdequate`. See the [gdpr] module on the
+    /// decisions.
+    ///
+    /// # Origin
+    /// The definition is a snapshot of the adequacy decisions in force, and is not legal
+    /// advice. The date of the snapshot is given by the version of the `eu-adequacy`
+    /// [dataset](dataset/index.html).
+    ///
+    /// [AdequacyStatus]: gdpr/enum.AdequacyStatus.html
+    /// [gdpr]: gdpr/index.html
+    #[cfg(feature = "political")]
+    pub fn eu_adequacy(&self) -> AdequacyStatus {
+        self.definition.eu_adequacy()
+    }
+
+    /// Return all jurisdictions grouped by their region, each group in the order of
+    /// [all](#method.all).
+    ///
+    /// See [group_by_region](fn.group_by_region.html) to group a subset of jurisdictions.
+    #[cfg(feature = "region")]
+    pub fn all_grouped_by_region() -> BTreeMap<Region, Vec<Jurisdiction>> {
+        crate::group_by_region(Jurisdiction::all())
+    }
+
+    /// Return all Jurisdictions zoning to specified region, sorted by numeric
+    /// [country_code](#method.country_code).
+    ///
+    /// The [Undefined] region lists the areas M49 places outside of any region, which is
+    /// Antarctica alone.
+    ///
+    /// [Undefined]: region/enum.Region.html#variant.Undefined
+    #[cfg(feature = "region")]
+    pub fn in_region(region: Region) -> Vec<Jurisdiction> {
+        region.jurisdictions().to_vec()
+    }
+
+    /// Return all Jurisdictions zoning to specified sub region, sorted by numeric
+    /// [country_code](#method.country_code).
+    #[cfg(feature = "region")]
+    pub fn in_sub_region(sub: SubRegion) -> Vec<Jurisdiction> {
+        sub.jurisdictions().to_vec()
+    }
+
+    /// Return all Jurisdictions zoning to specified intermediate region, sorted by numeric
+    /// [country_code](#method.country_code).
+    #[cfg(feature = "region")]
+    pub fn in_intermediate_region(inter: IntermediateRegion) -> Vec<Jurisdiction> {
+        inter.jurisdictions().to_vec()
+    }
+
+    /// Return a random `Jurisdiction`, sampled uniformly from [all](#method.all).
+    ///
+    /// Sampling is deterministic for a seeded `rng`, see [index](#method.index) for the
+    /// stability of the sampled jurisdictions across releases.
+    #[cfg(feature = "rand")]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Jurisdiction {
+        rng.gen()
+    }
+
+    /// Return a random `Jurisdiction` zoning to `region`, sampled uniformly from
+    /// [in_region](#method.in_region).
+    ///
+    /// Returns `None` if no jurisdiction zones to `region`.
+    #[cfg(all(feature = "rand", feature = "region"))]
+    pub fn random_in_region<R: Rng + ?Sized>(region: Region, rng: &mut R) -> Option<Jurisdiction> {
+        let jurisdictions = region.jurisdictions();
+        if jurisdictions.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..jurisdictions.len());
+        Some(jurisdictions[index].clone())
+    }
+
+    /// Return the `Jurisdiction` a [phonenumber] `PhoneNumber` is assigned to, as
+    /// determined by its country calling code and national number.
+    ///
+    /// Returns `None` if the number is not assigned to a single region, e.g. international
+    /// freephone numbers, or is assigned to a region that is not a jurisdiction, such as
+    /// Ascension Island.
+    ///
+    /// [phonenumber]: https://docs.rs/phonenumber
+    #[cfg(feature = "phonenumber")]
+    pub fn of_phone_number(number: &phonenumber::PhoneNumber) -> Option<Jurisdiction> {
+        number
+            .country()
+            .id()
+            .and_then(|id| Jurisdiction::try_from(id).ok())
+    }
+
+    /// Return the [iso_currency] currencies in use within this `Jurisdiction`, ordered by
+    /// their alpha code.
+    ///
+    /// The currencies are those listing this jurisdiction among their `used_by`
+    /// countries, including funds and superseded currencies still listed by ISO 4217.
+    /// Jurisdictions without a currency of their own, such as Antarctica, return an empty
+    /// list.
+    ///
+    /// [iso_currency]: https://docs.rs/iso_currency
+    #[cfg(feature = "iso-currency")]
+    pub fn currencies_iso(&self) -> Vec<iso_currency::Currency> {
+        crate::iso_currency_types::currencies_of(self)
+    }
+
+    /// Return the [icu_locid] region subtag of this `Jurisdiction`, its alpha2 code.
+    ///
+    /// [icu_locid]: https://docs.rs/icu_locid
+    #[cfg(feature = "icu")]
+    pub fn to_locale_region(&self) -> icu_locid::subtags::Region {
+        icu_locid::subtags::Region::from(self.alpha2())
+    }
+}
+
+/// Return the uppercase ASCII letter of a regional indicator symbol.
+fn regional_indicator_letter(symbol: char) -> Option<u8> {
+    match u32::from(symbol).checked_sub(0x1F1E6) {
+        Some(offset) if offset < 26 => Some(b'A' + offset as u8),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::definition::DEFINITION_COUNT;
+    use std::collections::BTreeMap;
+
+    /// Run by CI with `--release` as well, where debug assertions are disabled, showing no
+    /// panic is reachable by the conversions into jurisdictions.
+    #[test]
+    fn test_conversions_do_not_panic() {
+        let result = std::panic::catch_unwind(|| {
+            for jurisdiction in GENERATED_DEFINITIONS
+                .iter()
+                .map(Jurisdiction::from_definition)
+            {
+                assert_eq!(Jurisdiction::from(jurisdiction.alpha2()), jurisdiction);
+                assert_eq!(Jurisdiction::from(jurisdiction.alpha3()), jurisdiction);
+            }
+        });
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_from_trait_alpha2() {
+        let no = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(no.name(), "Norway");
+    }
+
+    #[test]
+    fn test_from_trait_alpha3() {
+        let no = Jurisdiction::from(Alpha3::NOR);
+        assert_eq!(no.name(), "Norway");
+    }
+
+    #[test]
+    fn test_compare_jurisdiction_with_alpha2() {
+        let no = Jurisdiction::from(Alpha2::NO);
+
+        assert_eq!(no, Alpha2::NO);
+    }
+
+    #[test]
+    fn test_compare_jurisdiction_with_alpha3() {
+        let no = Jurisdiction::from(Alpha3::NOR);
+
+        assert_eq!(no, Alpha3::NOR);
+    }
+
+    #[test]
+    fn test_iso3166_2_prefix_is_alpha2() {
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(
+                jurisdiction.iso3166_2_prefix(),
+                jurisdiction.alpha2().to_string()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "strum")]
+    fn test_strum_iterates_alpha_codes() {
+        use std::str::FromStr;
+        use strum::{EnumCount, IntoEnumIterator};
+
+        assert_eq!(Alpha2::iter().count(), Jurisdiction::all().count());
+        assert_eq!(Alpha2::COUNT, GENERATED_DEFINITIONS.len());
+        assert_eq!(Alpha3::iter().count(), Alpha3::COUNT);
+        for (alpha2, alpha3) in Alpha2::iter().zip(Alpha3::iter()) {
+            assert_eq!(Jurisdiction::from(alpha2), alpha3);
+        }
+
+        let name: &'static str = Alpha3::NOR.into();
+        assert_eq!(name, "NOR");
+        assert_eq!(Alpha2::from_str("no"), Ok(Alpha2::NO));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_region_path_round_trip() {
+        for jurisdiction in Jurisdiction::all() {
+            let path = jurisdiction.region_path();
+            let breadcrumb = path.to_string();
+            assert_eq!(breadcrumb.parse::<RegionPath>(), Ok(path), "{}", breadcrumb);
+            assert_eq!(
+                breadcrumb.split('/').count(),
+                if jurisdiction.intermediate_region_code().is_some() {
+                    3
+                } else {
+                    2
+                }
+            );
+        }
+
+        let guernsey = Jurisdiction::from(Alpha2::GG).region_path();
+        assert_eq!(
+            guernsey.to_string(),
+            "Europe/Northern Europe/Channel Islands"
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::AQ).region_path().to_string(),
+            "Undefined/Undefined"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_region_path_parse_errors() {
+        let parse = |s: &str| s.parse::<RegionPath>().unwrap_err().to_string();
+        assert_eq!(
+            parse("Europa/Northern Europe"),
+            r#"unrecognized region: "Europa""#
+        );
+        assert_eq!(
+            parse("Europe/Northern Europa"),
+            r#"unrecognized sub region: "Northern Europa""#
+        );
+        assert_eq!(
+            parse("Europe/Northern Europe/Channel Isles"),
+            r#"unrecognized intermediate region: "Channel Isles""#
+        );
+        assert_eq!(
+            parse("Europe"),
+            r#"expected two or three region path segments: "Europe""#
+        );
+        assert!("Europe/Northern Europe/Channel Islands/Guernsey"
+            .parse::<RegionPath>()
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_in_region() {
+        let europe = Jurisdiction::in_region(Region::Europe);
+        assert!(europe.contains(&Jurisdiction::from(Alpha2::NO)));
+
+        let undefined = Jurisdiction::in_region(Region::Undefined);
+        assert_eq!(undefined.len(), 1);
+        assert!(undefined.contains(&Jurisdiction::from(Alpha3::ATA)));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_region_into_iter() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        let europe = Region::Europe.into_iter();
+        assert_eq!(europe.len(), Region::Europe.jurisdiction_count());
+        assert!(europe.clone().any(|j| j == norway));
+        assert!(europe.eq(Jurisdiction::in_region(Region::Europe)));
+
+        let mut count = 0;
+        for jurisdiction in &SubRegion::NorthernEurope {
+            assert_eq!(jurisdiction.sub_region(), SubRegion::NorthernEurope);
+            count += 1;
+        }
+        assert_eq!(count, SubRegion::NorthernEurope.jurisdiction_count());
+        assert_eq!(
+            IntermediateRegion::ChannelIslands.into_iter().len(),
+            IntermediateRegion::ChannelIslands.jurisdiction_count()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_region_sorted_by_country_code() {
+        let codes: Vec<u16> = Jurisdiction::in_sub_region(SubRegion::NorthernEurope)
+            .iter()
+            .map(Jurisdiction::country_code)
+            .collect();
+        let mut sorted = codes.clone();
+        sorted.sort();
+        assert_eq!(codes, sorted);
+
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(Region::Europe.contains(&norway));
+        assert!(SubRegion::NorthernEurope.contains(&norway));
+        assert!(!Region::Asia.contains(&norway));
+        assert!(!IntermediateRegion::ChannelIslands.contains(&norway));
+        assert!(IntermediateRegion::Undefined.contains(&norway));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_antarctica_outside_regions() {
+        let antarctica = Jurisdiction::from(Alpha3::ATA);
+        assert_eq!(antarctica.region(), Region::Undefined);
+        assert_eq!(antarctica.sub_region(), SubRegion::Undefined);
+        assert_eq!(antarctica.region_code(), antarctica.country_code());
+        assert_eq!(antarctica.region_code(), 10);
+        assert_eq!(antarctica.sub_region_code(), 0);
+        assert_eq!(antarctica.intermediate_region_code(), None);
+        assert_eq!(
+            Jurisdiction::in_region(Region::Undefined),
+            std::slice::from_ref(&antarctica)
+        );
+        assert_eq!(
+            Jurisdiction::in_sub_region(SubRegion::Undefined),
+            [antarctica]
+        );
+
+        // Every other jurisdiction zones to a region of its own code
+        for jurisdiction in Jurisdiction::all().filter(|j| *j != Alpha3::ATA) {
+            assert_ne!(jurisdiction.region(), Region::Undefined);
+            assert!(
+                [2, 9, 19, 142, 150].contains(&jurisdiction.region_code()),
+                "{:?}",
+                jurisdiction
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
     fn test_in_sub_region() {
         let africa = Jurisdiction::in_region(Region::Africa);
         assert!(africa.contains(&Jurisdiction::from(Alpha2::AO)));
     }
 
     #[test]
+    #[cfg(feature = "region")]
+    fn test_same_region() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        let sweden = Jurisdiction::from(Alpha2::SE);
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert!(norway.same_region(&sweden));
+        assert!(norway.same_sub_region(&sweden));
+        assert!(norway.same_region(&germany));
+        assert!(!norway.same_sub_region(&germany));
+        assert!(!norway.same_region(&Alpha2::JP.into()));
+
+        // Neither is situated in an intermediate region
+        assert!(!norway.same_intermediate_region(&sweden));
+        assert!(!norway.same_intermediate_region(&norway));
+        let guernsey = Jurisdiction::from(Alpha2::GG);
+        assert!(guernsey.same_intermediate_region(&Alpha2::JE.into()));
+        assert!(!guernsey.same_intermediate_region(&norway));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_same_region_antarctica() {
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert!(!antarctica.same_region(&antarctica));
+        assert!(!antarctica.same_sub_region(&antarctica));
+        assert!(!antarctica.same_intermediate_region(&antarctica));
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(!antarctica.same_region(&norway));
+        assert!(!norway.same_region(&antarctica));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
     fn test_jurisdiction_getters() {
         let norway = Jurisdiction::from(Alpha2::NO);
 
@@ -256,6 +1773,14 @@ mod tests {
         assert_eq!(norway.intermediate_region_code(), None);
     }
 
+    #[test]
+    fn test_jurisdiction_numeric_str() {
+        assert_eq!(Jurisdiction::from(Alpha2::NO).numeric_str(), "578");
+        assert_eq!(Jurisdiction::from(Alpha2::AF).numeric_str(), "004");
+        assert_eq!(Alpha2::AT.numeric_str(), "040");
+        assert_eq!(Alpha3::AUT.numeric_str(), "040");
+    }
+
     #[test]
     fn test_jurisdiction_from_str_unknown() {
         let jur = Jurisdiction::from_str("rofl");
@@ -278,11 +1803,424 @@ mod tests {
         assert_eq!(no, Alpha3::NOR);
     }
 
+    #[test]
+    fn test_jurisdiction_position() {
+        for (position, jurisdiction) in Jurisdiction::all().enumerate() {
+            assert_eq!(jurisdiction.position(), position);
+            assert_eq!(Jurisdiction::from_position(position), jurisdiction);
+        }
+    }
+
+    #[test]
+    fn test_jurisdiction_index_bijective() {
+        let mut seen = [false; DEFINITION_COUNT];
+        for jurisdiction in Jurisdiction::all() {
+            let index = jurisdiction.index();
+            assert!(!seen[index], "{:?}", jurisdiction);
+            seen[index] = true;
+            assert_eq!(Jurisdiction::from_index(index), Some(jurisdiction));
+        }
+        assert!(seen.iter().all(|seen| *seen));
+    }
+
+    #[test]
+    fn test_jurisdiction_index_alphabetical() {
+        let alpha2: Vec<String> = (0..DEFINITION_COUNT)
+            .map(|index| {
+                Jurisdiction::from_index(index)
+                    .unwrap()
+                    .alpha2()
+                    .to_string()
+            })
+            .collect();
+        let mut sorted = alpha2.clone();
+        sorted.sort();
+        assert_eq!(alpha2, sorted);
+        assert_eq!(Jurisdiction::from(Alpha2::ZW).index(), DEFINITION_COUNT - 1);
+    }
+
+    #[test]
+    fn test_jurisdiction_index_out_of_range() {
+        assert_eq!(Jurisdiction::from_index(DEFINITION_COUNT), None);
+        assert_eq!(Jurisdiction::from_index(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_formal_name() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.formal_name(), "Kingdom of Norway");
+
+        let switzerland = Jurisdiction::from(Alpha2::CH);
+        assert_eq!(switzerland.formal_name(), "Swiss Confederation");
+
+        let uk = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(
+            uk.formal_name(),
+            "United Kingdom of Great Britain and Northern Ireland"
+        );
+    }
+
+    #[test]
+    fn test_formal_name_identical_to_name() {
+        let japan = Jurisdiction::from(Alpha2::JP);
+        assert_eq!(japan.formal_name(), "Japan");
+        assert!(std::ptr::eq(japan.formal_name(), japan.name()));
+    }
+
+    #[test]
+    #[cfg(feature = "demonym")]
+    fn test_demonym() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.demonym(), Some("Norwegian"));
+        assert_eq!(norway.demonym_plural(), Some("Norwegians"));
+
+        let netherlands = Jurisdiction::from(Alpha2::NL);
+        assert_eq!(netherlands.demonym(), Some("Dutch"));
+        assert_eq!(netherlands.demonym_plural(), Some("Dutch"));
+
+        let switzerland = Jurisdiction::from(Alpha2::CH);
+        assert_eq!(switzerland.demonym(), Some("Swiss"));
+        assert_eq!(switzerland.demonym_plural(), Some("Swiss"));
+
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert_eq!(germany.demonym(), Some("German"));
+        assert_eq!(germany.demonym_plural(), Some("Germans"));
+    }
+
+    #[test]
+    #[cfg(feature = "demonym")]
+    fn test_demonym_none() {
+        let bouvet = Jurisdiction::from(Alpha2::BV);
+        assert_eq!(bouvet.demonym(), None);
+        assert_eq!(bouvet.demonym_plural(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "statistics")]
+    fn test_statistics() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.area_km2(), Some(385_207));
+        assert!(norway.population().unwrap() > 5_000_000);
+
+        // Microstates are rounded to at least a square kilometre
+        for alpha2 in &[Alpha2::VA, Alpha2::MC, Alpha2::GI] {
+            let microstate = Jurisdiction::from(*alpha2);
+            assert!(microstate.area_km2().unwrap() >= 1, "{:?}", alpha2);
+            assert!(microstate.population().unwrap() > 0, "{:?}", alpha2);
+        }
+        assert_eq!(Jurisdiction::from(Alpha2::VA).area_km2(), Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "statistics")]
+    fn test_statistics_uninhabited() {
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert!(antarctica.area_km2().unwrap() > 10_000_000);
+        assert_eq!(antarctica.population(), None);
+        for alpha2 in &[Alpha2::BV, Alpha2::HM, Alpha2::GS] {
+            let territory = Jurisdiction::from(*alpha2);
+            assert!(territory.area_km2().is_some(), "{:?}", alpha2);
+            assert_eq!(territory.population(), None, "{:?}", alpha2);
+        }
+        assert!(Jurisdiction::all().all(|j| j.area_km2().is_some()));
+    }
+
+    #[test]
+    #[cfg(feature = "statistics")]
+    fn test_statistics_vintage() {
+        let dataset = crate::dataset::get("statistics").unwrap();
+        assert_eq!(dataset.version(), Some("2023"));
+    }
+
+    #[test]
+    #[cfg(feature = "sovereignty")]
+    fn test_sovereign() {
+        for (territory, sovereign) in &[
+            (Alpha2::GL, Alpha2::DK),
+            (Alpha2::PR, Alpha2::US),
+            (Alpha2::HK, Alpha2::CN),
+        ] {
+            let territory = Jurisdiction::from(*territory);
+            assert!(!territory.is_sovereign_state());
+            assert_eq!(territory.sovereign().unwrap(), *sovereign);
+        }
+
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(norway.is_sovereign_state());
+        assert_eq!(norway.sovereign(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "sovereignty")]
+    fn test_sovereign_none() {
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert!(!antarctica.is_sovereign_state());
+        assert_eq!(antarctica.sovereign(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "sovereignty")]
+    fn test_dependencies() {
+        let uk = Jurisdiction::from(Alpha2::GB);
+        for alpha2 in &[Alpha2::GI, Alpha2::BM, Alpha2::KY, Alpha2::FK] {
+            assert!(uk.dependencies().contains(&Jurisdiction::from(*alpha2)));
+        }
+
+        let us = Jurisdiction::from(Alpha2::US);
+        for alpha2 in &[Alpha2::PR, Alpha2::GU, Alpha2::VI, Alpha2::AS] {
+            assert!(us.dependencies().contains(&Jurisdiction::from(*alpha2)));
+        }
+
+        let france = Jurisdiction::from(Alpha2::FR);
+        for alpha2 in &[Alpha2::NC, Alpha2::PF, Alpha2::RE, Alpha2::GF] {
+            assert!(france.dependencies().contains(&Jurisdiction::from(*alpha2)));
+        }
+
+        assert!(Jurisdiction::from(Alpha2::SE).dependencies().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "sovereignty")]
+    fn test_dependencies_consistent_with_sovereign() {
+        for jurisdiction in Jurisdiction::all() {
+            for dependency in jurisdiction.dependencies() {
+                assert_eq!(dependency.sovereign().as_ref(), Some(&jurisdiction));
+            }
+            if let Some(sovereign) = jurisdiction.sovereign() {
+                assert!(sovereign.is_sovereign_state());
+                assert!(sovereign.dependencies().contains(&jurisdiction));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sovereignty")]
+    fn test_all_sovereign() {
+        assert_eq!(Jurisdiction::all().count(), 249);
+        assert!(Jurisdiction::all_sovereign().all(|j| j.is_sovereign_state()));
+        assert!(Jurisdiction::all_sovereign().any(|j| j == Alpha2::NO));
+        assert!(!Jurisdiction::all_sovereign().any(|j| j == Alpha2::GL));
+    }
+
+    #[test]
+    #[cfg(feature = "locale")]
+    fn test_default_locales() {
+        let switzerland = Jurisdiction::from(Alpha2::CH);
+        assert_eq!(
+            switzerland.default_locales(),
+            &["de-CH", "fr-CH", "it-CH", "rm-CH"]
+        );
+        assert_eq!(switzerland.primary_locale(), Some("de-CH"));
+
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.default_locales(), &["nb-NO", "nn-NO"]);
+        assert_eq!(norway.primary_locale(), Some("nb-NO"));
+    }
+
+    #[test]
+    #[cfg(feature = "locale")]
+    fn test_default_locales_inherited_from_sovereign() {
+        let bouvet_island = Jurisdiction::from(Alpha2::BV);
+        assert_eq!(bouvet_island.default_locales(), &["nb-NO", "nn-NO"]);
+
+        let french_southern_territories = Jurisdiction::from(Alpha2::TF);
+        assert_eq!(french_southern_territories.primary_locale(), Some("fr-FR"));
+
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert!(antarctica.default_locales().is_empty());
+        assert_eq!(antarctica.primary_locale(), None);
+    }
+
     #[test]
     fn test_alpha2_display() {
         assert_eq!(Alpha2::NO.to_string(), "NO");
     }
 
+    #[test]
+    fn test_all_sorted_by_name() {
+        let mut sorted: Vec<_> = Jurisdiction::all().collect();
+        sorted.reverse();
+        sorted.sort_by(Jurisdiction::cmp_by_name);
+        assert_eq!(Jurisdiction::all_sorted_by_name(), &sorted[..]);
+        assert_eq!(ALL_BY_NAME.len(), Jurisdiction::all().count());
+        assert_eq!(ALL_BY_NAME.last().map(Jurisdiction::name), Some("Zimbabwe"));
+    }
+
+    #[test]
+    fn test_from_flag() {
+        assert_eq!(Jurisdiction::from_flag("🇳🇴").unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::from_flag("🇬🇧").unwrap(), Alpha2::GB);
+        assert_eq!(Jurisdiction::from_flag("🇬🇧\u{FE0F}").unwrap(), Alpha2::GB);
+
+        assert_eq!(
+            Jurisdiction::from_flag("🇽🇽").unwrap_err().to_string(),
+            "unrecognized ISO 3166 alpha2 code of flag: XX"
+        );
+        for invalid in &[
+            "NO",
+            "no",
+            "",
+            "🇳",
+            "🇳🇴🇳",
+            "🇳🇴 ",
+            " 🇳🇴",
+            "🇳O",
+            "\u{FE0F}",
+            "🏴",
+        ] {
+            assert!(
+                Jurisdiction::from_flag(invalid)
+                    .unwrap_err()
+                    .to_string()
+                    .starts_with("not a flag of two regional indicator symbols"),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    fn capitals() -> BTreeMap<Jurisdiction, String> {
+        vec![
+            (Jurisdiction::from(Alpha2::NO), "Oslo".to_string()),
+            (Jurisdiction::from(Alpha2::SE), "Stockholm".to_string()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_serde_map_key_json() {
+        let json = serde_json::to_string(&capitals()).unwrap();
+        assert_eq!(json, r#"{"NO":"Oslo","SE":"Stockholm"}"#);
+        let parsed: BTreeMap<Jurisdiction, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, capitals());
+        let parsed: BTreeMap<Jurisdiction, String> =
+            serde_json::from_str(r#"{"nor":"Oslo","SWE":"Stockholm"}"#).unwrap();
+        assert_eq!(parsed, capitals());
+
+        let err = serde_json::from_str::<BTreeMap<Jurisdiction, String>>(r#"{"XX":"Nowhere"}"#)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value: string "XX", expected an ISO 3166 alpha2 or alpha3 code at line 1 column 5"#
+        );
+    }
+
+    #[test]
+    fn test_serde_map_key_yaml() {
+        let yaml = serde_yaml::to_string(&capitals()).unwrap();
+        assert_eq!(yaml, "NO: Oslo\nSE: Stockholm\n");
+        let parsed: BTreeMap<Jurisdiction, String> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, capitals());
+        let parsed: BTreeMap<Jurisdiction, String> =
+            serde_yaml::from_str("NOR: Oslo\nSWE: Stockholm\n").unwrap();
+        assert_eq!(parsed, capitals());
+
+        let err =
+            serde_yaml::from_str::<BTreeMap<Jurisdiction, String>>("XX: Nowhere\n").unwrap_err();
+        assert!(err.to_string().starts_with(
+            r#"invalid value: string "XX", expected an ISO 3166 alpha2 or alpha3 code"#
+        ));
+    }
+
+    #[test]
+    fn test_serde_map_key_toml() {
+        let toml = toml::to_string(&capitals()).unwrap();
+        assert_eq!(toml, "NO = \"Oslo\"\nSE = \"Stockholm\"\n");
+        let parsed: BTreeMap<Jurisdiction, String> = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed, capitals());
+        let parsed: BTreeMap<Jurisdiction, String> =
+            toml::from_str("NOR = \"Oslo\"\nse = \"Stockholm\"\n").unwrap();
+        assert_eq!(parsed, capitals());
+
+        let err =
+            toml::from_str::<BTreeMap<Jurisdiction, String>>("XX = \"Nowhere\"\n").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"invalid value: string "XX", expected an ISO 3166 alpha2 or alpha3 code"#));
+    }
+
+    #[test]
+    fn test_serde_value() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(serde_json::to_string(&norway).unwrap(), r#""NO""#);
+        assert_eq!(
+            serde_json::from_str::<Jurisdiction>(r#""NOR""#).unwrap(),
+            norway
+        );
+        assert!(serde_json::from_str::<Jurisdiction>("578").is_err());
+        let (afghanistan, aland) = (
+            Jurisdiction::from(Alpha2::AF),
+            Jurisdiction::from(Alpha2::AX),
+        );
+        assert!(afghanistan < aland);
+    }
+
+    #[test]
+    fn test_try_from_integers() {
+        assert_eq!(Jurisdiction::try_from(578u16).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(578i16).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(578i32).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(578i64).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(578u32).unwrap(), Alpha2::NO);
+        assert_eq!(Jurisdiction::try_from(4i32).unwrap(), Alpha2::AF);
+    }
+
+    #[test]
+    fn test_try_from_integers_out_of_range() {
+        assert_eq!(
+            Jurisdiction::try_from(-578i16).unwrap_err(),
+            CountryCodeError::OutOfRange(-578)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(-1i64).unwrap_err(),
+            CountryCodeError::OutOfRange(-1)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(70_000u32).unwrap_err(),
+            CountryCodeError::OutOfRange(70_000)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(i64::MAX).unwrap_err(),
+            CountryCodeError::OutOfRange(i64::MAX)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(-1i32).unwrap_err().to_string(),
+            "numeric country code out of range: -1"
+        );
+    }
+
+    #[test]
+    fn test_try_from_integers_unassigned() {
+        // Codes above 999 fit a u16, but are never assigned
+        assert_eq!(
+            Jurisdiction::try_from(1000i32).unwrap_err(),
+            CountryCodeError::Unassigned(1000)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(0u16).unwrap_err(),
+            CountryCodeError::Unassigned(0)
+        );
+        assert_eq!(
+            Jurisdiction::try_from(999i64).unwrap_err().to_string(),
+            "unrecognized ISO 3166 numeric country code: 999"
+        );
+    }
+
+    #[test]
+    fn test_into_integers() {
+        for jurisdiction in Jurisdiction::all() {
+            let country_code = jurisdiction.country_code();
+            assert_eq!(u16::from(jurisdiction.clone()), country_code);
+            assert_eq!(i32::from(jurisdiction.clone()), i32::from(country_code));
+            assert_eq!(i64::from(jurisdiction.clone()), i64::from(country_code));
+            assert_eq!(
+                Jurisdiction::try_from(i64::from(jurisdiction.clone())).unwrap(),
+                jurisdiction
+            );
+        }
+    }
+
     #[test]
     fn test_alpha3_display() {
         assert_eq!(Alpha3::NOR.to_string(), "NOR");
@@ -0,0 +1,120 @@
+//! Legal system classification of a jurisdiction.
+//!
+//! Each jurisdiction is classified by the legal tradition its law is founded on, such as
+//! the civil law of continental Europe or the common law of England. Many jurisdictions
+//! combine several traditions, e.g. South Africa applying Roman-Dutch civil law, English
+//! common law and customary law side by side, and are classified as [LegalSystem::Mixed].
+//!
+//! The classification is curated, following the [JuriGlobe] classification of world legal
+//! systems and otherwise the legal system field of the CIA World Factbook. The reference
+//! backing each classification is kept alongside it in `data/legal-system.json`.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::legal::LegalSystem;
+//!
+//! let south_africa = Jurisdiction::from(Alpha2::ZA).legal_system().unwrap();
+//! assert!(south_africa.is_mixed());
+//! assert!(south_africa.includes(LegalSystem::CommonLaw));
+//! ```
+//!
+//! [LegalSystem::Mixed]: enum.LegalSystem.html#variant.Mixed
+//! [JuriGlobe]: http://www.juriglobe.ca
+
+use serde::Serialize;
+
+/// The legal system of a jurisdiction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum LegalSystem {
+    /// Civil law, codified law in the tradition of Roman law.
+    CivilLaw,
+    /// Common law, law developed by the courts in the tradition of English law.
+    CommonLaw,
+    /// Customary law, the unwritten practices of local communities.
+    CustomaryLaw,
+    /// Religious law, such as Muslim law.
+    ReligiousLaw,
+    /// A mixed system, combining the legal systems listed.
+    ///
+    /// The systems are never mixed themselves, and are listed in the order of the variants.
+    Mixed(&'static [LegalSystem]),
+}
+
+impl LegalSystem {
+    /// Return whether this is a mixed system, combining several legal systems.
+    pub fn is_mixed(&self) -> bool {
+        matches!(self, LegalSystem::Mixed(_))
+    }
+
+    /// Return the legal systems combined within a mixed system, or this system alone.
+    pub fn systems(&self) -> &[LegalSystem] {
+        match self {
+            LegalSystem::Mixed(systems) => systems,
+            system => std::slice::from_ref(system),
+        }
+    }
+
+    /// Return whether `system` is applied, either by being this system or being combined
+    /// within this mixed system.
+    pub fn includes(&self, system: LegalSystem) -> bool {
+        self.systems().contains(&system)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_legal_system_pure() {
+        assert_eq!(
+            Jurisdiction::from(Alpha2::GB).legal_system(),
+            Some(LegalSystem::CommonLaw)
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::NO).legal_system(),
+            Some(LegalSystem::CivilLaw)
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::SA).legal_system(),
+            Some(LegalSystem::ReligiousLaw)
+        );
+    }
+
+    #[test]
+    fn test_legal_system_mixed() {
+        let south_africa = Jurisdiction::from(Alpha2::ZA).legal_system().unwrap();
+        assert_eq!(
+            south_africa,
+            LegalSystem::Mixed(&[
+                LegalSystem::CivilLaw,
+                LegalSystem::CommonLaw,
+                LegalSystem::CustomaryLaw
+            ])
+        );
+        assert!(south_africa.is_mixed());
+        assert!(south_africa.includes(LegalSystem::CivilLaw));
+        assert!(!south_africa.includes(LegalSystem::ReligiousLaw));
+    }
+
+    #[test]
+    fn test_legal_system_systems() {
+        let norway = Jurisdiction::from(Alpha2::NO).legal_system().unwrap();
+        assert!(!norway.is_mixed());
+        assert_eq!(norway.systems(), &[LegalSystem::CivilLaw]);
+        for jurisdiction in Jurisdiction::all() {
+            if let Some(LegalSystem::Mixed(systems)) = jurisdiction.legal_system() {
+                assert!(systems.len() > 1, "{:?}", jurisdiction);
+                assert!(systems.windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(systems.iter().all(|system| !system.is_mixed()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_legal_system_unclassified() {
+        assert_eq!(Jurisdiction::from(Alpha2::AQ).legal_system(), None);
+        assert_eq!(Jurisdiction::from(Alpha2::BV).legal_system(), None);
+    }
+}
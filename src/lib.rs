@@ -1,4 +1,4 @@
-#![deny(intra_doc_link_resolution_failure)]
+#![deny(rustdoc::broken_intra_doc_links)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
 #![deny(warnings)]
@@ -11,6 +11,7 @@
 //! * ISO 3166 [Alpha2] and [Alpha3] character codes.
 //! * ISO 3166 numeric country code.
 //! * [UN M49] region classifications.
+//! * ISO 3166-2 subdivision codes.
 //!
 //! The [Jurisdiction] object is a lightweight object, the size of a pointer,
 //! suitable for transfer in API surfaces throughout an ecosystem. Serialization on
@@ -68,45 +69,448 @@
 //! let jurisdiction = Jurisdiction::from(Alpha3::NOR);
 //! assert_eq!(jurisdiction, Alpha3::NOR);
 //! ```
+//!
+//! Construct `Jurisdiction` from a code validated at compile time:
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2, jurisdiction};
+//! const NORWAY: Jurisdiction = jurisdiction!("NO");
+//! assert_eq!(NORWAY, Alpha2::NO);
+//! ```
 
 //!
 //! # Static jurisdiction information
 //!
 //! All the static information about a jurisdiction is embedded into the application binary
-//! through a `lazy_static` hashmap declaration, populated on first use from const definitions.
-//! This way, the only copy of the definition should reside in the hashmap.
+//! as a static definition table, generated at build time. The bulk of the strings of the
+//! definitions are stored in a single concatenated string table, referred to by offset and
+//! length, while the numeric and enum fields are stored in arrays parallel to the table.
+//! Nothing is allocated or initialized at runtime.
 //!
-//! This map is not publicly exported from the crate, only accessible through `Jurisdiction`.
-//! A `Jurisdiction` object simply contains the reference to the definition within this hashmap,
+//! The table is not publicly exported from the crate, only accessible through `Jurisdiction`.
+//! A `Jurisdiction` object simply contains the reference to its definition within this table,
 //! making all look-up operations a simple pointer dereference into the statically
-//! stored item in this global hashmap.
+//! stored item, or an index into a parallel array by the position of the item. Converting from an alpha code indexes the table directly, as the alpha code
+//! variants are declared in the order of the definitions, while numeric country codes are
+//! binary searched in a generated table sorted by country code.
 //!
 //!
 //! # Features
 //! This crate has the following features:
 //!
 //! * `region`: Include the [region] module with region definitions and `Jurisdiction` array
-//! methods returning the zoning jurisdictions within these regions (`in_*_region`).
+//!   methods returning the zoning jurisdictions within these regions (`in_*_region`),
+//!   along with the `group_by_*region` functions grouping jurisdictions by region, and
+//!   `count_by_region` counting them.
+//! * `arbitrary`: Implement the [arbitrary] `Arbitrary` trait for `Jurisdiction`, `Alpha2`,
+//!   `Alpha3` and the region classifications, choosing uniformly from their valid values.
+//!   Not enabled by `full`.
+//! * `arrow`: Include the [arrow] module with conversions of jurisdiction columns into and
+//!   from [Apache Arrow] arrays of alpha2 or numeric codes. Not enabled by `full`.
+//! * `async-graphql`: Implement the [async-graphql] scalars `Jurisdiction`, `Alpha2` and
+//!   `Alpha3`, represented by their alpha code, along with enums for the region
+//!   classifications. Not enabled by `full`.
+//! * `aviation`: Include the [aviation] module with the aircraft registration prefixes of
+//!   each jurisdiction, along with the jurisdiction lookup of a registration.
+//! * `banking`: Include the [banking] module with the IBAN length and SEPA membership
+//!   of each jurisdiction, along with IBAN validation.
+//! * `clap`: Include the [clap] module with the value parsers of `Jurisdiction`, `Alpha2`
+//!   and `Alpha3` arguments, listing the codes as possible values. Not enabled by `full`.
+//! * `cldr-containment`: Include the [cldr] module with the CLDR territory containment,
+//!   listing the jurisdictions of each territory, along with the containing territories of
+//!   each jurisdiction.
+//! * `cli`: Build the `jurisdiction` binary, looking up jurisdictions by code or name and
+//!   listing them by region in plain, JSON or CSV format. Implies `clap` and `region`.
+//!   Not enabled by `full`.
+//! * `collation`: Include the [collation] module, ordering jurisdictions by the collation
+//!   of their names in a locale through [icu_collator], along with
+//!   `Jurisdiction::sort_by_display_name`. Not enabled by `full`.
+//! * `compat-celes`: Implement `TryFrom` conversions between `Jurisdiction` or `Alpha2` and
+//!   the `Country` of [celes], matched on the numeric country code. Not enabled by `full`.
+//! * `compat-isocountry`: Implement `TryFrom` conversions between `Jurisdiction` or `Alpha2`
+//!   and the `CountryCode` of [isocountry], matched on the numeric country code. Not
+//!   enabled by `full`.
+//! * `compat-rust-iso3166`: Implement `TryFrom` conversions between `Jurisdiction` or
+//!   `Alpha2` and the `CountryCode` of [rust_iso3166], matched on the numeric country code.
+//!   Not enabled by `full`.
+//! * `continent`: Include the [continent] module with the seven-continent classification
+//!   of each jurisdiction, through `Jurisdiction::continent`.
+//! * `conventions`: Include the [conventions] module with local conventions of each
+//!   jurisdiction, such as the first day of the week, the weekend days, the measurement
+//!   system, the default paper size and the emergency telephone numbers.
+//! * `demonym`: Include the english demonym of each jurisdiction, through
+//!   `Jurisdiction::demonym` and `Jurisdiction::demonym_plural`.
+//! * `defmt`: Implement the [defmt] `Format` trait for `Jurisdiction`, `Alpha2`, `Alpha3` and
+//!   the region classifications, logging alpha codes and regions as interned strings. Not
+//!   enabled by `full`.
+//! * `diesel`: Implement the [Diesel] `ToSql` and `FromSql` traits of the Postgres and SQLite
+//!   backends for `Jurisdiction`, `Alpha2` and `Alpha3`, stored as text alpha codes, along
+//!   with `AsExpression` and `FromSqlRow`. Not enabled by `full`.
+//! * `economics`: Include the [economics] module with the World Bank income group of
+//!   each jurisdiction, through `Jurisdiction::income_group`.
+//! * `eurostat`: Include the [eurostat] module with the Eurostat country code of each
+//!   jurisdiction, such as `EL` for Greece, through `Jurisdiction::eurostat_code` and
+//!   `Jurisdiction::from_eurostat`.
+//! * `ffi`: Include the [ffi] module with the C foreign function interface, declared by
+//!   `include/jurisdiction.h`. Not enabled by `full`.
+//! * `gs1`: Include the [gs1] module with the GS1 barcode prefixes allocated to each
+//!   jurisdiction, along with the issuer lookup of a barcode.
+//! * `http`: Include the [http] module with the jurisdictions preferred by the
+//!   `Accept-Language` header of HTTP requests.
+//! * `icu`: Implement conversions between `Jurisdiction` or `Alpha2` and the region subtags
+//!   of [icu_locid], along with `Jurisdiction::to_locale_region`. Not enabled by `full`.
+//! * `ip`: Include the [ip] module with the WIPO ST.3 code of each jurisdiction, along with
+//!   the resolution of ST.3 codes to jurisdictions or intellectual property organizations.
+//! * `iso-currency`: Implement `TryFrom` conversions between `Jurisdiction` or `Alpha2` and
+//!   the `Country` of [iso_currency], along with `Jurisdiction::currencies_iso`. Not
+//!   enabled by `full`.
+//! * `juniper`: Implement the [juniper] scalars `Jurisdiction`, `Alpha2` and `Alpha3`,
+//!   represented by their alpha code, along with enums for the region classifications.
+//!   Not enabled by `full`.
+//! * `legal`: Include the [legal] module with the legal system of each jurisdiction, such
+//!   as civil law or common law, through `Jurisdiction::legal_system`.
+//! * `locale`: Include the BCP-47 tags of the locales likely in use within each
+//!   jurisdiction, through `Jurisdiction::default_locales`.
+//! * `localized-names`: Include the [localized] module with jurisdiction names in other
+//!   languages, through `Jurisdiction::name_in`. Only the languages selected through their
+//!   `names-*` feature, e.g. `names-de`, are embedded. Not enabled by `full`.
+//! * `minicbor`: Implement the [minicbor] `Encode` and `Decode` traits for `Jurisdiction`,
+//!   encoded as its numeric country code, and for `Alpha2` and `Alpha3`, encoded as text
+//!   strings of their code. Not enabled by `full`.
+//! * `mrz`: Include the [mrz] module resolving the nationality codes of machine-readable
+//!   travel documents, including the special codes of ICAO Doc 9303.
+//! * `nohash`: Implement the [nohash-hasher] `IsEnabled` trait for `Jurisdiction`, keying
+//!   its `IntMap` and `IntSet` by the numeric country code. Not enabled by `full`.
+//! * `nuts`: Include the [nuts] module resolving NUTS statistical region codes, such as
+//!   `NO011`, to their jurisdiction, along with `Jurisdiction::has_nuts`. Implies `eurostat`.
+//! * `phonenumber`: Implement `TryFrom` conversions between `Jurisdiction` or `Alpha2` and
+//!   the country `Id` of [phonenumber], along with `Jurisdiction::of_phone_number`. Not
+//!   enabled by `full`.
+//! * `polars`: Include the [polars] module with conversions of jurisdiction series into and
+//!   from [Polars] series of alpha2 codes. Implies `arrow`. Not enabled by `full`.
+//! * `political`: Include the [sets] module with predefined [JurisdictionSet]s of common
+//!   groupings, such as the European Union, along with membership predicates such as
+//!   `Jurisdiction::is_eu_member`, the [un] module with the UN regional group of each
+//!   member state, and the [gdpr] module with the EU adequacy status of each jurisdiction.
+//! * `postal`: Include the [postal] module with the postal code format of each jurisdiction,
+//!   used to validate postal codes through `Jurisdiction::validate_postal_code`.
+//! * `postgres`: Implement the [postgres-types] `ToSql` and `FromSql` traits used by
+//!   tokio-postgres for `Jurisdiction`, `Alpha2` and `Alpha3`, stored as alpha codes or
+//!   numeric country codes. Not enabled by `full`.
+//! * `power`: Include the [power] module with the electrical plug types, mains voltages and
+//!   mains frequencies in use within each jurisdiction.
+//! * `proptest`: Include the [proptest] module with strategies of jurisdictions, alpha2
+//!   codes, and valid and invalid code strings. Not enabled by `full`.
+//! * `python`: Implement the [PyO3] bindings of the `jurisdiction` Python module, built by
+//!   maturin through `pyproject.toml`. Not enabled by `full`.
+//! * `quickcheck`: Implement the [quickcheck] `Arbitrary` trait for `Jurisdiction`,
+//!   `Alpha2` and `Alpha3`, shrinking toward lower numeric country codes. Not enabled by
+//!   `full`.
+//! * `radio`: Include the [radio] module with the ITU call sign series allocated to each
+//!   jurisdiction, along with the jurisdiction lookup of an amateur radio call sign.
+//! * `rail`: Include the [rail] module with the UIC railway country code of each
+//!   jurisdiction, along with the jurisdiction lookup of a code.
+//! * `rand`: Implement the [rand] `Distribution` of `Jurisdiction` for `Standard`, uniform
+//!   over all jurisdictions, along with `Jurisdiction::random` and
+//!   `Jurisdiction::random_in_region`. Not enabled by `full`.
+//! * `redis`: Implement the [redis-rs] `ToRedisArgs` and `FromRedisValue` traits for
+//!   `Jurisdiction`, `Alpha2` and `Alpha3`, written as alpha codes and read from alpha or
+//!   numeric codes. Not enabled by `full`.
+//! * `reserved`: Include the [reserved] module with the exceptionally reserved alpha-2 codes,
+//!   such as `UK` and `EU`, and their resolution to a jurisdiction where sensible.
+//! * `rkyv`: Include the [rkyv] module, implementing the zero-copy `Archive`, `Serialize`
+//!   and `Deserialize` traits of rkyv for `Jurisdiction`, `Alpha2` and `Alpha3`, archived as
+//!   numeric country codes and alpha codes validated on access. Not enabled by `full`.
+//! * `rocket`: Implement the [Rocket] `FromParam`, `FromFormField` and `UriDisplay` traits for
+//!   `Jurisdiction`, `Alpha2` and `Alpha3`. Not enabled by `full`.
+//! * `rusqlite`: Implement the [rusqlite] `ToSql` and `FromSql` traits for `Jurisdiction`,
+//!   `Alpha2` and `Alpha3`, stored as alpha codes or numeric country codes. Not enabled
+//!   by `full`.
+//! * `sea-orm`: Include the [sea_orm] module, implementing the [SeaORM] column traits for
+//!   `Jurisdiction` and `Alpha2` stored as alpha2 codes, along with a wrapper storing a
+//!   jurisdiction as its numeric country code. Not enabled by `full`.
+//! * `sovereignty`: Include whether each jurisdiction is an independent state, along with
+//!   the sovereign state of dependent territories, through `Jurisdiction::sovereign` and
+//!   `Jurisdiction::dependencies`, and the [territory] module with the kind of territory of
+//!   each jurisdiction.
+//! * `sqlx-mysql`: Implement the [sqlx] MySQL `Type`, `Encode` and `Decode` traits for
+//!   `Jurisdiction`, `Alpha2` and `Alpha3`, stored as alpha codes or numeric country codes.
+//!   Not enabled by `full`.
+//! * `sqlx-postgres`: Implement the [sqlx] Postgres `Type`, `Encode` and `Decode` traits for
+//!   `Jurisdiction`, `Alpha2` and `Alpha3`, stored as alpha codes or numeric country codes.
+//!   Not enabled by `full`.
+//! * `sqlx-sqlite`: Implement the [sqlx] SQLite `Type`, `Encode` and `Decode` traits for
+//!   `Jurisdiction`, `Alpha2` and `Alpha3`, stored as alpha codes or numeric country codes.
+//!   Not enabled by `full`.
+//! * `statistics`: Include a snapshot of the area and population of each jurisdiction,
+//!   through `Jurisdiction::area_km2` and `Jurisdiction::population`.
+//! * `strum`: Derive the [strum] `EnumIter`, `EnumCount`, `IntoStaticStr` and `EnumString`
+//!   traits for `Alpha2`, `Alpha3` and the region classifications. The strings agree with
+//!   their serialized form, and are parsed ignoring case. Not enabled by `full`.
+//! * `subdivision`: Include the [subdivision] module with ISO 3166-2 subdivision definitions,
+//!   parsable from and serializable as their subdivision code.
+//! * `telephony`: Include the [telephony] module with the E.212 mobile country codes of
+//!   each jurisdiction, along with the jurisdiction lookup of a code or IMSI.
+//! * `ts-rs`: Implement the [ts-rs] `TS` trait for `Jurisdiction`, `Alpha2`, `Alpha3` and the
+//!   region classifications, exporting TypeScript declarations of their serialized form.
+//!   Not enabled by `full`.
+//! * `uniffi`: Implement the [UniFFI] bindings exposing jurisdictions to Kotlin and Swift,
+//!   generated by the `uniffi-bindgen` binary of the `uniffi-bindgen` feature. Implies
+//!   `region`. Not enabled by `full`.
+//! * `utoipa`: Implement the [utoipa] `ToSchema` trait for `Jurisdiction`, `Alpha2`,
+//!   `Alpha3` and the region classifications, describing their serialized form in OpenAPI
+//!   documents. Not enabled by `full`.
+//! * `vat`: Include the [vat] module with the prefix and format of the VAT identification
+//!   numbers issued within the European Union.
+//! * `wasm`: Include the [wasm] module with the [wasm-bindgen] bindings exposing
+//!   `Jurisdiction` to JavaScript. Not enabled by `full`.
 //!
 //!
 //! [UN M49]: https://unstats.un.org/unsd/methodology/m49/overview
+//! [Apache Arrow]: https://arrow.apache.org
+//! [arbitrary]: https://docs.rs/arbitrary
+//! [arrow]: mod.arrow.html
+//! [async-graphql]: https://docs.rs/async-graphql
+//! [aviation]: mod.aviation.html
+//! [banking]: mod.banking.html
+//! [celes]: https://docs.rs/celes
+//! [clap]: mod.clap.html
+//! [cldr]: mod.cldr.html
+//! [collation]: mod.collation.html
+//! [continent]: mod.continent.html
+//! [conventions]: mod.conventions.html
+//! [defmt]: https://defmt.ferrous-systems.com
+//! [Diesel]: https://diesel.rs
+//! [economics]: mod.economics.html
+//! [eurostat]: mod.eurostat.html
+//! [ffi]: mod.ffi.html
+//! [gdpr]: mod.gdpr.html
+//! [gs1]: mod.gs1.html
+//! [http]: mod.http.html
+//! [icu_collator]: https://docs.rs/icu_collator
+//! [icu_locid]: https://docs.rs/icu_locid
+//! [ip]: mod.ip.html
+//! [iso_currency]: https://docs.rs/iso_currency
+//! [isocountry]: https://docs.rs/isocountry
+//! [juniper]: https://docs.rs/juniper
+//! [legal]: mod.legal.html
+//! [localized]: mod.localized.html
+//! [minicbor]: https://docs.rs/minicbor
+//! [mrz]: mod.mrz.html
+//! [nohash-hasher]: https://docs.rs/nohash-hasher
+//! [nuts]: mod.nuts.html
+//! [phonenumber]: https://docs.rs/phonenumber
+//! [polars]: mod.polars.html
+//! [Polars]: https://pola.rs
+//! [postal]: mod.postal.html
+//! [postgres-types]: https://docs.rs/postgres-types
+//! [power]: mod.power.html
+//! [proptest]: mod.proptest.html
+//! [PyO3]: https://pyo3.rs
+//! [quickcheck]: https://docs.rs/quickcheck
+//! [radio]: mod.radio.html
+//! [rail]: mod.rail.html
+//! [rand]: https://docs.rs/rand
+//! [redis-rs]: https://docs.rs/redis
 //! [region]: mod.region.html
+//! [reserved]: mod.reserved.html
+//! [rkyv]: mod.rkyv.html
+//! [Rocket]: https://rocket.rs
+//! [rusqlite]: https://docs.rs/rusqlite
+//! [rust_iso3166]: https://docs.rs/rust_iso3166
+//! [sea_orm]: mod.sea_orm.html
+//! [SeaORM]: https://www.sea-ql.org/SeaORM/
+//! [sets]: mod.sets.html
+//! [sqlx]: https://docs.rs/sqlx
+//! [strum]: https://docs.rs/strum
+//! [subdivision]: mod.subdivision.html
+//! [telephony]: mod.telephony.html
+//! [territory]: mod.territory.html
+//! [ts-rs]: https://docs.rs/ts-rs
+//! [un]: mod.un.html
+//! [UniFFI]: https://mozilla.github.io/uniffi-rs/
+//! [utoipa]: https://docs.rs/utoipa
+//! [vat]: mod.vat.html
+//! [wasm]: mod.wasm.html
+//! [wasm-bindgen]: https://docs.rs/wasm-bindgen
 //! [Jurisdiction]: struct.Jurisdiction.html
+//! [JurisdictionSet]: struct.JurisdictionSet.html
 //! [Alpha2]: enum.Alpha2.html
 //! [Alpha3]: enum.Alpha3.html
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary_types;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "aviation")]
+pub mod aviation;
+#[cfg(feature = "banking")]
+pub mod banking;
+#[cfg(feature = "compat-celes")]
+mod celes_types;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "cldr-containment")]
+pub mod cldr;
+#[cfg(feature = "collation")]
+pub mod collation;
+#[cfg(feature = "continent")]
+pub mod continent;
+#[cfg(feature = "conventions")]
+pub mod conventions;
+pub mod dataset;
 mod definition;
+#[cfg(feature = "defmt")]
+mod defmt_types;
+#[cfg(feature = "diesel")]
+mod diesel_types;
+#[cfg(feature = "economics")]
+pub mod economics;
+#[cfg(feature = "eurostat")]
+pub mod eurostat;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fuzzy;
+#[cfg(feature = "political")]
+pub mod gdpr;
 mod generated;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "region")]
+mod group;
+#[cfg(feature = "gs1")]
+pub mod gs1;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "icu")]
+mod icu;
+pub mod info;
+#[cfg(feature = "ip")]
+pub mod ip;
+#[cfg(feature = "iso-currency")]
+mod iso_currency_types;
+#[cfg(feature = "compat-isocountry")]
+mod isocountry_types;
+#[cfg(feature = "juniper")]
+mod juniper_types;
 mod jurisdiction;
+#[cfg(feature = "legal")]
+pub mod legal;
+pub mod list;
+#[cfg(feature = "localized-names")]
+pub mod localized;
+mod macros;
+pub mod map;
+#[cfg(feature = "minicbor")]
+mod minicbor_types;
+#[cfg(feature = "mrz")]
+pub mod mrz;
+#[cfg(feature = "nohash")]
+mod nohash_types;
+#[cfg(feature = "nuts")]
+pub mod nuts;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(any(feature = "postal", feature = "vat"))]
+mod pattern;
+#[cfg(feature = "phonenumber")]
+mod phonenumber_types;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "postal")]
+pub mod postal;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "power")]
+pub mod power;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "python")]
+mod python;
+pub mod query;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_types;
+#[cfg(feature = "radio")]
+pub mod radio;
+#[cfg(feature = "rail")]
+pub mod rail;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "redis")]
+mod redis_types;
 #[cfg(feature = "region")]
 pub mod region;
+#[cfg(feature = "reserved")]
+pub mod reserved;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "rocket")]
+mod rocket_types;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_types;
+#[cfg(feature = "compat-rust-iso3166")]
+mod rust_iso3166_types;
+#[cfg(feature = "sea-orm")]
+pub mod sea_orm;
+mod search;
+pub mod serde;
+pub mod set;
+#[cfg(feature = "political")]
+pub mod sets;
+#[cfg(any(
+    feature = "diesel",
+    feature = "postgres",
+    feature = "redis",
+    feature = "rusqlite",
+    feature = "sea-orm",
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+mod sql;
+#[cfg(feature = "sqlx-mysql")]
+mod sqlx_mysql;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx_postgres;
+#[cfg(feature = "sqlx-sqlite")]
+mod sqlx_sqlite;
+pub mod strict;
+#[cfg(feature = "subdivision")]
+pub mod subdivision;
+#[cfg(feature = "telephony")]
+pub mod telephony;
+#[cfg(feature = "sovereignty")]
+pub mod territory;
+#[cfg(feature = "ts-rs")]
+mod ts_rs_types;
+#[cfg(feature = "political")]
+pub mod un;
+#[cfg(feature = "uniffi")]
+mod uniffi_bindings;
+mod validate;
+#[cfg(feature = "vat")]
+pub mod vat;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Re-export generated modules
 use crate::generated::alpha;
 
 // Publicly export types
 pub use crate::alpha::{Alpha2, Alpha3};
-pub use crate::jurisdiction::Jurisdiction;
+#[cfg(feature = "region")]
+pub use crate::group::{
+    count_by_region, group_by_intermediate_region, group_by_region, group_by_sub_region,
+};
+pub use crate::info::JurisdictionInfo;
+pub use crate::jurisdiction::{CountryCodeError, Jurisdiction, ALL_BY_NAME};
+pub use crate::list::ParseListError;
+pub use crate::map::JurisdictionMap;
+pub use crate::query::JurisdictionQuery;
+pub use crate::set::JurisdictionSet;
+pub use crate::validate::{is_valid_alpha2, is_valid_alpha3, is_valid_code};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
 
 // Assert properties about crate types
 use static_assertions as sa;
@@ -125,10 +529,27 @@ sa::assert_eq_size!(crate::region::SubRegion, u8);
 #[cfg(feature = "region")]
 sa::assert_eq_size!(crate::region::IntermediateRegion, u8);
 
+#[cfg(feature = "continent")]
+sa::assert_impl_all!(crate::continent::Continent: Sized, Send, Sync);
+#[cfg(feature = "continent")]
+sa::assert_eq_size!(crate::continent::Continent, u8);
+
+#[cfg(feature = "subdivision")]
+sa::assert_impl_all!(crate::subdivision::Subdivision: Sized, Send, Sync);
+#[cfg(feature = "subdivision")]
+sa::assert_eq_size!(crate::subdivision::Subdivision, usize);
+
 sa::assert_impl_all!(crate::definition::Definition: Sized, Send, Sync);
 
-// Assert that the Jurisdiction object is the same size as a simple pointer.
+sa::assert_impl_all!(Jurisdiction: Eq, std::hash::Hash, Send, Sync);
+sa::assert_impl_all!(JurisdictionSet: Copy, Send, Sync);
+sa::assert_impl_all!(JurisdictionMap<u8>: Send, Sync);
+
+// Assert that the Jurisdiction object is the same size as a simple pointer, also when
+// optional, as documented in its layout guarantees.
 sa::assert_eq_size!(Jurisdiction, usize);
+sa::assert_eq_size!(Option<Jurisdiction>, usize);
+sa::assert_eq_align!(Jurisdiction, usize);
 
 sa::assert_eq_size!(Alpha2, u8);
 sa::assert_eq_size!(Alpha3, u8);
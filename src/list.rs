@@ -0,0 +1,175 @@
+//! Parsing of delimited lists of jurisdictions.
+//!
+//! Lists such as `NO,SE, DK ;FI` are found in environment variables and configuration
+//! strings. Their entries are separated by commas, semicolons or whitespace, in any
+//! combination, and are parsed as alpha2 or alpha3 codes ignoring case. Empty entries are
+//! skipped, such that trailing and repeated delimiters are tolerated.
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let allowed = Jurisdiction::parse_list("NO,SE, DK ;FI").unwrap();
+//! assert_eq!(allowed, [Alpha2::NO, Alpha2::SE, Alpha2::DK, Alpha2::FI]);
+//!
+//! let err = Jurisdiction::parse_list("NO,XX,SE,YY").unwrap_err();
+//! assert_eq!(err.to_string(), r#"unrecognized jurisdiction codes: "XX" at 3, "YY" at 9"#);
+//! ```
+
+use crate::{Jurisdiction, JurisdictionSet};
+
+use std::fmt;
+
+/// An entry of a list that is not the alpha code of a jurisdiction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidEntry {
+    /// The byte offset of the entry within the list.
+    pub position: usize,
+    /// The entry, without its surrounding delimiters.
+    pub entry: String,
+}
+
+/// An error which can be returned when parsing a list of jurisdictions, reporting every
+/// invalid entry of the list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseListError {
+    invalid: Vec<InvalidEntry>,
+}
+
+impl ParseListError {
+    /// Return the invalid entries of the list, in the order of the list.
+    pub fn invalid_entries(&self) -> &[InvalidEntry] {
+        &self.invalid
+    }
+}
+
+impl fmt::Display for ParseListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.invalid[..] {
+            [invalid] => write!(
+                f,
+                "unrecognized jurisdiction code: {:?} at {}",
+                invalid.entry, invalid.position
+            ),
+            invalid => {
+                f.write_str("unrecognized jurisdiction codes: ")?;
+                for (i, invalid) in invalid.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{:?} at {}", invalid.entry, invalid.position)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseListError {}
+
+fn is_delimiter(c: char) -> bool {
+    c == ',' || c == ';' || c.is_whitespace()
+}
+
+/// Parse the entries of `list`, keeping duplicates unless `unique`.
+pub(crate) fn parse(list: &str, unique: bool) -> Result<Vec<Jurisdiction>, ParseListError> {
+    let mut jurisdictions = Vec::new();
+    let mut seen = JurisdictionSet::new();
+    let mut invalid = Vec::new();
+    for entry in list.split(is_delimiter).filter(|entry| !entry.is_empty()) {
+        match entry.parse::<Jurisdiction>() {
+            Ok(jurisdiction) => {
+                if !unique || seen.insert(jurisdiction.clone()) {
+                    jurisdictions.push(jurisdiction);
+                }
+            }
+            Err(_) => invalid.push(InvalidEntry {
+                // The entries are slices of the list
+                position: entry.as_ptr() as usize - list.as_ptr() as usize,
+                entry: entry.to_string(),
+            }),
+        }
+    }
+    if invalid.is_empty() {
+        Ok(jurisdictions)
+    } else {
+        Err(ParseListError { invalid })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    #[test]
+    fn test_parse_list_delimiters() {
+        let nordics = [Alpha2::NO, Alpha2::SE, Alpha2::DK, Alpha2::FI];
+        for list in &[
+            "NO,SE,DK,FI",
+            "NO,SE, DK ;FI",
+            " no;swe\tDK\n fin ,",
+            ",,NO;;SE  DK,;FI;",
+        ] {
+            assert_eq!(
+                Jurisdiction::parse_list(list).unwrap(),
+                nordics,
+                "{:?}",
+                list
+            );
+        }
+        assert!(Jurisdiction::parse_list("").unwrap().is_empty());
+        assert!(Jurisdiction::parse_list(" ,; ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_list_duplicates() {
+        let list = "NO,SE,NOR,no,DK,SE";
+        assert_eq!(
+            Jurisdiction::parse_list(list).unwrap(),
+            [
+                Alpha2::NO,
+                Alpha2::SE,
+                Alpha2::NO,
+                Alpha2::NO,
+                Alpha2::DK,
+                Alpha2::SE
+            ]
+        );
+        assert_eq!(
+            Jurisdiction::parse_list_unique(list).unwrap(),
+            [Alpha2::NO, Alpha2::SE, Alpha2::DK]
+        );
+    }
+
+    #[test]
+    fn test_parse_list_errors() {
+        let err = Jurisdiction::parse_list("NO, XX;SE  Norway,YY").unwrap_err();
+        assert_eq!(
+            err.invalid_entries(),
+            [
+                InvalidEntry {
+                    position: 4,
+                    entry: "XX".to_string()
+                },
+                InvalidEntry {
+                    position: 11,
+                    entry: "Norway".to_string()
+                },
+                InvalidEntry {
+                    position: 18,
+                    entry: "YY".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            r#"unrecognized jurisdiction codes: "XX" at 4, "Norway" at 11, "YY" at 18"#
+        );
+
+        let err = Jurisdiction::parse_list_unique("NO,NO,N0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"unrecognized jurisdiction code: "N0" at 6"#
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Localized jurisdiction names.
+//!
+//! The names are sourced from the translations of ISO 3166-1 by the [Debian iso-codes]
+//! project. Only the languages selected through their cargo feature are embedded into
+//! the application binary, e.g. `names-de` for German:
+//!
+//! ```toml
+//! [dependencies]
+//! jurisdiction = { version = "0.1", features = ["names-de", "names-fr"] }
+//! ```
+//!
+//! [Debian iso-codes]: https://salsa.debian.org/iso-codes-team/iso-codes
+
+use serde::{Deserialize, Serialize};
+
+/// A language the jurisdiction names are available in.
+///
+/// All languages are listed regardless of which are embedded. Whether the names of a
+/// language are embedded is given by [is_embedded](#method.is_embedded).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum Language {
+    Arabic,
+    Danish,
+    German,
+    Spanish,
+    Finnish,
+    French,
+    Italian,
+    Japanese,
+    NorwegianBokmal,
+    Dutch,
+    Polish,
+    Portuguese,
+    Russian,
+    Swedish,
+    Chinese,
+}
+
+impl Language {
+    /// Return the ISO 639-1 code of the language, e.g. `de` for German.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::Arabic => "ar",
+            Language::Danish => "da",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Finnish => "fi",
+            Language::French => "fr",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::NorwegianBokmal => "nb",
+            Language::Dutch => "nl",
+            Language::Polish => "pl",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
+            Language::Swedish => "sv",
+            Language::Chinese => "zh",
+        }
+    }
+
+    /// Return whether the names in this language are embedded, selected through
+    /// the `names-*` feature of the language.
+    pub fn is_embedded(&self) -> bool {
+        match self {
+            Language::Arabic => cfg!(feature = "names-ar"),
+            Language::Danish => cfg!(feature = "names-da"),
+            Language::German => cfg!(feature = "names-de"),
+            Language::Spanish => cfg!(feature = "names-es"),
+            Language::Finnish => cfg!(feature = "names-fi"),
+            Language::French => cfg!(feature = "names-fr"),
+            Language::Italian => cfg!(feature = "names-it"),
+            Language::Japanese => cfg!(feature = "names-ja"),
+            Language::NorwegianBokmal => cfg!(feature = "names-nb"),
+            Language::Dutch => cfg!(feature = "names-nl"),
+            Language::Polish => cfg!(feature = "names-pl"),
+            Language::Portuguese => cfg!(feature = "names-pt"),
+            Language::Russian => cfg!(feature = "names-ru"),
+            Language::Swedish => cfg!(feature = "names-sv"),
+            Language::Chinese => cfg!(feature = "names-zh"),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "names-de", feature = "names-fr", not(feature = "names-ja")))]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    #[cfg(feature = "names-de")]
+    fn test_name_in_german() {
+        let us = Jurisdiction::from(Alpha2::US);
+        assert_eq!(us.name_in(Language::German), Some("Vereinigte Staaten"));
+    }
+
+    #[test]
+    #[cfg(feature = "names-fr")]
+    fn test_name_in_french() {
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert_eq!(germany.name_in(Language::French), Some("Allemagne"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "names-ja"))]
+    fn test_name_in_unselected_language() {
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert!(!Language::Japanese.is_embedded());
+        assert_eq!(germany.name_in(Language::Japanese), Some("Germany"));
+    }
+
+    #[test]
+    #[cfg(feature = "names-fr")]
+    fn test_name_in_missing_translation() {
+        // The French translations hold no name for Turkey, falling back to the English name.
+        let turkey = Jurisdiction::from(Alpha2::TR);
+        assert_eq!(turkey.name_in(Language::French), Some("Turkey"));
+    }
+}
@@ -0,0 +1,87 @@
+//! Macros of jurisdictions validated at compile time.
+
+/// Construct a [Jurisdiction] from an alpha2 or alpha3 string literal, validated at compile
+/// time.
+///
+/// The macro expands to a constant expression, usable in `const` items, and fails to
+/// compile when the literal is not the code of a jurisdiction.
+///
+/// ```rust
+/// use jurisdiction::{jurisdiction, Alpha2, Jurisdiction};
+///
+/// const NORWAY: Jurisdiction = jurisdiction!("NO");
+/// assert_eq!(NORWAY, Alpha2::NO);
+/// assert_eq!(jurisdiction!("SWE"), Alpha2::SE);
+/// ```
+///
+/// ```compile_fail
+/// let unknown = jurisdiction::jurisdiction!("XX");
+/// ```
+///
+/// [Jurisdiction]: struct.Jurisdiction.html
+#[macro_export]
+macro_rules! jurisdiction {
+    ($code:literal) => {{
+        const JURISDICTION: $crate::Jurisdiction = match $crate::Jurisdiction::__from_code($code) {
+            Some(jurisdiction) => jurisdiction,
+            None => panic!(concat!("unrecognized ISO 3166 alpha country code: ", $code)),
+        };
+        JURISDICTION
+    }};
+    ($($code:tt)*) => {
+        compile_error!("expected an ISO 3166 alpha2 or alpha3 code as a string literal")
+    };
+}
+
+/// Construct an array of [Jurisdiction]s from alpha2 or alpha3 string literals, each
+/// validated at compile time as by [jurisdiction!].
+///
+/// ```rust
+/// use jurisdiction::{jurisdictions, Jurisdiction};
+///
+/// const SCANDINAVIA: [Jurisdiction; 3] = jurisdictions!["NO", "SE", "DK"];
+/// assert_eq!(SCANDINAVIA[2].name(), "Denmark");
+/// ```
+///
+/// [Jurisdiction]: struct.Jurisdiction.html
+/// [jurisdiction!]: macro.jurisdiction.html
+#[macro_export]
+macro_rules! jurisdictions {
+    ($($code:literal),* $(,)?) => {
+        [$($crate::jurisdiction!($code)),*]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alpha::Alpha2;
+    use crate::Jurisdiction;
+
+    const NORWAY: Jurisdiction = jurisdiction!("NO");
+    const NORDICS: [Jurisdiction; 5] = jurisdictions!["NO", "SE", "DK", "FIN", "ISL",];
+
+    #[test]
+    fn test_macro_jurisdiction() {
+        assert_eq!(NORWAY, Alpha2::NO);
+        assert_eq!(jurisdiction!("NOR"), Alpha2::NO);
+        for jurisdiction in Jurisdiction::all() {
+            let alpha2 = jurisdiction.alpha2().to_string();
+            let alpha3 = jurisdiction.alpha3().to_string();
+            assert_eq!(
+                Jurisdiction::__from_code(&alpha2),
+                Some(jurisdiction.clone())
+            );
+            assert_eq!(Jurisdiction::__from_code(&alpha3), Some(jurisdiction));
+        }
+        assert_eq!(Jurisdiction::__from_code("no"), None);
+        assert_eq!(Jurisdiction::__from_code("XX"), None);
+    }
+
+    #[test]
+    fn test_macro_jurisdictions() {
+        let names: Vec<_> = NORDICS.iter().map(|j| j.name()).collect();
+        assert_eq!(names, ["Norway", "Sweden", "Denmark", "Finland", "Iceland"]);
+        let empty: [Jurisdiction; 0] = jurisdictions![];
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+//! A dense map keyed by jurisdiction.
+
+use crate::alpha::Alpha2;
+use crate::generated::definition::DEFINITION_COUNT;
+use crate::Jurisdiction;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::fmt;
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+
+/// A map keyed by [Jurisdiction], backed by an array with a slot per jurisdiction.
+///
+/// Each jurisdiction is located by its position in the order of `Jurisdiction::all`,
+/// without hashing. The map occupies a slot for every jurisdiction regardless of how many
+/// are inserted, which favors maps populated for a large share of jurisdictions.
+/// Iteration yields the entries in the order of `Jurisdiction::all`.
+///
+/// ```rust
+/// use jurisdiction::{Alpha2, Jurisdiction, JurisdictionMap};
+///
+/// let mut vat_rates = JurisdictionMap::new();
+/// vat_rates.insert(Jurisdiction::from(Alpha2::NO), 25);
+/// vat_rates.insert(Jurisdiction::from(Alpha2::DE), 19);
+///
+/// assert_eq!(vat_rates.get(&Alpha2::NO.into()), Some(&25));
+/// assert_eq!(vat_rates.get(&Alpha2::SE.into()), None);
+/// ```
+///
+/// The map serializes as a map keyed by [Alpha2] codes, sorted alphabetically.
+///
+/// [Jurisdiction]: struct.Jurisdiction.html
+/// [Alpha2]: enum.Alpha2.html
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JurisdictionMap<T> {
+    slots: Box<[Option<T>]>,
+    len: usize,
+}
+
+impl<T> JurisdictionMap<T> {
+    /// Construct an empty map.
+    pub fn new() -> JurisdictionMap<T> {
+        JurisdictionMap {
+            slots: (0..DEFINITION_COUNT).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+
+    /// Return the value of a jurisdiction.
+    pub fn get(&self, jurisdiction: &Jurisdiction) -> Option<&T> {
+        self.slots[jurisdiction.position()].as_ref()
+    }
+
+    /// Return a mutable reference to the value of a jurisdiction.
+    pub fn get_mut(&mut self, jurisdiction: &Jurisdiction) -> Option<&mut T> {
+        self.slots[jurisdiction.position()].as_mut()
+    }
+
+    /// Return whether the map contains a value for the jurisdiction.
+    pub fn contains_key(&self, jurisdiction: &Jurisdiction) -> bool {
+        self.slots[jurisdiction.position()].is_some()
+    }
+
+    /// Insert the value of a jurisdiction, returning the previous value, if any.
+    pub fn insert(&mut self, jurisdiction: Jurisdiction, value: T) -> Option<T> {
+        let previous = self.slots[jurisdiction.position()].replace(value);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Remove the value of a jurisdiction, returning it, if any.
+    pub fn remove(&mut self, jurisdiction: &Jurisdiction) -> Option<T> {
+        let previous = self.slots[jurisdiction.position()].take();
+        if previous.is_some() {
+            self.len -= 1;
+        }
+        previous
+    }
+
+    /// Return the [Entry] of a jurisdiction for in-place manipulation.
+    ///
+    /// [Entry]: struct.Entry.html
+    pub fn entry(&mut self, jurisdiction: Jurisdiction) -> Entry<'_, T> {
+        Entry {
+            index: jurisdiction.position(),
+            jurisdiction,
+            map: self,
+        }
+    }
+
+    /// Return the number of jurisdictions in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return whether the map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all values from the map.
+    pub fn clear(&mut self) {
+        for slot in self.slots.iter_mut() {
+            *slot = None;
+        }
+        self.len = 0;
+    }
+
+    /// Return an iterator over the entries, in the order of `Jurisdiction::all`.
+    pub fn iter(&self) -> impl Iterator<Item = (Jurisdiction, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((Jurisdiction::from_position(index), slot.as_ref()?)))
+    }
+
+    /// Return an iterator over the entries with mutable values, in the order of
+    /// `Jurisdiction::all`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Jurisdiction, &mut T)> {
+        self.slots
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((Jurisdiction::from_position(index), slot.as_mut()?)))
+    }
+
+    /// Return an iterator over the jurisdictions, in the order of `Jurisdiction::all`.
+    pub fn keys(&self) -> impl Iterator<Item = Jurisdiction> + '_ {
+        self.iter().map(|(jurisdiction, _)| jurisdiction)
+    }
+
+    /// Return an iterator over the values, in the order of `Jurisdiction::all`.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().filter_map(Option::as_ref)
+    }
+}
+
+impl<T> Default for JurisdictionMap<T> {
+    fn default() -> JurisdictionMap<T> {
+        JurisdictionMap::new()
+    }
+}
+
+/// The entry of a jurisdiction within a [JurisdictionMap], which may be vacant.
+///
+/// [JurisdictionMap]: struct.JurisdictionMap.html
+pub struct Entry<'a, T> {
+    jurisdiction: Jurisdiction,
+    index: usize,
+    map: &'a mut JurisdictionMap<T>,
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Return the jurisdiction of this entry.
+    pub fn key(&self) -> &Jurisdiction {
+        &self.jurisdiction
+    }
+
+    /// Insert `default` if the entry is vacant, and return a reference to the value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of `default` if the entry is vacant, and return a reference
+    /// to the value.
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+        let slot = &mut self.map.slots[self.index];
+        if slot.is_none() {
+            self.map.len += 1;
+        }
+        slot.get_or_insert_with(default)
+    }
+
+    /// Modify the value in place if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Entry<'a, T> {
+        if let Some(value) = self.map.slots[self.index].as_mut() {
+            f(value);
+        }
+        self
+    }
+}
+
+impl<'a, T: Default> Entry<'a, T> {
+    /// Insert the default value if the entry is vacant, and return a reference to the value.
+    pub fn or_default(self) -> &'a mut T {
+        self.or_insert_with(T::default)
+    }
+}
+
+impl<T> FromIterator<(Jurisdiction, T)> for JurisdictionMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Jurisdiction, T)>>(iter: I) -> JurisdictionMap<T> {
+        let mut map = JurisdictionMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<T> Extend<(Jurisdiction, T)> for JurisdictionMap<T> {
+    fn extend<I: IntoIterator<Item = (Jurisdiction, T)>>(&mut self, iter: I) {
+        for (jurisdiction, value) in iter {
+            self.insert(jurisdiction, value);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for JurisdictionMap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(jurisdiction, value)| (jurisdiction.alpha2(), value)),
+            )
+            .finish()
+    }
+}
+
+impl<T: Serialize> Serialize for JurisdictionMap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entries: Vec<(String, &T)> = self
+            .iter()
+            .map(|(jurisdiction, value)| (jurisdiction.alpha2().to_string(), value))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (code, value) in entries {
+            map.serialize_entry(&code, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for JurisdictionMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MapVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for MapVisitor<T> {
+            type Value = JurisdictionMap<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map keyed by alpha2 codes")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = JurisdictionMap::new();
+                while let Some((alpha2, value)) = access.next_entry::<Alpha2, T>()? {
+                    map.insert(Jurisdiction::from(alpha2), value);
+                }
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_sparse() {
+        let mut map = JurisdictionMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(Alpha2::NO.into(), 25), None);
+        assert_eq!(map.insert(Alpha2::NO.into(), 24), Some(25));
+        assert_eq!(map.insert(Alpha2::DE.into(), 19), None);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get(&Alpha2::NO.into()), Some(&24));
+        assert!(!map.contains_key(&Alpha2::SE.into()));
+        *map.get_mut(&Alpha2::DE.into()).unwrap() += 1;
+        assert_eq!(map.get(&Alpha2::DE.into()), Some(&20));
+
+        assert_eq!(map.remove(&Alpha2::NO.into()), Some(24));
+        assert_eq!(map.remove(&Alpha2::NO.into()), None);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![Alpha2::DE]);
+    }
+
+    #[test]
+    fn test_map_entry() {
+        let mut counts: JurisdictionMap<u32> = JurisdictionMap::new();
+        for alpha2 in &[Alpha2::NO, Alpha2::SE, Alpha2::NO] {
+            *counts.entry(Jurisdiction::from(*alpha2)).or_default() += 1;
+        }
+        assert_eq!(counts.get(&Alpha2::NO.into()), Some(&2));
+        assert_eq!(counts.get(&Alpha2::SE.into()), Some(&1));
+        assert_eq!(counts.len(), 2);
+
+        counts
+            .entry(Alpha2::SE.into())
+            .and_modify(|count| *count *= 10)
+            .or_insert(0);
+        assert_eq!(counts.get(&Alpha2::SE.into()), Some(&10));
+    }
+
+    #[test]
+    fn test_map_fully_populated() {
+        let map: JurisdictionMap<u16> = Jurisdiction::all()
+            .map(|jurisdiction| {
+                let cc = jurisdiction.country_code();
+                (jurisdiction, cc)
+            })
+            .collect();
+        assert_eq!(map.len(), Jurisdiction::all().count());
+
+        // Iteration follows the order of Jurisdiction::all
+        for ((jurisdiction, cc), expected) in map.iter().zip(Jurisdiction::all()) {
+            assert_eq!(jurisdiction, expected);
+            assert_eq!(*cc, expected.country_code());
+        }
+
+        let mut map = map;
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_map_serde_round_trip() {
+        let map: JurisdictionMap<&str> = vec![
+            (Jurisdiction::from(Alpha2::VN), "Hanoi"),
+            (Jurisdiction::from(Alpha2::GB), "London"),
+            (Jurisdiction::from(Alpha2::AF), "Kabul"),
+        ]
+        .into_iter()
+        .collect();
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"AF":"Kabul","GB":"London","VN":"Hanoi"}"#);
+
+        let deserialized: JurisdictionMap<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.len(), 3);
+        assert_eq!(deserialized.get(&Alpha2::GB.into()).unwrap(), "London");
+
+        assert!(serde_json::from_str::<JurisdictionMap<u8>>(r#"{"XX":1}"#).is_err());
+    }
+}
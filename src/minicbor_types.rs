@@ -0,0 +1,134 @@
+//! [minicbor] support.
+//!
+//! A [Jurisdiction] is encoded as its numeric country code, an unsigned integer, while
+//! [Alpha2] and [Alpha3] are encoded as text strings of their code. Decoding an integer
+//! that is not an assigned country code, or a text string that is not a known alpha code,
+//! fails with a `decode::Error` describing the value.
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! let bytes = minicbor::to_vec(&norway).unwrap();
+//! assert_eq!(bytes, [0x19, 0x02, 0x42]);
+//! assert_eq!(minicbor::decode::<Jurisdiction>(&bytes).unwrap(), norway);
+//! ```
+//!
+//! [minicbor]: https://docs.rs/minicbor
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{Decode, Encode};
+
+use std::convert::TryFrom;
+
+impl<C> Encode<C> for Jurisdiction {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.u16(self.country_code())?.ok()
+    }
+}
+
+impl<'b, C> Decode<'b, C> for Jurisdiction {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let position = d.position();
+        let country_code = d.u16()?;
+        Jurisdiction::try_from(country_code).map_err(|err| decode::Error::message(err).at(position))
+    }
+}
+
+/// Implement `Encode` and `Decode` of an alpha code enum as a text string of its code.
+macro_rules! alpha_text {
+    ($alpha:ident, $kind:literal) => {
+        impl<C> Encode<C> for $alpha {
+            fn encode<W: Write>(
+                &self,
+                e: &mut Encoder<W>,
+                _ctx: &mut C,
+            ) -> Result<(), encode::Error<W::Error>> {
+                e.str(&self.to_string())?.ok()
+            }
+        }
+
+        impl<'b, C> Decode<'b, C> for $alpha {
+            fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+                let position = d.position();
+                let code = d.str()?;
+                serde_plain::from_str(code).map_err(|_| {
+                    let message = format!(concat!("unrecognized ", $kind, ": {:?}"), code);
+                    decode::Error::message(message).at(position)
+                })
+            }
+        }
+    };
+}
+
+alpha_text!(Alpha2, "alpha2 code");
+alpha_text!(Alpha3, "alpha3 code");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minicbor_jurisdiction_round_trip() {
+        for jurisdiction in Jurisdiction::all() {
+            let bytes = minicbor::to_vec(&jurisdiction).unwrap();
+            assert_eq!(
+                minicbor::decode::<Jurisdiction>(&bytes).unwrap(),
+                jurisdiction
+            );
+        }
+    }
+
+    #[test]
+    fn test_minicbor_alpha_round_trip() {
+        let bytes = minicbor::to_vec(Alpha2::NO).unwrap();
+        assert_eq!(bytes, [0x62, b'N', b'O']);
+        assert_eq!(minicbor::decode::<Alpha2>(&bytes).unwrap(), Alpha2::NO);
+
+        let bytes = minicbor::to_vec(Alpha3::NOR).unwrap();
+        assert_eq!(bytes, [0x63, b'N', b'O', b'R']);
+        assert_eq!(minicbor::decode::<Alpha3>(&bytes).unwrap(), Alpha3::NOR);
+    }
+
+    #[test]
+    fn test_minicbor_within_array() {
+        let jurisdictions = vec![Jurisdiction::from(Alpha2::NO), Alpha2::SE.into()];
+        let bytes = minicbor::to_vec(&jurisdictions).unwrap();
+        let decoded: Vec<Jurisdiction> = minicbor::decode(&bytes).unwrap();
+        assert_eq!(decoded, jurisdictions);
+    }
+
+    #[test]
+    fn test_minicbor_decode_unassigned() {
+        // An array of Norway, 578, and the unassigned country code 999
+        let bytes = [0x82, 0x19, 0x02, 0x42, 0x19, 0x03, 0xe7];
+        let err = minicbor::decode::<Vec<Jurisdiction>>(&bytes).unwrap_err();
+        assert!(err.is_message());
+        assert!(err.to_string().contains("999"), "{}", err);
+        assert!(err.to_string().contains("position 4"), "{}", err);
+
+        // A text string is no country code
+        assert!(minicbor::decode::<Jurisdiction>(&[0x62, b'N', b'O']).is_err());
+    }
+
+    #[test]
+    fn test_minicbor_decode_unknown_alpha() {
+        let err = minicbor::decode::<Alpha2>(&[0x62, b'X', b'X']).unwrap_err();
+        assert!(err.is_message());
+        assert!(err.to_string().contains(r#""XX""#), "{}", err);
+
+        let err = minicbor::decode::<Alpha3>(&[0x62, b'N', b'O']).unwrap_err();
+        assert!(err.to_string().contains("alpha3 code"), "{}", err);
+    }
+}
@@ -0,0 +1,236 @@
+//! Nationality codes of machine-readable travel documents.
+//!
+//! The machine-readable zone (MRZ) of passports and identity cards, specified by ICAO
+//! Doc 9303, identifies the issuing state and the nationality of the holder by three
+//! letter codes. These are the ISO 3166-1 alpha-3 codes, with the exception of `D` for
+//! Germany, along with codes of their own for British nationals other than citizens,
+//! documents issued by international organisations, and stateless persons and refugees.
+//! The fields of the zone are padded to their length by `<` filler characters.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! use jurisdiction::mrz::{self, MrzCode, MrzNationality};
+//!
+//! assert_eq!(mrz::resolve("D<<"), MrzNationality::Jurisdiction(Alpha2::DE.into()));
+//! assert_eq!(mrz::resolve("GBD"), MrzNationality::Special(MrzCode::GBD));
+//! assert_eq!(MrzCode::GBD.resolve_to_jurisdiction().unwrap(), Alpha2::GB);
+//! ```
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use std::fmt;
+
+/// A code of ICAO Doc 9303 that is not the alpha-3 code of a jurisdiction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MrzCode {
+    /// The European Union.
+    EUE,
+    /// British Overseas Territories Citizen.
+    GBD,
+    /// British National (Overseas).
+    GBN,
+    /// British Overseas Citizen.
+    GBO,
+    /// British Protected Person.
+    GBP,
+    /// British Subject.
+    GBS,
+    /// Kosovo.
+    RKS,
+    /// Specialized agency of the United Nations.
+    UNA,
+    /// Resident of Kosovo, issued by the United Nations Interim Administration Mission.
+    UNK,
+    /// The United Nations Organization.
+    UNO,
+    /// The African Development Bank.
+    XBA,
+    /// The Caribbean Community.
+    XCC,
+    /// The Council of Europe.
+    XCE,
+    /// The Common Market for Eastern and Southern Africa.
+    XCO,
+    /// The Economic Community of West African States.
+    XEC,
+    /// The African Export-Import Bank.
+    XIM,
+    /// The Sovereign Military Order of Malta.
+    XOM,
+    /// The International Criminal Police Organization (INTERPOL).
+    XPO,
+    /// Stateless person, as defined by the 1954 Convention.
+    XXA,
+    /// Refugee, as defined by the 1951 Convention.
+    XXB,
+    /// Refugee, other than as defined by the 1951 Convention.
+    XXC,
+    /// Person of unspecified nationality.
+    XXX,
+}
+
+impl MrzCode {
+    const ALL: [MrzCode; 22] = [
+        MrzCode::EUE,
+        MrzCode::GBD,
+        MrzCode::GBN,
+        MrzCode::GBO,
+        MrzCode::GBP,
+        MrzCode::GBS,
+        MrzCode::RKS,
+        MrzCode::UNA,
+        MrzCode::UNK,
+        MrzCode::UNO,
+        MrzCode::XBA,
+        MrzCode::XCC,
+        MrzCode::XCE,
+        MrzCode::XCO,
+        MrzCode::XEC,
+        MrzCode::XIM,
+        MrzCode::XOM,
+        MrzCode::XPO,
+        MrzCode::XXA,
+        MrzCode::XXB,
+        MrzCode::XXC,
+        MrzCode::XXX,
+    ];
+
+    /// Return the code as a string, e.g. `GBD`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MrzCode::EUE => "EUE",
+            MrzCode::GBD => "GBD",
+            MrzCode::GBN => "GBN",
+            MrzCode::GBO => "GBO",
+            MrzCode::GBP => "GBP",
+            MrzCode::GBS => "GBS",
+            MrzCode::RKS => "RKS",
+            MrzCode::UNA => "UNA",
+            MrzCode::UNK => "UNK",
+            MrzCode::UNO => "UNO",
+            MrzCode::XBA => "XBA",
+            MrzCode::XCC => "XCC",
+            MrzCode::XCE => "XCE",
+            MrzCode::XCO => "XCO",
+            MrzCode::XEC => "XEC",
+            MrzCode::XIM => "XIM",
+            MrzCode::XOM => "XOM",
+            MrzCode::XPO => "XPO",
+            MrzCode::XXA => "XXA",
+            MrzCode::XXB => "XXB",
+            MrzCode::XXC => "XXC",
+            MrzCode::XXX => "XXX",
+        }
+    }
+
+    /// Return the [Jurisdiction] the holder of a document of this code is a national of.
+    ///
+    /// Only the British nationalities resolve, to the United Kingdom. Kosovo is not
+    /// assigned an ISO 3166-1 code, and the remaining codes identify organisations or
+    /// persons without a nationality.
+    ///
+    /// [Jurisdiction]: ../struct.Jurisdiction.html
+    pub fn resolve_to_jurisdiction(&self) -> Option<Jurisdiction> {
+        match self {
+            MrzCode::GBD | MrzCode::GBN | MrzCode::GBO | MrzCode::GBP | MrzCode::GBS => {
+                Some(Jurisdiction::from(Alpha2::GB))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MrzCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The nationality, or issuing state, identified by a code of a machine-readable zone.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MrzNationality {
+    /// The code of a jurisdiction.
+    Jurisdiction(Jurisdiction),
+    /// A code of ICAO Doc 9303 that is not the code of a jurisdiction.
+    Special(MrzCode),
+    /// A code that is not used in machine-readable zones.
+    Unrecognized,
+}
+
+impl MrzNationality {
+    /// Return the [Jurisdiction] of the nationality, resolving special codes through
+    /// [MrzCode::resolve_to_jurisdiction].
+    ///
+    /// [Jurisdiction]: ../struct.Jurisdiction.html
+    /// [MrzCode::resolve_to_jurisdiction]: enum.MrzCode.html#method.resolve_to_jurisdiction
+    pub fn jurisdiction(&self) -> Option<Jurisdiction> {
+        match self {
+            MrzNationality::Jurisdiction(jurisdiction) => Some(jurisdiction.clone()),
+            MrzNationality::Special(code) => code.resolve_to_jurisdiction(),
+            MrzNationality::Unrecognized => None,
+        }
+    }
+}
+
+/// Resolve the nationality or issuing state field of a machine-readable zone.
+///
+/// Trailing `<` filler characters are removed before matching the code, which must be in
+/// uppercase as the zone is. Only the alpha-3 codes of jurisdictions are recognized, as
+/// are used in the zone, along with `D` for Germany.
+pub fn resolve(field: &str) -> MrzNationality {
+    let code = field.trim_end_matches('<');
+    if code == "D" {
+        return MrzNationality::Jurisdiction(Jurisdiction::from(Alpha2::DE));
+    }
+    if code.len() != 3 {
+        return MrzNationality::Unrecognized;
+    }
+    if let Some(alpha2) = Alpha2::from_code(code) {
+        return MrzNationality::Jurisdiction(Jurisdiction::from(alpha2));
+    }
+    match MrzCode::ALL.iter().find(|special| special.as_str() == code) {
+        Some(special) => MrzNationality::Special(*special),
+        None => MrzNationality::Unrecognized,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mrz_resolve_jurisdictions() {
+        let germany = MrzNationality::Jurisdiction(Alpha2::DE.into());
+        assert_eq!(resolve("D<<"), germany);
+        assert_eq!(resolve("D"), germany);
+        assert_eq!(resolve("DEU"), germany);
+        assert_eq!(
+            resolve("GBR"),
+            MrzNationality::Jurisdiction(Alpha2::GB.into())
+        );
+        assert_eq!(
+            resolve("NOR"),
+            MrzNationality::Jurisdiction(Alpha2::NO.into())
+        );
+    }
+
+    #[test]
+    fn test_mrz_resolve_special() {
+        assert_eq!(resolve("GBD"), MrzNationality::Special(MrzCode::GBD));
+        assert_eq!(resolve("GBD").jurisdiction().unwrap(), Alpha2::GB);
+        assert_eq!(resolve("UNO"), MrzNationality::Special(MrzCode::UNO));
+        assert_eq!(resolve("UNO").jurisdiction(), None);
+        assert_eq!(resolve("XXA"), MrzNationality::Special(MrzCode::XXA));
+        for code in MrzCode::ALL.iter() {
+            assert_eq!(resolve(code.as_str()), MrzNationality::Special(*code));
+        }
+    }
+
+    #[test]
+    fn test_mrz_resolve_unrecognized() {
+        for field in &["ZZZ", "NO<", "NO", "nor", "", "<<<", "G<B", "NORW", "D<D"] {
+            assert_eq!(resolve(field), MrzNationality::Unrecognized, "{:?}", field);
+        }
+    }
+}
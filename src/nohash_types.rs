@@ -0,0 +1,55 @@
+//! [nohash-hasher] support.
+//!
+//! A [Jurisdiction] hashes as its numeric country code, written as a single `u16`, such
+//! that it is usable as the key of an `IntMap` or the element of an `IntSet`:
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use nohash_hasher::IntMap;
+//!
+//! let mut populations: IntMap<Jurisdiction, u32> = IntMap::default();
+//! populations.insert(Alpha2::NO.into(), 5_550_203);
+//! assert_eq!(populations[&Alpha2::NO.into()], 5_550_203);
+//! ```
+//!
+//! [nohash-hasher]: https://docs.rs/nohash-hasher
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+
+use crate::Jurisdiction;
+
+impl nohash_hasher::IsEnabled for Jurisdiction {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nohash_hasher::{IntMap, IntSet, NoHashHasher};
+    use std::hash::{Hash, Hasher};
+
+    fn hash(jurisdiction: &Jurisdiction) -> u64 {
+        let mut hasher = NoHashHasher::<Jurisdiction>::default();
+        jurisdiction.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_nohash_is_country_code() {
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(hash(&jurisdiction), u64::from(jurisdiction.country_code()));
+        }
+    }
+
+    #[test]
+    fn test_nohash_no_collisions() {
+        let hashes: IntSet<u64> = Jurisdiction::all().map(|j| hash(&j)).collect();
+        assert_eq!(hashes.len(), Jurisdiction::all().count());
+
+        let map: IntMap<Jurisdiction, usize> = Jurisdiction::all()
+            .map(|j| (j.clone(), j.index()))
+            .collect();
+        assert_eq!(map.len(), Jurisdiction::all().count());
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(map[&jurisdiction], jurisdiction.index());
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! NUTS codes of statistical regions.
+//!
+//! The Nomenclature of Territorial Units for Statistics (NUTS) of Eurostat subdivides the
+//! member states of the European Union into statistical regions at three levels. Each code
+//! is the Eurostat country code followed by one character per level, such as `NO0`,
+//! `NO01` and `NO011`, while the country code alone is level 0. Statistical regions of
+//! the same shape are defined for the EFTA countries, the candidate countries and the
+//! United Kingdom, covered by [COVERED].
+//!
+//! The regions themselves are not enumerated. [jurisdiction_of] validates the shape of a
+//! code and resolves the jurisdiction of its country code:
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::nuts;
+//!
+//! assert_eq!(nuts::jurisdiction_of("EL30").unwrap(), Alpha2::GR);
+//! assert!(nuts::jurisdiction_of("GR30").is_err());
+//! ```
+//!
+//! [COVERED]: constant.COVERED.html
+//! [jurisdiction_of]: fn.jurisdiction_of.html
+
+use crate::alpha::Alpha2;
+use crate::{Jurisdiction, JurisdictionSet};
+
+use std::fmt;
+
+/// The jurisdictions covered by the NUTS 2021 classification, being the member states of
+/// the European Union, along with the statistical regions of the EFTA countries, the
+/// candidate countries Albania, Montenegro, North Macedonia, Serbia and Turkey, and the
+/// United Kingdom.
+pub const COVERED: JurisdictionSet = JurisdictionSet::from_alpha2(&[
+    // European Union
+    Alpha2::AT,
+    Alpha2::BE,
+    Alpha2::BG,
+    Alpha2::CY,
+    Alpha2::CZ,
+    Alpha2::DE,
+    Alpha2::DK,
+    Alpha2::EE,
+    Alpha2::ES,
+    Alpha2::FI,
+    Alpha2::FR,
+    Alpha2::GR,
+    Alpha2::HR,
+    Alpha2::HU,
+    Alpha2::IE,
+    Alpha2::IT,
+    Alpha2::LT,
+    Alpha2::LU,
+    Alpha2::LV,
+    Alpha2::MT,
+    Alpha2::NL,
+    Alpha2::PL,
+    Alpha2::PT,
+    Alpha2::RO,
+    Alpha2::SE,
+    Alpha2::SI,
+    Alpha2::SK,
+    // EFTA
+    Alpha2::CH,
+    Alpha2::IS,
+    Alpha2::LI,
+    Alpha2::NO,
+    // Candidate countries
+    Alpha2::AL,
+    Alpha2::ME,
+    Alpha2::MK,
+    Alpha2::RS,
+    Alpha2::TR,
+    // United Kingdom
+    Alpha2::GB,
+]);
+
+/// Error returned when a string is not the NUTS code of a covered jurisdiction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseNutsError {
+    /// The input is not two upper case letters followed by up to three upper case letters
+    /// or digits.
+    Shape(String),
+    /// The country code of the input is not the Eurostat code of a jurisdiction covered by
+    /// the classification.
+    Country(String),
+}
+
+impl fmt::Display for ParseNutsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNutsError::Shape(s) => write!(f, "malformed NUTS code: {}", s),
+            ParseNutsError::Country(s) => {
+                write!(
+                    f,
+                    "NUTS code of a country outside the classification: {}",
+                    s
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseNutsError {}
+
+/// Return the jurisdiction of a NUTS code at any level, e.g. Norway of `NO011`.
+///
+/// The code must be in upper case, and its country code must be the Eurostat code of a
+/// jurisdiction covered by the classification, e.g. `EL` rather than `GR` for Greece.
+pub fn jurisdiction_of(code: &str) -> Result<Jurisdiction, ParseNutsError> {
+    let bytes = code.as_bytes();
+    let well_formed = (2..=5).contains(&bytes.len())
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..]
+            .iter()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit());
+    if !well_formed {
+        return Err(ParseNutsError::Shape(code.to_string()));
+    }
+
+    let country = &code[..2];
+    Jurisdiction::from_eurostat(country)
+        .filter(|jurisdiction| jurisdiction.eurostat_code() == country)
+        .filter(Jurisdiction::has_nuts)
+        .ok_or_else(|| ParseNutsError::Country(code.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nuts_jurisdiction_of() {
+        for (code, alpha2) in &[
+            ("NO011", Alpha2::NO),
+            ("NO0", Alpha2::NO),
+            ("NO", Alpha2::NO),
+            ("EL30", Alpha2::GR),
+            ("UKI1", Alpha2::GB),
+            ("FR101", Alpha2::FR),
+            ("DEZZZ", Alpha2::DE),
+        ] {
+            assert_eq!(jurisdiction_of(code).unwrap(), *alpha2, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_nuts_malformed() {
+        for code in &["", "N", "NO0111", "no011", "N0011", "NO-1", "NO 1", "ΝΟ1"] {
+            assert_eq!(
+                jurisdiction_of(code),
+                Err(ParseNutsError::Shape(code.to_string())),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_nuts_country() {
+        // The ISO codes of Greece and the United Kingdom are not NUTS codes
+        for code in &["GR30", "GBI1", "XX1", "US12", "CN0"] {
+            assert_eq!(
+                jurisdiction_of(code),
+                Err(ParseNutsError::Country(code.to_string())),
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_has_nuts() {
+        assert!(Jurisdiction::from(Alpha2::NO).has_nuts());
+        assert!(Jurisdiction::from(Alpha2::GR).has_nuts());
+        assert!(Jurisdiction::from(Alpha2::TR).has_nuts());
+        assert!(!Jurisdiction::from(Alpha2::US).has_nuts());
+        assert_eq!(COVERED.iter().count(), 37);
+    }
+}
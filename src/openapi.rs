@@ -0,0 +1,150 @@
+//! [utoipa] support.
+//!
+//! [Alpha2] and [Alpha3] are described as string schemas enumerating their codes, and the
+//! region classifications as string schemas enumerating their names. A [Jurisdiction] is
+//! described as a string holding its alpha2 code, constrained by a pattern rather than
+//! enumerating every code. The schemas are named after their types, e.g. `Jurisdiction`.
+//!
+//! [utoipa]: https://docs.rs/utoipa
+
+use crate::Jurisdiction;
+
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use std::borrow::Cow;
+
+impl PartialSchema for Jurisdiction {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some("ISO 3166-1 alpha-2 code of a jurisdiction."))
+            .pattern(Some("^[A-Z]{2}$"))
+            .examples(["NO"])
+            .into()
+    }
+}
+
+impl ToSchema for Jurisdiction {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("Jurisdiction")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::{Alpha2, Alpha3};
+
+    #[cfg(feature = "region")]
+    use utoipa::OpenApi;
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct Customer {
+        country: Jurisdiction,
+        #[cfg(feature = "region")]
+        region: crate::region::Region,
+    }
+
+    #[allow(dead_code)]
+    #[utoipa::path(
+        get,
+        path = "/customers/{country}",
+        params(("country" = Jurisdiction, Path, description = "Country of the customers")),
+        responses((status = 200, body = Vec<Customer>))
+    )]
+    fn customers_in() {}
+
+    #[cfg(feature = "region")]
+    #[derive(OpenApi)]
+    #[openapi(paths(customers_in), components(schemas(Alpha2, Alpha3)))]
+    struct ApiDoc;
+
+    #[test]
+    fn test_openapi_schema_names() {
+        assert_eq!(<Jurisdiction as ToSchema>::name(), "Jurisdiction");
+        assert_eq!(<Alpha2 as ToSchema>::name(), "Alpha2");
+        assert_eq!(<Alpha3 as ToSchema>::name(), "Alpha3");
+    }
+
+    #[test]
+    fn test_openapi_alpha_enumerated() {
+        let schema = serde_json::to_value(Alpha2::schema()).unwrap();
+        let codes = schema["enum"].as_array().unwrap();
+        assert_eq!(codes.len(), Jurisdiction::all().count());
+        assert!(codes.contains(&serde_json::json!("NO")));
+
+        let schema = serde_json::to_value(Alpha3::schema()).unwrap();
+        assert_eq!(schema["type"], "string");
+        assert!(schema["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("NOR")));
+    }
+
+    #[test]
+    fn test_openapi_jurisdiction_pattern_matches_codes() {
+        let schema = serde_json::to_value(Jurisdiction::schema()).unwrap();
+        assert_eq!(schema["pattern"], "^[A-Z]{2}$");
+        for jurisdiction in Jurisdiction::all() {
+            let code = jurisdiction.alpha2().to_string();
+            assert!(code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_openapi_region_serde_names() {
+        use crate::region::SubRegion;
+
+        let schema = serde_json::to_value(SubRegion::schema()).unwrap();
+        let name = serde_plain::to_string(&SubRegion::NorthernEurope).unwrap();
+        assert!(schema["enum"].as_array().unwrap().contains(&name.into()));
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_openapi_components() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let mut schemas = doc["components"]["schemas"].clone();
+        let schemas = schemas.as_object_mut().unwrap();
+        for alpha in &["Alpha2", "Alpha3"] {
+            // The enumerated codes are covered by test_openapi_alpha_enumerated
+            schemas[*alpha].as_object_mut().unwrap().remove("enum");
+        }
+        assert_eq!(
+            serde_json::Value::Object(schemas.clone()),
+            serde_json::json!({
+                "Alpha2": {
+                    "description": "Two alpha character ISO 3166 country code classification.",
+                    "type": "string"
+                },
+                "Alpha3": {
+                    "description": "Three alpha character ISO 3166 country code classification.",
+                    "type": "string"
+                },
+                "Customer": {
+                    "properties": {
+                        "country": { "$ref": "#/components/schemas/Jurisdiction" },
+                        "region": { "$ref": "#/components/schemas/Region" }
+                    },
+                    "required": ["country", "region"],
+                    "type": "object"
+                },
+                "Jurisdiction": {
+                    "description": "ISO 3166-1 alpha-2 code of a jurisdiction.",
+                    "examples": ["NO"],
+                    "pattern": "^[A-Z]{2}$",
+                    "type": "string"
+                },
+                "Region": {
+                    "description": "The high level region a Jurisdiction may zone to.",
+                    "enum": ["Africa", "Asia", "Europe", "Oceania", "Americas", "Undefined"],
+                    "type": "string"
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Matcher for the small format pattern language shared by the postal code and
+//! VAT number formats.
+//!
+//! * `#` matches a single ASCII digit.
+//! * `@` matches a single ASCII letter.
+//! * `*` matches a single ASCII letter or digit.
+//! * `|` separates alternative formats.
+//! * Any other character matches itself, without regard to ASCII case.
+
+/// Return whether `input` matches any of the alternatives in `pattern`.
+pub fn is_match(pattern: &str, input: &str) -> bool {
+    pattern
+        .split('|')
+        .any(|alternative| matches_alternative(alternative, input))
+}
+
+fn matches_alternative(alternative: &str, input: &str) -> bool {
+    let mut input = input.chars();
+    for token in alternative.chars() {
+        let c = match input.next() {
+            Some(c) => c,
+            None => return false,
+        };
+        let matched = match token {
+            '#' => c.is_ascii_digit(),
+            '@' => c.is_ascii_alphabetic(),
+            '*' => c.is_ascii_alphanumeric(),
+            literal => literal.eq_ignore_ascii_case(&c),
+        };
+        if !matched {
+            return false;
+        }
+    }
+    input.next().is_none()
+}
@@ -0,0 +1,131 @@
+//! [phonenumber] interoperability.
+//!
+//! [Jurisdiction] and [Alpha2] convert to and from the country `Id` of phonenumber, matched
+//! on their alpha2 code. The conversions are fallible in both directions, as phonenumber
+//! identifies the regions of telephone numbering plans, which include territories without
+//! an ISO 3166-1 code of their own, e.g. Ascension Island, and exclude the jurisdictions
+//! without a numbering plan of their own: Antarctica, Bouvet Island, the French Southern
+//! Territories, Heard Island and McDonald Islands, Pitcairn, South Georgia and the South
+//! Sandwich Islands, and the United States Minor Outlying Islands.
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use phonenumber::country::Id;
+//! use std::convert::TryFrom;
+//!
+//! let number = phonenumber::parse(None, "+47 22 00 00 00").unwrap();
+//! let norway = Jurisdiction::of_phone_number(&number).unwrap();
+//! assert_eq!(norway, Alpha2::NO);
+//! assert_eq!(Id::try_from(norway).unwrap(), Id::NO);
+//! ```
+//!
+//! [phonenumber]: https://docs.rs/phonenumber
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use phonenumber::country::Id;
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+impl TryFrom<Id> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn try_from(id: Id) -> Result<Self, Self::Error> {
+        Jurisdiction::from_str(id.as_ref())
+    }
+}
+
+impl TryFrom<Jurisdiction> for Id {
+    type Error = anyhow::Error;
+
+    fn try_from(jurisdiction: Jurisdiction) -> Result<Self, Self::Error> {
+        Id::try_from(jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Id> for Alpha2 {
+    type Error = anyhow::Error;
+
+    fn try_from(id: Id) -> Result<Self, Self::Error> {
+        Jurisdiction::try_from(id).map(|jurisdiction| jurisdiction.alpha2())
+    }
+}
+
+impl TryFrom<Alpha2> for Id {
+    type Error = anyhow::Error;
+
+    fn try_from(alpha2: Alpha2) -> Result<Self, Self::Error> {
+        Id::from_str(&alpha2.to_string())
+            .map_err(|_| format_err!("jurisdiction {} is not defined by phonenumber", alpha2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phonenumber_round_trip() {
+        let mut undefined = Vec::new();
+        for jurisdiction in Jurisdiction::all() {
+            let id = match Id::try_from(jurisdiction.clone()) {
+                Ok(id) => id,
+                Err(_) => {
+                    undefined.push(jurisdiction.alpha2());
+                    continue;
+                }
+            };
+            assert_eq!(Jurisdiction::try_from(id).unwrap(), jurisdiction);
+            assert_eq!(Alpha2::try_from(id).unwrap(), jurisdiction.alpha2());
+        }
+        // Jurisdictions without a telephone numbering plan of their own
+        assert_eq!(
+            undefined,
+            [
+                Alpha2::AQ,
+                Alpha2::BV,
+                Alpha2::TF,
+                Alpha2::HM,
+                Alpha2::PN,
+                Alpha2::GS,
+                Alpha2::UM
+            ],
+            "jurisdictions not defined by phonenumber"
+        );
+    }
+
+    #[test]
+    fn test_phonenumber_unrecognized_id() {
+        // Ascension Island and Tristan da Cunha are numbering plan regions, but part of
+        // Saint Helena, Ascension and Tristan da Cunha in ISO 3166-1
+        for id in &[Id::AC, Id::TA, Id::XK] {
+            assert!(Jurisdiction::try_from(*id).is_err(), "{:?}", id);
+        }
+        let number = phonenumber::parse(None, "+247 6000").unwrap();
+        assert_eq!(number.country().id(), Some(Id::AC));
+        assert_eq!(Jurisdiction::of_phone_number(&number), None);
+    }
+
+    #[test]
+    fn test_phonenumber_of_phone_number() {
+        let number = phonenumber::parse(None, "+1 416 555 0100").unwrap();
+        assert_eq!(
+            Jurisdiction::of_phone_number(&number),
+            Some(Alpha2::CA.into())
+        );
+        // Shared country calling codes are resolved through the national number
+        let number = phonenumber::parse(None, "+1 212 555 0100").unwrap();
+        assert_eq!(
+            Jurisdiction::of_phone_number(&number),
+            Some(Alpha2::US.into())
+        );
+        // International freephone numbers are not assigned to a region
+        let number = phonenumber::parse(None, "+800 1234 5678").unwrap();
+        assert_eq!(Jurisdiction::of_phone_number(&number), None);
+    }
+}
@@ -0,0 +1,83 @@
+//! [Polars] conversions.
+//!
+//! A thin helper over the [arrow] conversions, converting jurisdictions into and from a
+//! `Series` of their alpha2 codes. A `Series` of alpha3 codes is read back as well, and
+//! invalid rows are reported by the same [ArrayError].
+//!
+//! ```rust
+//! use jurisdiction::{polars, Alpha2, Jurisdiction};
+//!
+//! let jurisdictions = [Jurisdiction::from(Alpha2::NO), Jurisdiction::from(Alpha2::SE)];
+//! let series = polars::to_series("country", &jurisdictions);
+//! assert_eq!(polars::try_from_series(&series).unwrap(), jurisdictions);
+//! ```
+//!
+//! [Polars]: https://pola.rs
+//! [arrow]: ../arrow/index.html
+//! [ArrayError]: ../arrow/enum.ArrayError.html
+
+use crate::arrow::{try_from_codes, ArrayError};
+use crate::Jurisdiction;
+
+use polars::prelude::{DataType, NamedFrom, Series};
+
+/// Return the alpha2 codes of `jurisdictions` as a `String` series named `name`.
+pub fn to_series(name: &str, jurisdictions: &[Jurisdiction]) -> Series {
+    let codes: Vec<String> = jurisdictions
+        .iter()
+        .map(|jurisdiction| jurisdiction.alpha2().to_string())
+        .collect();
+    Series::new(name.into(), codes)
+}
+
+/// Return the jurisdictions of a `String` series of alpha2 or alpha3 codes.
+///
+/// Every row is validated, and the first null or unrecognized row is reported by the
+/// error.
+pub fn try_from_series(series: &Series) -> Result<Vec<Jurisdiction>, ArrayError> {
+    match series.dtype() {
+        DataType::String => {
+            let codes = series.str().expect("series is of strings");
+            try_from_codes(codes.iter())
+        }
+        dtype => Err(ArrayError::UnsupportedType(dtype.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polars_round_trip() {
+        let jurisdictions: Vec<_> = Jurisdiction::all().collect();
+        let series = to_series("country", &jurisdictions);
+        assert_eq!(series.name().as_str(), "country");
+        assert_eq!(series.len(), jurisdictions.len());
+        assert_eq!(try_from_series(&series).unwrap(), jurisdictions);
+    }
+
+    #[test]
+    fn test_polars_invalid_values() {
+        let series = Series::new("country".into(), &[Some("NOR"), None, Some("XX")]);
+        assert_eq!(
+            try_from_series(&series).unwrap_err(),
+            ArrayError::Null { row: 1 }
+        );
+
+        let series = Series::new("country".into(), &["NO", "SE", "XX"]);
+        assert_eq!(
+            try_from_series(&series).unwrap_err(),
+            ArrayError::Unrecognized {
+                row: 2,
+                value: "XX".to_string()
+            }
+        );
+
+        let series = Series::new("country".into(), &[578i32]);
+        assert_eq!(
+            try_from_series(&series).unwrap_err(),
+            ArrayError::UnsupportedType("i32".to_string())
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Postal code formats.
+//!
+//! The format of the postal codes within a jurisdiction is described by a small
+//! pattern language, in the style of the [GeoNames] country information:
+//!
+//! * `#` matches a single ASCII digit.
+//! * `@` matches a single ASCII letter.
+//! * `*` matches a single ASCII letter or digit.
+//! * `|` separates alternative formats.
+//! * Any other character matches itself.
+//!
+//! Letters are matched without regard to ASCII case, so both `sw1a 1aa` and
+//! `SW1A 1AA` are accepted for the United Kingdom.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! let format = norway.postal_code_format().unwrap();
+//! assert_eq!(format.pattern(), "####");
+//! assert!(format.is_match("0150"));
+//! assert!(!format.is_match("150"));
+//! ```
+//!
+//! [GeoNames]: https://www.geonames.org/countries/
+
+/// The format of postal codes within a `Jurisdiction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PostalFormat {
+    pub(crate) pattern: &'static str,
+    pub(crate) example: &'static str,
+}
+
+impl PostalFormat {
+    /// Return the pattern describing the valid postal codes, e.g. `#### @@` for the Netherlands.
+    ///
+    /// See the [module documentation](index.html) for the pattern language.
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// Return an example of a valid postal code.
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+
+    /// Return whether `code` matches any of the alternatives in the pattern.
+    ///
+    /// Surrounding whitespace of `code` is ignored.
+    pub fn is_match(&self, code: &str) -> bool {
+        crate::pattern::is_match(self.pattern, code.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generated::definition::GENERATED_DEFINITIONS;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_postal_code_norway() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(norway.validate_postal_code("0150"));
+        assert!(norway.validate_postal_code(" 9170 "));
+        assert!(!norway.validate_postal_code("150"));
+        assert!(!norway.validate_postal_code("01500"));
+        assert!(!norway.validate_postal_code("O150"));
+    }
+
+    #[test]
+    fn test_postal_code_netherlands() {
+        let netherlands = Jurisdiction::from(Alpha2::NL);
+        assert!(netherlands.validate_postal_code("1012 AB"));
+        assert!(netherlands.validate_postal_code("1012ab"));
+        assert!(!netherlands.validate_postal_code("1012 A1"));
+        assert!(!netherlands.validate_postal_code("AB 1012"));
+    }
+
+    #[test]
+    fn test_postal_code_united_kingdom() {
+        let uk = Jurisdiction::from(Alpha2::GB);
+        for code in &[
+            "SW1A 1AA", "M1 1AE", "B33 8TH", "CR2 6XH", "DN55 1PT", "W1A 0AX", "GIR 0AA",
+        ] {
+            assert!(uk.validate_postal_code(code), "{} should be valid", code);
+        }
+        assert!(uk.validate_postal_code("ec1a 1bb"));
+        assert!(!uk.validate_postal_code("SW1A1AA"));
+        assert!(!uk.validate_postal_code("1SW 1AA"));
+        assert!(!uk.validate_postal_code("SW1A 1A"));
+    }
+
+    #[test]
+    fn test_postal_code_united_states() {
+        let us = Jurisdiction::from(Alpha2::US);
+        assert!(us.validate_postal_code("20500"));
+        assert!(us.validate_postal_code("20500-0003"));
+        assert!(!us.validate_postal_code("2050"));
+        assert!(!us.validate_postal_code("20500-003"));
+        assert!(!us.validate_postal_code("205000003"));
+    }
+
+    #[test]
+    fn test_postal_code_without_postal_codes() {
+        let emirates = Jurisdiction::from(Alpha2::AE);
+        assert_eq!(emirates.postal_code_format(), None);
+        assert!(emirates.validate_postal_code(""));
+        assert!(emirates.validate_postal_code("anything"));
+    }
+
+    #[test]
+    fn test_postal_code_examples_match_format() {
+        for def in GENERATED_DEFINITIONS.iter() {
+            if let Some(format) = def.postal_code_format {
+                assert!(
+                    format.is_match(format.example()),
+                    "example {} of {} does not match {}",
+                    format.example(),
+                    def.name,
+                    format.pattern()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! [postgres-types] support, as used by tokio-postgres and postgres.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are stored as their alpha code in `TEXT`, `VARCHAR`
+//! and `CHAR` columns, tolerating the space padding of `CHAR`. A `Jurisdiction` is stored
+//! as its alpha2 code and read back from either an alpha2 or an alpha3 code, and is
+//! additionally stored as its numeric country code in `SMALLINT` columns.
+//!
+//! The types are passed as query parameters and read from rows as any other type, e.g. with
+//! tokio-postgres:
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use postgres_types::{FromSql, ToSql, Type};
+//!
+//! // client.query("SELECT name FROM customers WHERE country = $1", &[&jurisdiction])
+//! let jurisdiction = Jurisdiction::from(Alpha2::NO);
+//! let params: &[&(dyn ToSql + Sync)] = &[&jurisdiction];
+//! assert!(<Jurisdiction as ToSql>::accepts(&Type::BPCHAR));
+//!
+//! // let country: Jurisdiction = row.get("country");
+//! let country = Jurisdiction::from_sql(&Type::INT2, &578i16.to_be_bytes()).unwrap();
+//! assert_eq!(country, Alpha2::NO);
+//! ```
+//!
+//! [postgres-types]: https://docs.rs/postgres-types
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{
+    alpha2_from_sql, alpha3_from_sql, jurisdiction_from_sql, jurisdiction_from_sql_numeric,
+};
+use crate::Jurisdiction;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use std::convert::TryFrom;
+use std::error::Error;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+/// Return whether `ty` is one of the text types alpha codes are stored in.
+fn accepts_text(ty: &Type) -> bool {
+    matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR)
+}
+
+impl ToSql for Alpha2 {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.to_string().as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Alpha2 {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        Ok(alpha2_from_sql(<&str>::from_sql(ty, raw)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty)
+    }
+}
+
+impl ToSql for Alpha3 {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        self.to_string().as_str().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Alpha3 {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        Ok(alpha3_from_sql(<&str>::from_sql(ty, raw)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty)
+    }
+}
+
+impl ToSql for Jurisdiction {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+        if *ty == Type::INT2 {
+            return i16::try_from(self.country_code())?.to_sql(ty, out);
+        }
+        self.alpha2().to_sql(ty, out)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty) || *ty == Type::INT2
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Jurisdiction {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        if *ty == Type::INT2 {
+            let cc = i16::from_sql(ty, raw)?;
+            return Ok(jurisdiction_from_sql_numeric(i64::from(cc))?);
+        }
+        Ok(jurisdiction_from_sql(<&str>::from_sql(ty, raw)?)?)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        accepts_text(ty) || *ty == Type::INT2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postgres_accepts() {
+        for ty in &[Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+            assert!(<Alpha2 as ToSql>::accepts(ty));
+            assert!(<Alpha3 as FromSql<'_>>::accepts(ty));
+            assert!(<Jurisdiction as ToSql>::accepts(ty));
+            assert!(<Jurisdiction as FromSql<'_>>::accepts(ty));
+        }
+        assert!(<Jurisdiction as ToSql>::accepts(&Type::INT2));
+        assert!(<Jurisdiction as FromSql<'_>>::accepts(&Type::INT2));
+        assert!(!<Alpha2 as FromSql<'_>>::accepts(&Type::INT2));
+        assert!(!<Jurisdiction as FromSql<'_>>::accepts(&Type::INT4));
+        assert!(!<Jurisdiction as ToSql>::accepts(&Type::BYTEA));
+    }
+
+    #[test]
+    fn test_postgres_encode() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+
+        let mut buf = BytesMut::new();
+        assert!(matches!(
+            norway.to_sql(&Type::TEXT, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&buf[..], b"NO");
+
+        let mut buf = BytesMut::new();
+        norway.to_sql(&Type::INT2, &mut buf).unwrap();
+        assert_eq!(&buf[..], &578i16.to_be_bytes());
+
+        let mut buf = BytesMut::new();
+        Alpha3::NOR.to_sql(&Type::BPCHAR, &mut buf).unwrap();
+        assert_eq!(&buf[..], b"NOR");
+
+        let mut buf = BytesMut::new();
+        assert!(norway.to_sql_checked(&Type::INT4, &mut buf).is_err());
+    }
+
+    #[test]
+    fn test_postgres_decode() {
+        assert_eq!(Alpha2::from_sql(&Type::TEXT, b"NO").unwrap(), Alpha2::NO);
+        assert_eq!(
+            Alpha3::from_sql(&Type::BPCHAR, b"NOR").unwrap(),
+            Alpha3::NOR
+        );
+        assert_eq!(
+            Jurisdiction::from_sql(&Type::BPCHAR, b"NO ").unwrap(),
+            Alpha2::NO
+        );
+        assert_eq!(
+            Jurisdiction::from_sql(&Type::VARCHAR, b"NOR").unwrap(),
+            Alpha2::NO
+        );
+        assert_eq!(
+            Jurisdiction::from_sql(&Type::INT2, &578i16.to_be_bytes()).unwrap(),
+            Alpha2::NO
+        );
+    }
+
+    #[test]
+    fn test_postgres_decode_unknown() {
+        let err = Jurisdiction::from_sql(&Type::TEXT, b"XX").unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized jurisdiction code: "XX""#);
+        let err = Jurisdiction::from_sql(&Type::INT2, &0i16.to_be_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized numeric country code: "0""#);
+        let err = Alpha2::from_sql(&Type::TEXT, b"NOR").unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized alpha2 code: "NOR""#);
+    }
+}
@@ -0,0 +1,91 @@
+//! Electrical plug types and mains power.
+//!
+//! This information is typeset from [IEC World Plugs], describing the plug types, mains
+//! voltages and mains frequencies in use within each jurisdiction.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::power::PlugType;
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! assert_eq!(norway.plug_types(), &[PlugType::C, PlugType::F]);
+//! assert_eq!(norway.mains_voltage(), &[230]);
+//! assert_eq!(norway.mains_frequency(), &[50]);
+//! ```
+//!
+//! [IEC World Plugs]: https://www.iec.ch/world-plugs
+
+use serde::{Deserialize, Serialize};
+
+/// An electrical plug and socket type, lettered by the IEC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[allow(missing_docs)]
+pub enum PlugType {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_power_norway() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.plug_types(), &[PlugType::C, PlugType::F]);
+        assert_eq!(norway.mains_voltage(), &[230]);
+        assert_eq!(norway.mains_frequency(), &[50]);
+    }
+
+    #[test]
+    fn test_power_united_states() {
+        let us = Jurisdiction::from(Alpha2::US);
+        assert_eq!(us.plug_types(), &[PlugType::A, PlugType::B]);
+        assert_eq!(us.mains_voltage(), &[120]);
+        assert_eq!(us.mains_frequency(), &[60]);
+    }
+
+    #[test]
+    fn test_power_united_kingdom() {
+        let uk = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(uk.plug_types(), &[PlugType::G]);
+        assert_eq!(uk.mains_voltage(), &[230]);
+        assert_eq!(uk.mains_frequency(), &[50]);
+    }
+
+    #[test]
+    fn test_power_japan_split_frequency() {
+        let japan = Jurisdiction::from(Alpha2::JP);
+        assert_eq!(japan.mains_voltage(), &[100]);
+        assert_eq!(japan.mains_frequency(), &[50, 60]);
+    }
+
+    #[test]
+    fn test_power_brazil_mixed_voltage() {
+        let brazil = Jurisdiction::from(Alpha2::BR);
+        assert_eq!(brazil.plug_types(), &[PlugType::C, PlugType::N]);
+        assert_eq!(brazil.mains_voltage(), &[127, 220]);
+        assert_eq!(brazil.mains_frequency(), &[60]);
+    }
+
+    #[test]
+    fn test_power_uninhabited() {
+        let antarctica = Jurisdiction::from(Alpha2::AQ);
+        assert!(antarctica.plug_types().is_empty());
+        assert!(antarctica.mains_voltage().is_empty());
+    }
+}
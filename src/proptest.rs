@@ -0,0 +1,177 @@
+//! [proptest] strategies.
+//!
+//! The strategies choose uniformly from the valid values, and shrink toward the
+//! jurisdiction of the lowest numeric country code, Afghanistan (`004`). Strategies of
+//! codes shrink toward the codes of that jurisdiction, e.g. `AF`.
+//!
+//! ```rust
+//! use jurisdiction::proptest::{any_jurisdiction, valid_code_string};
+//! use jurisdiction::Jurisdiction;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn alpha3_round_trip(jurisdiction in any_jurisdiction()) {
+//!         prop_assert_eq!(Jurisdiction::from(jurisdiction.alpha3()), jurisdiction);
+//!     }
+//!
+//!     fn parse_code(code in valid_code_string()) {
+//!         prop_assert!(code.parse::<Jurisdiction>().is_ok());
+//!     }
+//! }
+//!
+//! // Normally annotated with #[test] instead
+//! alpha3_round_trip();
+//! parse_code();
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+
+use crate::alpha::Alpha2;
+#[cfg(feature = "region")]
+use crate::region::Region;
+use crate::Jurisdiction;
+
+use lazy_static::lazy_static;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+lazy_static! {
+    /// All jurisdictions, ordered by numeric country code.
+    static ref BY_COUNTRY_CODE: Vec<Jurisdiction> = by_country_code(Jurisdiction::all());
+    /// All strings of two or three uppercase letters that are not codes of a jurisdiction,
+    /// ordered by length and then alphabetically.
+    static ref INVALID_CODES: Vec<String> = invalid_codes();
+}
+
+fn invalid_codes() -> Vec<String> {
+    let letters = || b'A'..=b'Z';
+    let alpha2 = letters().flat_map(move |a| letters().map(move |b| vec![a, b]));
+    let alpha3 = letters()
+        .flat_map(move |a| letters().flat_map(move |b| letters().map(move |c| vec![a, b, c])));
+    alpha2
+        .chain(alpha3)
+        .map(|code| String::from_utf8(code).expect("ascii letters"))
+        .filter(|code| code.parse::<Jurisdiction>().is_err())
+        .collect()
+}
+
+fn by_country_code(jurisdictions: impl Iterator<Item = Jurisdiction>) -> Vec<Jurisdiction> {
+    let mut jurisdictions: Vec<Jurisdiction> = jurisdictions.collect();
+    jurisdictions.sort_by_key(Jurisdiction::country_code);
+    jurisdictions
+}
+
+/// Return a strategy of any `Jurisdiction`.
+///
+/// Shrinks toward lower numeric country codes.
+pub fn any_jurisdiction() -> impl Strategy<Value = Jurisdiction> {
+    select(BY_COUNTRY_CODE.as_slice())
+}
+
+/// Return a strategy of any alpha2 code.
+///
+/// Shrinks toward the codes of lower numeric country codes.
+pub fn any_alpha2() -> impl Strategy<Value = Alpha2> {
+    any_jurisdiction().prop_map(|jurisdiction| jurisdiction.alpha2())
+}
+
+/// Return a strategy of any `Jurisdiction` zoning to `region`.
+///
+/// Shrinks toward lower numeric country codes.
+#[cfg(feature = "region")]
+pub fn jurisdiction_in(region: Region) -> impl Strategy<Value = Jurisdiction> {
+    select(by_country_code(region.jurisdictions().iter().cloned()))
+}
+
+/// Return a strategy of strings that parse as a `Jurisdiction`, being either its alpha2 or
+/// its alpha3 code.
+///
+/// Shrinks toward the alpha2 codes of lower numeric country codes.
+pub fn valid_code_string() -> impl Strategy<Value = String> {
+    (any_jurisdiction(), any::<bool>()).prop_map(|(jurisdiction, alpha3)| {
+        if alpha3 {
+            jurisdiction.alpha3().to_string()
+        } else {
+            jurisdiction.alpha2().to_string()
+        }
+    })
+}
+
+/// Return a strategy of strings shaped like alpha2 or alpha3 codes, that do not parse as a
+/// `Jurisdiction`.
+///
+/// Shrinks toward `AA`, which is assigned by ISO 3166-1 for user defined purposes.
+pub fn invalid_code_string() -> impl Strategy<Value = String> {
+    select(INVALID_CODES.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha3;
+
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    /// Simplify a generated value of `strategy` for as long as possible.
+    fn shrunk<S: Strategy>(strategy: S) -> S::Value {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = strategy.new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        tree.current()
+    }
+
+    #[test]
+    fn test_proptest_shrink() {
+        assert_eq!(shrunk(any_jurisdiction()), Alpha2::AF);
+        assert_eq!(shrunk(any_alpha2()), Alpha2::AF);
+        assert_eq!(shrunk(valid_code_string()), "AF");
+        assert_eq!(shrunk(invalid_code_string()), "AA");
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_proptest_shrink_in_region() {
+        // Albania has the lowest numeric country code within Europe
+        assert_eq!(shrunk(jurisdiction_in(Region::Europe)), Alpha2::AL);
+    }
+
+    proptest! {
+        #[test]
+        fn test_proptest_alpha_round_trip(jurisdiction in any_jurisdiction()) {
+            prop_assert_eq!(Jurisdiction::from(jurisdiction.alpha2()), jurisdiction.clone());
+            prop_assert_eq!(Jurisdiction::from(jurisdiction.alpha3()), jurisdiction);
+        }
+
+        #[test]
+        fn test_proptest_alpha2_to_alpha3(alpha2 in any_alpha2()) {
+            let alpha3: Alpha3 = Jurisdiction::from(alpha2).alpha3();
+            prop_assert_eq!(Jurisdiction::from(alpha3).alpha2(), alpha2);
+        }
+
+        #[test]
+        fn test_proptest_valid_code(code in valid_code_string()) {
+            let jurisdiction: Jurisdiction = code.parse().unwrap();
+            prop_assert!(jurisdiction == jurisdiction.alpha2() || jurisdiction == jurisdiction.alpha3());
+        }
+
+        #[test]
+        fn test_proptest_invalid_code(code in invalid_code_string()) {
+            prop_assert!(code.parse::<Jurisdiction>().is_err());
+        }
+
+        #[test]
+        #[cfg(feature = "region")]
+        fn test_proptest_in_region(jurisdiction in jurisdiction_in(Region::Oceania)) {
+            prop_assert_eq!(jurisdiction.region(), Region::Oceania);
+        }
+
+        #[test]
+        fn test_proptest_compose(
+            jurisdictions in proptest::collection::vec(any_jurisdiction(), 0..8),
+        ) {
+            let set: crate::JurisdictionSet = jurisdictions.iter().cloned().collect();
+            prop_assert!(jurisdictions.iter().all(|jurisdiction| set.contains(jurisdiction)));
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! [PyO3] bindings, exposing jurisdictions to Python.
+//!
+//! The `jurisdiction` Python module holds the `Jurisdiction` class along with the
+//! function `all`, returning every jurisdiction in the order of ISO 3166-1:
+//!
+//! ```python
+//! import jurisdiction
+//!
+//! norway = jurisdiction.Jurisdiction.from_code("NOR")
+//! print(norway.name, norway.alpha2, norway.numeric)  # Norway NO 578
+//! assert norway == jurisdiction.Jurisdiction.from_name("norway")
+//! assert len(jurisdiction.all()) == 249
+//! ```
+//!
+//! Unrecognized codes and names raise `ValueError`. The module is built as an extension
+//! module by [maturin], configured through `pyproject.toml`, e.g. with `maturin develop`.
+//!
+//! [PyO3]: https://pyo3.rs
+//! [maturin]: https://www.maturin.rs
+
+use crate::Jurisdiction;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use std::str::FromStr;
+
+/// A `Jurisdiction` exported to Python.
+#[pyclass(
+    name = "Jurisdiction",
+    module = "jurisdiction",
+    frozen,
+    skip_from_py_object
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyJurisdiction(Jurisdiction);
+
+#[pymethods]
+impl PyJurisdiction {
+    /// Return the jurisdiction of an alpha2 or alpha3 code.
+    #[staticmethod]
+    fn from_code(code: &str) -> PyResult<Self> {
+        Jurisdiction::from_str(code)
+            .map(PyJurisdiction)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// Return the jurisdiction of a common or formal english name, ignoring case.
+    #[staticmethod]
+    fn from_name(name: &str) -> PyResult<Self> {
+        Jurisdiction::all()
+            .find(|jurisdiction| {
+                jurisdiction.name().eq_ignore_ascii_case(name)
+                    || jurisdiction.formal_name().eq_ignore_ascii_case(name)
+            })
+            .map(PyJurisdiction)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!("unrecognized jurisdiction name: {}", name))
+            })
+    }
+
+    /// The common english name.
+    #[getter]
+    fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    /// The ISO 3166 alpha2 code.
+    #[getter]
+    fn alpha2(&self) -> String {
+        self.0.alpha2().to_string()
+    }
+
+    /// The ISO 3166 alpha3 code.
+    #[getter]
+    fn alpha3(&self) -> String {
+        self.0.alpha3().to_string()
+    }
+
+    /// The ISO 3166 numeric country code.
+    #[getter]
+    fn numeric(&self) -> u16 {
+        self.0.country_code()
+    }
+
+    /// The name of the UN M49 region, e.g. `Europe`.
+    #[cfg(feature = "region")]
+    #[getter]
+    fn region(&self) -> String {
+        serde_plain::to_string(&self.0.region()).expect("regions serialize to strings")
+    }
+
+    /// The name of the UN M49 sub region, e.g. `Northern Europe`.
+    #[cfg(feature = "region")]
+    #[getter]
+    fn sub_region(&self) -> String {
+        serde_plain::to_string(&self.0.sub_region()).expect("regions serialize to strings")
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __hash__(&self) -> u64 {
+        u64::from(self.0.country_code())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Jurisdiction('{}')", self.0.alpha2())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.alpha2().to_string()
+    }
+}
+
+/// Return all jurisdictions, in the order of ISO 3166-1.
+#[pyfunction]
+fn all() -> Vec<PyJurisdiction> {
+    Jurisdiction::all().map(PyJurisdiction).collect()
+}
+
+/// The `jurisdiction` Python module.
+#[pymodule]
+fn jurisdiction(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyJurisdiction>()?;
+    m.add_function(wrap_pyfunction!(all, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::ffi::c_str;
+    use pyo3::types::PyDict;
+
+    /// Run `code` with the `jurisdiction` module imported.
+    fn run(code: &std::ffi::CStr) -> PyResult<()> {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "jurisdiction")?;
+            jurisdiction(&module)?;
+            let globals = PyDict::new(py);
+            globals.set_item("jurisdiction", module)?;
+            py.run(code, Some(&globals), None)
+        })
+    }
+
+    #[test]
+    fn test_python_parse() {
+        run(c_str!(
+            r#"
+norway = jurisdiction.Jurisdiction.from_code("NOR")
+assert norway.name == "Norway", norway.name
+assert (norway.alpha2, norway.alpha3, norway.numeric) == ("NO", "NOR", 578)
+assert repr(norway) == "Jurisdiction('NO')", repr(norway)
+assert jurisdiction.Jurisdiction.from_name("kingdom of norway") == norway
+
+try:
+    jurisdiction.Jurisdiction.from_code("XX")
+    raise AssertionError("parsed XX")
+except ValueError as err:
+    assert str(err) == "unrecognized ISO 3166 alpha country code: XX", str(err)
+
+try:
+    jurisdiction.Jurisdiction.from_name("Atlantis")
+    raise AssertionError("parsed Atlantis")
+except ValueError as err:
+    assert str(err) == "unrecognized jurisdiction name: Atlantis", str(err)
+"#
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_python_equality() {
+        run(c_str!(
+            r#"
+norway = jurisdiction.Jurisdiction.from_code("NO")
+assert norway == jurisdiction.Jurisdiction.from_code("NOR")
+assert norway != jurisdiction.Jurisdiction.from_code("SE")
+assert len({norway, jurisdiction.Jurisdiction.from_name("Norway")}) == 1
+assert {norway: 1}[jurisdiction.Jurisdiction.from_code("NOR")] == 1
+"#
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn test_python_all() {
+        run(c_str!(
+            r#"
+everything = jurisdiction.all()
+assert isinstance(everything, list)
+assert len(everything) == len({j.alpha2 for j in everything})
+assert everything[0].alpha2 == "AF"
+assert jurisdiction.Jurisdiction.from_code("NO") in everything
+"#
+        ))
+        .unwrap();
+        assert_eq!(
+            Python::attach(|py| -> PyResult<usize> {
+                let module = PyModule::new(py, "jurisdiction")?;
+                jurisdiction(&module)?;
+                module.getattr("all")?.call0()?.len()
+            })
+            .unwrap(),
+            Jurisdiction::all().count()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_python_region() {
+        run(c_str!(
+            r#"
+norway = jurisdiction.Jurisdiction.from_code("NO")
+assert (norway.region, norway.sub_region) == ("Europe", "Northern Europe")
+"#
+        ))
+        .unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+//! A builder of filtered listings of jurisdictions.
+
+#[cfg(feature = "region")]
+use crate::region::Region;
+use crate::{Jurisdiction, JurisdictionSet, ALL_BY_NAME};
+
+use std::iter::FromIterator;
+
+/// A query composing filters over all jurisdictions, constructed by [Jurisdiction::query].
+///
+/// Every filter narrows the result further, such that a jurisdiction is only listed when
+/// it passes all of them. The filters are evaluated lazily over the static tables as the
+/// query is iterated, in the order of [Jurisdiction::all] unless sorted otherwise.
+/// Filters depending on a feature are only available when it is enabled.
+///
+/// ```rust
+/// # #[cfg(all(feature = "political", feature = "region"))] {
+/// use jurisdiction::{sets, Alpha2, Jurisdiction};
+/// use jurisdiction::region::Region;
+///
+/// let jurisdictions: Vec<_> = Jurisdiction::query()
+///     .region(Region::Europe)
+///     .member_of(&sets::EFTA)
+///     .exclude([Alpha2::CH])
+///     .sort_by_name()
+///     .collect();
+/// assert_eq!(jurisdictions, [Alpha2::IS, Alpha2::LI, Alpha2::NO]);
+/// # }
+/// ```
+///
+/// [Jurisdiction::query]: struct.Jurisdiction.html#method.query
+/// [Jurisdiction::all]: struct.Jurisdiction.html#method.all
+#[derive(Clone, Debug, Default)]
+pub struct JurisdictionQuery {
+    /// The regions of which a jurisdiction must be in any, if restricted.
+    #[cfg(feature = "region")]
+    regions: Option<Vec<Region>>,
+    /// The set of which a jurisdiction must be a member, if restricted.
+    members: Option<JurisdictionSet>,
+    excluded: JurisdictionSet,
+    by_name: bool,
+}
+
+impl JurisdictionQuery {
+    /// Construct a query listing all jurisdictions.
+    pub fn new() -> JurisdictionQuery {
+        JurisdictionQuery::default()
+    }
+
+    /// Only list jurisdictions within the [Region].
+    ///
+    /// [Region]: region/enum.Region.html
+    #[cfg(feature = "region")]
+    pub fn region(self, region: Region) -> JurisdictionQuery {
+        self.any_region([region])
+    }
+
+    /// Only list jurisdictions within any of the [Region]s.
+    ///
+    /// Restricting the regions of a query already restricted by region only lists the
+    /// regions in both, such that `any_region([Europe, Asia]).region(Asia)` lists Asia.
+    ///
+    /// [Region]: region/enum.Region.html
+    #[cfg(feature = "region")]
+    pub fn any_region<I>(mut self, regions: I) -> JurisdictionQuery
+    where
+        I: IntoIterator<Item = Region>,
+    {
+        let regions = regions.into_iter();
+        self.regions = Some(match self.regions {
+            Some(restricted) => regions
+                .filter(|region| restricted.contains(region))
+                .collect(),
+            None => regions.collect(),
+        });
+        self
+    }
+
+    /// Only list jurisdictions that are members of the set, such as
+    /// [sets::EU](sets/constant.EU.html).
+    pub fn member_of(mut self, set: &JurisdictionSet) -> JurisdictionQuery {
+        self.members = Some(match self.members {
+            Some(members) => members.intersection(set),
+            None => *set,
+        });
+        self
+    }
+
+    /// Never list the jurisdictions.
+    pub fn exclude<I, J>(mut self, jurisdictions: I) -> JurisdictionQuery
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<Jurisdiction>,
+    {
+        for jurisdiction in jurisdictions {
+            self.excluded.insert(jurisdiction.into());
+        }
+        self
+    }
+
+    /// List the jurisdictions ordered by their english name, see
+    /// [all_sorted_by_name](struct.Jurisdiction.html#method.all_sorted_by_name).
+    pub fn sort_by_name(mut self) -> JurisdictionQuery {
+        self.by_name = true;
+        self
+    }
+
+    /// Return whether the jurisdiction passes every filter of the query.
+    pub fn matches(&self, jurisdiction: &Jurisdiction) -> bool {
+        #[cfg(feature = "region")]
+        if let Some(regions) = &self.regions {
+            if !regions.contains(&jurisdiction.region()) {
+                return false;
+            }
+        }
+        if let Some(members) = &self.members {
+            if !members.contains(jurisdiction) {
+                return false;
+            }
+        }
+        !self.excluded.contains(jurisdiction)
+    }
+
+    /// Iterate the jurisdictions passing every filter of the query.
+    pub fn iter(&self) -> impl Iterator<Item = Jurisdiction> + '_ {
+        let all: Box<dyn Iterator<Item = Jurisdiction>> = if self.by_name {
+            Box::new(ALL_BY_NAME.iter().cloned())
+        } else {
+            Box::new(Jurisdiction::all())
+        };
+        all.filter(move |jurisdiction| self.matches(jurisdiction))
+    }
+
+    /// Collect the jurisdictions passing every filter of the query.
+    pub fn collect<B>(&self) -> B
+    where
+        B: FromIterator<Jurisdiction>,
+    {
+        self.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    fn codes(query: &JurisdictionQuery) -> Vec<Alpha2> {
+        query
+            .iter()
+            .map(|jurisdiction| jurisdiction.alpha2())
+            .collect()
+    }
+
+    #[test]
+    fn test_query_all() {
+        assert!(JurisdictionQuery::new().iter().eq(Jurisdiction::all()));
+        let by_name = JurisdictionQuery::new().sort_by_name();
+        assert!(by_name.iter().eq(ALL_BY_NAME.iter().cloned()));
+    }
+
+    #[test]
+    fn test_query_exclude() {
+        let query = Jurisdiction::query().exclude([Alpha2::NO, Alpha2::SE]);
+        assert_eq!(query.iter().count(), Jurisdiction::all().count() - 2);
+        assert!(!query.matches(&Alpha2::NO.into()));
+        assert!(query.matches(&Alpha2::DK.into()));
+    }
+
+    #[test]
+    fn test_query_member_of() {
+        const NORDICS: JurisdictionSet = JurisdictionSet::from_alpha2(&[
+            Alpha2::DK,
+            Alpha2::FI,
+            Alpha2::IS,
+            Alpha2::NO,
+            Alpha2::SE,
+        ]);
+        const NORTH_SEA: JurisdictionSet =
+            JurisdictionSet::from_alpha2(&[Alpha2::DK, Alpha2::NO, Alpha2::GB, Alpha2::NL]);
+        let query = Jurisdiction::query()
+            .member_of(&NORDICS)
+            .member_of(&NORTH_SEA);
+        assert_eq!(codes(&query), [Alpha2::DK, Alpha2::NO]);
+        let query = query.exclude([Alpha2::NO]);
+        assert_eq!(codes(&query), [Alpha2::DK]);
+        let query = query.exclude([Alpha2::DK]);
+        assert!(codes(&query).is_empty());
+    }
+
+    #[test]
+    fn test_query_sort_by_name() {
+        let set = JurisdictionSet::from_alpha2(&[Alpha2::UM, Alpha2::US]);
+        let query = Jurisdiction::query().member_of(&set);
+        // ISO 3166-1 orders the United States of America before its minor outlying islands
+        assert_eq!(codes(&query), [Alpha2::US, Alpha2::UM]);
+        let query = query.sort_by_name();
+        assert_eq!(codes(&query), [Alpha2::UM, Alpha2::US]);
+        let set = JurisdictionSet::from_alpha2(&[Alpha2::NO, Alpha2::DE, Alpha2::SE]);
+        let query = Jurisdiction::query().member_of(&set).sort_by_name();
+        assert_eq!(codes(&query), [Alpha2::DE, Alpha2::NO, Alpha2::SE]);
+    }
+
+    #[cfg(feature = "region")]
+    #[test]
+    fn test_query_region() {
+        let query = Jurisdiction::query().region(Region::Oceania);
+        assert!(query.iter().all(|j| j.region() == Region::Oceania));
+        assert_eq!(query.iter().count(), Region::Oceania.jurisdiction_count());
+
+        let query = Jurisdiction::query().any_region([Region::Europe, Region::Asia]);
+        assert_eq!(
+            query.iter().count(),
+            Region::Europe.jurisdiction_count() + Region::Asia.jurisdiction_count()
+        );
+        let query = query.region(Region::Asia);
+        assert_eq!(query.iter().count(), Region::Asia.jurisdiction_count());
+        let query = query.region(Region::Europe);
+        assert!(codes(&query).is_empty());
+        assert!(codes(&Jurisdiction::query().any_region(None)).is_empty());
+    }
+
+    #[cfg(all(feature = "political", feature = "region"))]
+    #[test]
+    fn test_query_composition() {
+        let query = Jurisdiction::query()
+            .region(Region::Europe)
+            .member_of(&crate::sets::EU)
+            .exclude([Alpha2::DE, Alpha2::FR])
+            .sort_by_name();
+        let found = codes(&query);
+        // Cyprus is within Asia
+        assert_eq!(found.len(), crate::sets::EU.len() - 3);
+        assert!(!found.contains(&Alpha2::CY));
+        assert_eq!(found[..3], [Alpha2::AT, Alpha2::BE, Alpha2::BG]);
+        assert!(!found.contains(&Alpha2::DE));
+        assert!(!found.contains(&Alpha2::NO));
+        let names: Vec<_> = query.iter().map(|j| j.name_ascii().to_string()).collect();
+        assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
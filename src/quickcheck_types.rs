@@ -0,0 +1,104 @@
+//! [quickcheck] support.
+//!
+//! Values are chosen uniformly from the valid values of each type, and shrink toward
+//! lower numeric country codes, down to Afghanistan (`004`).
+//!
+//! [quickcheck]: https://docs.rs/quickcheck
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::generated::definition::GENERATED_DEFINITIONS;
+use crate::Jurisdiction;
+
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for Jurisdiction {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let definition = g
+            .choose(&GENERATED_DEFINITIONS[..])
+            .expect("definitions are not empty");
+        Jurisdiction::from_definition(definition)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut lower: Vec<Jurisdiction> = Jurisdiction::all()
+            .filter(|jurisdiction| jurisdiction.country_code() < self.country_code())
+            .collect();
+        lower.sort_by_key(Jurisdiction::country_code);
+        // Shrink the rank of this jurisdiction in numeric order as an integer
+        let rank = lower.len();
+        Box::new(rank.shrink().map(move |rank| lower[rank].clone()))
+    }
+}
+
+impl Arbitrary for Alpha2 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Jurisdiction::arbitrary(g).alpha2()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(Jurisdiction::from(*self).shrink().map(|j| j.alpha2()))
+    }
+}
+
+impl Arbitrary for Alpha3 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Jurisdiction::arbitrary(g).alpha3()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(Jurisdiction::from(*self).shrink().map(|j| j.alpha3()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quickcheck::quickcheck;
+    use std::str::FromStr;
+
+    quickcheck! {
+        fn test_quickcheck_parse_alpha2(jurisdiction: Jurisdiction) -> bool {
+            Jurisdiction::from_str(&jurisdiction.alpha2().to_string()).ok() == Some(jurisdiction)
+        }
+
+        fn test_quickcheck_alpha_codes(alpha2: Alpha2, alpha3: Alpha3) -> bool {
+            Jurisdiction::from(alpha2).alpha2() == alpha2
+                && Jurisdiction::from(alpha3).alpha3() == alpha3
+        }
+
+        fn test_quickcheck_shrink_lower(jurisdiction: Jurisdiction) -> bool {
+            jurisdiction
+                .shrink()
+                .all(|shrunk| shrunk.country_code() < jurisdiction.country_code())
+        }
+    }
+
+    #[test]
+    fn test_quickcheck_shrink() {
+        let afghanistan = Jurisdiction::from(Alpha2::AF);
+        assert_eq!(afghanistan.country_code(), 4);
+        assert_eq!(afghanistan.shrink().count(), 0);
+
+        let norway = Jurisdiction::from(Alpha2::NO);
+        let shrunk: Vec<Jurisdiction> = norway.shrink().collect();
+        assert_eq!(shrunk.first(), Some(&afghanistan));
+        assert!(shrunk.len() < 16, "{:?}", shrunk);
+        assert_eq!(Alpha3::NOR.shrink().next(), Some(Alpha3::AFG));
+    }
+
+    #[test]
+    fn test_quickcheck_shrink_failing_property() {
+        // Shrinking finds the lowest numeric country code failing the property
+        let result = std::panic::catch_unwind(|| {
+            fn below_578(jurisdiction: Jurisdiction) -> bool {
+                jurisdiction.country_code() < 578
+            }
+            quickcheck::QuickCheck::new()
+                .tests(1_000)
+                .quickcheck(below_578 as fn(Jurisdiction) -> bool);
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("Norway"), "{}", message);
+    }
+}
@@ -0,0 +1,105 @@
+//! ITU call sign series.
+//!
+//! The International Telecommunication Union allocates the series of call signs to its
+//! member states, such that the prefix of an amateur radio call sign identifies the
+//! jurisdiction that issued it, such as `LA1ABC` issued by Norway or `W1AW` by the
+//! United States. The series are allocated in blocks of prefixes of one to three
+//! characters, e.g. `LA`–`LN` for Norway, the entire `W` series for the United States,
+//! or `SSA`–`SSM` for Egypt, while `SSN`–`SSZ` are allocated to Sudan.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! let issuer = jurisdiction::radio::from_callsign("LA1ABC").unwrap();
+//! assert_eq!(issuer, Alpha2::NO);
+//! ```
+//!
+//! Note that the series are allocated to the member state administering them, such that
+//! call signs of dependent territories resolve to their sovereign, and that series
+//! allocated to international organisations such as the United Nations are not included.
+
+use crate::generated::radio::GENERATED_CALLSIGN_SERIES;
+use crate::Jurisdiction;
+
+/// Return the [Jurisdiction] allocated the call sign series of a call sign.
+///
+/// The call sign is matched from its start, ignoring ASCII case, against the longest
+/// series it falls within. Call signs made up of only a series prefix, or starting with
+/// a series not allocated to a jurisdiction, return `None`. Portable designators, such
+/// as `LA/G4ABC`, are not interpreted.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn from_callsign(callsign: &str) -> Option<Jurisdiction> {
+    // The longest series are three characters
+    let mut prefix = [0u8; 3];
+    for (prefix, byte) in prefix.iter_mut().zip(callsign.bytes()) {
+        if !byte.is_ascii_alphanumeric() {
+            return None;
+        }
+        *prefix = byte.to_ascii_uppercase();
+    }
+
+    // The series are ordered by decreasing length, so the first match is the longest
+    GENERATED_CALLSIGN_SERIES
+        .iter()
+        .find(|(series, _)| {
+            let n = series.start().len();
+            // Within each position, a series spans either letters or digits, such that
+            // the prefixes within the series compare bytewise between its bounds
+            callsign.len() > n
+                && series.start().as_bytes() <= &prefix[..n]
+                && &prefix[..n] <= series.end().as_bytes()
+        })
+        .map(|(_, alpha2)| Jurisdiction::from(*alpha2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_radio_from_callsign() {
+        assert_eq!(from_callsign("LA1ABC"), Some(Alpha2::NO.into()));
+        assert_eq!(from_callsign("W1AW"), Some(Alpha2::US.into()));
+        assert_eq!(from_callsign("k2abc"), Some(Alpha2::US.into()));
+        assert_eq!(from_callsign("DL1ABC"), Some(Alpha2::DE.into()));
+        assert_eq!(from_callsign("G4ABC"), Some(Alpha2::GB.into()));
+        assert_eq!(from_callsign("JA1XYZ"), Some(Alpha2::JP.into()));
+        assert_eq!(from_callsign("9V1AB"), Some(Alpha2::SG.into()));
+    }
+
+    #[test]
+    fn test_radio_range_boundaries() {
+        assert_eq!(from_callsign("LN2A"), Some(Alpha2::NO.into()));
+        assert_eq!(from_callsign("LO2A"), Some(Alpha2::AR.into()));
+        assert_eq!(from_callsign("SSM1A"), Some(Alpha2::EG.into()));
+        assert_eq!(from_callsign("SSN1A"), Some(Alpha2::SD.into()));
+        assert_eq!(from_callsign("3DA0AB"), Some(Alpha2::SZ.into()));
+        assert_eq!(from_callsign("3DN1AB"), Some(Alpha2::FJ.into()));
+    }
+
+    #[test]
+    fn test_radio_callsign_prefixes() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(norway.callsign_prefixes().contains(&("LA"..="LN")));
+        assert!(norway.callsign_prefixes().contains(&("3Y"..="3Y")));
+        let us = Jurisdiction::from(Alpha2::US);
+        assert!(us.callsign_prefixes().contains(&("W"..="W")));
+        assert!(Jurisdiction::from(Alpha2::AQ)
+            .callsign_prefixes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_radio_unallocated() {
+        // The Q series is reserved for Q codes, and 4U allocated to the United Nations
+        assert_eq!(from_callsign("QA1ABC"), None);
+        assert_eq!(from_callsign("4U1UN"), None);
+        assert_eq!(from_callsign("1A0KM"), None);
+        assert_eq!(from_callsign("W"), None);
+        assert_eq!(from_callsign("LA"), None);
+        assert_eq!(from_callsign(""), None);
+        assert_eq!(from_callsign("/LA1ABC"), None);
+        assert_eq!(from_callsign("ÑA1ABC"), None);
+    }
+}
@@ -0,0 +1,56 @@
+//! UIC railway country codes.
+//!
+//! The International Union of Railways (UIC) assigns a two-digit numerical code to the
+//! countries of its members, embedded in the numbers of wagons, locomotives and railway
+//! undertakings exchanged in international traffic, such as `76` for Norway or `80` for
+//! Germany. Codes are only assigned to countries taking part in the interchange, which
+//! are mostly found in Europe, North Africa and Asia.
+//!
+//! ```rust
+//! # use jurisdiction::{Alpha2, Jurisdiction};
+//! assert_eq!(Jurisdiction::from(Alpha2::NO).uic_code(), Some(76));
+//! assert_eq!(jurisdiction::rail::from_uic(80).unwrap(), Alpha2::DE);
+//! ```
+
+use crate::Jurisdiction;
+
+/// Return the [Jurisdiction] assigned the UIC railway country code, if any.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn from_uic(code: u8) -> Option<Jurisdiction> {
+    Jurisdiction::all().find(|jurisdiction| jurisdiction.uic_code() == Some(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_rail_uic_code() {
+        assert_eq!(Jurisdiction::from(Alpha2::NO).uic_code(), Some(76));
+        assert_eq!(Jurisdiction::from(Alpha2::DE).uic_code(), Some(80));
+        assert_eq!(Jurisdiction::from(Alpha2::US).uic_code(), None);
+        assert_eq!(Jurisdiction::from(Alpha2::BR).uic_code(), None);
+    }
+
+    #[test]
+    fn test_rail_from_uic() {
+        assert_eq!(from_uic(76), Some(Alpha2::NO.into()));
+        assert_eq!(from_uic(80), Some(Alpha2::DE.into()));
+        assert_eq!(from_uic(10), Some(Alpha2::FI.into()));
+        // Unassigned codes
+        assert_eq!(from_uic(0), None);
+        assert_eq!(from_uic(63), None);
+        assert_eq!(from_uic(100), None);
+    }
+
+    #[test]
+    fn test_rail_round_trip() {
+        for jurisdiction in Jurisdiction::all() {
+            if let Some(code) = jurisdiction.uic_code() {
+                assert_eq!(from_uic(code), Some(jurisdiction));
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! [rand] support.
+//!
+//! The `Standard` distribution samples jurisdictions uniformly from the static definition
+//! table, such that seeded random number generators reproduce the same jurisdictions.
+//!
+//! ```rust
+//! use jurisdiction::Jurisdiction;
+//! use rand::rngs::StdRng;
+//! use rand::{Rng, SeedableRng};
+//!
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let customers: Vec<Jurisdiction> = (&mut rng).sample_iter(rand::distributions::Standard)
+//!     .take(10)
+//!     .collect();
+//! assert_eq!(customers.len(), 10);
+//! ```
+//!
+//! [rand]: https://docs.rs/rand
+
+use crate::generated::definition::GENERATED_DEFINITIONS;
+use crate::Jurisdiction;
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+impl Distribution<Jurisdiction> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Jurisdiction {
+        let index = rng.gen_range(0..GENERATED_DEFINITIONS.len());
+        Jurisdiction::from_index(index).expect("index below the number of jurisdictions")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn sample(seed: u64, count: usize) -> Vec<Jurisdiction> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count).map(|_| Jurisdiction::random(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_random_reproducible() {
+        assert_eq!(sample(42, 100), sample(42, 100));
+        assert_ne!(sample(42, 100), sample(43, 100));
+    }
+
+    #[test]
+    fn test_random_covers_definitions() {
+        let mut seen = vec![0usize; GENERATED_DEFINITIONS.len()];
+        for jurisdiction in sample(1, 10_000) {
+            seen[jurisdiction.index()] += 1;
+        }
+        // Each of the ~250 jurisdictions is expected about 40 times
+        assert!(seen.iter().all(|&count| count > 0), "{:?}", seen);
+        assert!(seen.iter().all(|&count| count < 100), "{:?}", seen);
+    }
+
+    #[test]
+    #[cfg(feature = "region")]
+    fn test_random_regions() {
+        use crate::region::Region;
+        use std::collections::BTreeSet;
+
+        let regions: BTreeSet<Region> = sample(7, 5_000).iter().map(|j| j.region()).collect();
+        assert!(regions.len() >= 5, "{:?}", regions);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1_000 {
+            let jurisdiction = Jurisdiction::random_in_region(Region::Europe, &mut rng).unwrap();
+            assert_eq!(jurisdiction.region(), Region::Europe);
+        }
+    }
+}
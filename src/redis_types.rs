@@ -0,0 +1,195 @@
+//! [redis-rs] support.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are written as arguments of their alpha code,
+//! where a `Jurisdiction` is written as its alpha2 code, such that they are usable both as
+//! keys and as values. Each is read back from either an alpha2 or an alpha3 code, or a
+//! numeric country code, whether replied as an integer or as a string of digits. Replies
+//! that do not identify a jurisdiction, including nil, fail to convert; read an `Option`
+//! to accept nil.
+//!
+//! ```rust,no_run
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use redis::Commands;
+//!
+//! # fn main() -> redis::RedisResult<()> {
+//! # let mut con: redis::Connection = unimplemented!();
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! con.set::<_, _, ()>("customer:orca-labs:country", norway)?;
+//! let country: Option<Jurisdiction> = con.get("customer:orca-labs:country")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [redis-rs]: https://docs.rs/redis
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{jurisdiction_from_sql, jurisdiction_from_sql_numeric};
+use crate::Jurisdiction;
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use std::str::{self, FromStr};
+
+/// Resolve a replied alpha2 code, alpha3 code or numeric country code.
+fn jurisdiction_from_value(value: &Value) -> Result<Jurisdiction, ParsingError> {
+    let text = match value {
+        Value::Int(cc) => return jurisdiction_from_numeric(*cc),
+        Value::BulkString(bytes) => str::from_utf8(bytes)?,
+        Value::SimpleString(text) | Value::VerbatimString { text, .. } => text.as_str(),
+        Value::Nil => return Err("unexpected nil, expected a jurisdiction code".into()),
+        value => {
+            return Err(format!("{:?} is not a jurisdiction code", value).into());
+        }
+    };
+    match i64::from_str(text) {
+        Ok(cc) => jurisdiction_from_numeric(cc),
+        Err(_) => jurisdiction_from_sql(text).map_err(|err| err.to_string().into()),
+    }
+}
+
+fn jurisdiction_from_numeric(cc: i64) -> Result<Jurisdiction, ParsingError> {
+    jurisdiction_from_sql_numeric(cc).map_err(|err| err.to_string().into())
+}
+
+impl ToRedisArgs for Alpha2 {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.to_string().as_bytes())
+    }
+}
+
+impl ToSingleRedisArg for Alpha2 {}
+
+impl FromRedisValue for Alpha2 {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        jurisdiction_from_value(v).map(|jurisdiction| jurisdiction.alpha2())
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
+
+impl ToRedisArgs for Alpha3 {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.to_string().as_bytes())
+    }
+}
+
+impl ToSingleRedisArg for Alpha3 {}
+
+impl FromRedisValue for Alpha3 {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        jurisdiction_from_value(v).map(|jurisdiction| jurisdiction.alpha3())
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
+
+impl ToRedisArgs for Jurisdiction {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        self.alpha2().write_redis_args(out)
+    }
+}
+
+impl ToSingleRedisArg for Jurisdiction {}
+
+impl FromRedisValue for Jurisdiction {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        jurisdiction_from_value(v)
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(s: &str) -> Value {
+        Value::BulkString(s.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_redis_args() {
+        let norway = Jurisdiction::from(Alpha3::NOR);
+        assert_eq!(norway.to_redis_args(), vec![b"NO".to_vec()]);
+        assert_eq!(Alpha3::NOR.to_redis_args(), vec![b"NOR".to_vec()]);
+        // Composite keys, e.g. of HSET
+        assert_eq!(
+            ("currency", norway).to_redis_args(),
+            vec![b"currency".to_vec(), b"NO".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_redis_bulk_string() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(Jurisdiction::from_redis_value(bulk("NO")).unwrap(), norway);
+        assert_eq!(Jurisdiction::from_redis_value(bulk("NOR")).unwrap(), norway);
+        assert_eq!(Jurisdiction::from_redis_value(bulk("578")).unwrap(), norway);
+        assert_eq!(Alpha3::from_redis_value(bulk("NO")).unwrap(), Alpha3::NOR);
+        assert_eq!(
+            Alpha2::from_redis_value(Value::SimpleString("SE".to_string())).unwrap(),
+            Alpha2::SE
+        );
+    }
+
+    #[test]
+    fn test_redis_int() {
+        assert_eq!(
+            Jurisdiction::from_redis_value(Value::Int(578)).unwrap(),
+            Alpha2::NO
+        );
+        assert_eq!(
+            Alpha2::from_redis_value(Value::Int(752)).unwrap(),
+            Alpha2::SE
+        );
+    }
+
+    #[test]
+    fn test_redis_nil() {
+        let err = Jurisdiction::from_redis_value(Value::Nil).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible type - unexpected nil, expected a jurisdiction code"
+        );
+        assert_eq!(
+            Option::<Jurisdiction>::from_redis_value(Value::Nil).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_redis_unknown() {
+        let err = Jurisdiction::from_redis_value(bulk("XX")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Incompatible type - unrecognized jurisdiction code: "XX""#
+        );
+        let err = Jurisdiction::from_redis_value(Value::Int(0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"Incompatible type - unrecognized numeric country code: "0""#
+        );
+        let err = Jurisdiction::from_redis_value(Value::Boolean(true)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incompatible type - boolean(true) is not a jurisdiction code"
+        );
+        let err = redis::RedisError::from(Alpha2::from_redis_value(bulk("xx")).unwrap_err());
+        assert!(err.to_string().contains("xx"), "{}", err);
+    }
+}
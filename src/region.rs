@@ -4,12 +4,42 @@
 //! standard country or area codes for statistical use (M49)
 //! to extract the region definition.
 //!
+//! Each region iterates the jurisdictions zoning to it, sorted by numeric country code,
+//! and tells whether a jurisdiction zones to it by bisecting them:
+//!
+//! ```rust
+//! use jurisdiction::region::Region;
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! assert!(Region::Europe.contains(&norway));
+//! assert!(Region::Europe.into_iter().any(|j| j == norway));
+//! assert_eq!(Region::Europe.into_iter().len(), Region::Europe.jurisdiction_count());
+//! ```
+//!
 //! [UN M49]: https://unstats.un.org/unsd/methodology/m49/overview
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// The high level region a Jurisdiction may zone to.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "juniper", derive(juniper::GraphQLEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "strum",
+    derive(
+        strum::EnumIter,
+        strum::EnumCount,
+        strum::IntoStaticStr,
+        strum::EnumString
+    ),
+    strum(ascii_case_insensitive)
+)]
 #[allow(missing_docs)]
 pub enum Region {
     Africa,
@@ -18,77 +48,356 @@ pub enum Region {
     Oceania,
     Americas,
 
+    /// Outside of any region, as M49 places Antarctica directly beneath the world.
     #[serde(other)]
     Undefined,
 }
 
 /// A subdivision within a [Region](enum.Region.html).
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "juniper", derive(juniper::GraphQLEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "strum",
+    derive(
+        strum::EnumIter,
+        strum::EnumCount,
+        strum::IntoStaticStr,
+        strum::EnumString
+    ),
+    strum(ascii_case_insensitive)
+)]
 #[allow(missing_docs)]
 pub enum SubRegion {
     // Africa
     #[serde(rename = "Northern Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Northern Africa"))]
     NorthernAfrica,
     #[serde(rename = "Sub-Saharan Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Sub-Saharan Africa"))]
     SubSaharanAfrica,
     // Asia
     #[serde(rename = "Eastern Asia")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Eastern Asia"))]
     EasternAsia,
     #[serde(rename = "Southern Asia")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Southern Asia"))]
     SouthernAsia,
     #[serde(rename = "South-eastern Asia")]
+    #[cfg_attr(feature = "strum", strum(serialize = "South-eastern Asia"))]
     SouthEasternAsia,
     #[serde(rename = "Western Asia")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Western Asia"))]
     WesternAsia,
     #[serde(rename = "Central Asia")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Central Asia"))]
     CentralAsia,
     // America
     #[serde(rename = "Northern America")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Northern America"))]
     NorthernAmerica,
     #[serde(rename = "Latin America and the Caribbean")]
+    #[cfg_attr(
+        feature = "strum",
+        strum(serialize = "Latin America and the Caribbean")
+    )]
     LatinAmericaAndTheCaribbean,
     // Europa
     #[serde(rename = "Northern Europe")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Northern Europe"))]
     NorthernEurope,
     #[serde(rename = "Eastern Europe")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Eastern Europe"))]
     EasternEurope,
     #[serde(rename = "Southern Europe")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Southern Europe"))]
     SouthernEurope,
     #[serde(rename = "Western Europe")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Western Europe"))]
     WesternEurope,
     // Oceania
     Polynesia,
     Melanesia,
     Micronesia,
     #[serde(rename = "Australia and New Zealand")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Australia and New Zealand"))]
     AustraliaAndNewZealand,
 
     #[serde(other)]
     Undefined,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "async-graphql", derive(async_graphql::Enum))]
+#[cfg_attr(feature = "juniper", derive(juniper::GraphQLEnum))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(
+    feature = "strum",
+    derive(
+        strum::EnumIter,
+        strum::EnumCount,
+        strum::IntoStaticStr,
+        strum::EnumString
+    ),
+    strum(ascii_case_insensitive)
+)]
 #[allow(missing_docs)]
 pub enum IntermediateRegion {
     // Africa
     #[serde(rename = "Eastern Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Eastern Africa"))]
     EasternAfrica,
     #[serde(rename = "Middle Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Middle Africa"))]
     MiddleAfrica,
     #[serde(rename = "Southern Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Southern Africa"))]
     SouthernAfrica,
     #[serde(rename = "Western Africa")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Western Africa"))]
     WesternAfrica,
     // America
     Caribbean,
     #[serde(rename = "Central America")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Central America"))]
     CentralAmerica,
     #[serde(rename = "South America")]
+    #[cfg_attr(feature = "strum", strum(serialize = "South America"))]
     SouthAmerica,
     // Europe,
     #[serde(rename = "Channel Islands")]
+    #[cfg_attr(feature = "strum", strum(serialize = "Channel Islands"))]
     ChannelIslands,
 
     #[serde(other)]
     Undefined,
 }
+
+impl Region {
+    /// The names of the regions, as they are serialized.
+    pub(crate) const NAMES: &'static [&'static str] = &[
+        "Africa",
+        "Asia",
+        "Europe",
+        "Oceania",
+        "Americas",
+        "Undefined",
+    ];
+}
+
+impl SubRegion {
+    /// The names of the sub regions, as they are serialized.
+    pub(crate) const NAMES: &'static [&'static str] = &[
+        "Northern Africa",
+        "Sub-Saharan Africa",
+        "Eastern Asia",
+        "Southern Asia",
+        "South-eastern Asia",
+        "Western Asia",
+        "Central Asia",
+        "Northern America",
+        "Latin America and the Caribbean",
+        "Northern Europe",
+        "Eastern Europe",
+        "Southern Europe",
+        "Western Europe",
+        "Polynesia",
+        "Melanesia",
+        "Micronesia",
+        "Australia and New Zealand",
+        "Undefined",
+    ];
+}
+
+impl IntermediateRegion {
+    /// The names of the intermediate regions, as they are serialized.
+    pub(crate) const NAMES: &'static [&'static str] = &[
+        "Eastern Africa",
+        "Middle Africa",
+        "Southern Africa",
+        "Western Africa",
+        "Caribbean",
+        "Central America",
+        "South America",
+        "Channel Islands",
+        "Undefined",
+    ];
+}
+
+/// The position of a jurisdiction within the region hierarchy, from its [Region] down to
+/// its [IntermediateRegion], if any.
+///
+/// A path is displayed as the names of its regions joined by slashes, and parsed back
+/// from the same form:
+///
+/// ```rust
+/// use jurisdiction::region::{RegionPath, IntermediateRegion};
+/// use jurisdiction::{Alpha2, Jurisdiction};
+///
+/// let path = Jurisdiction::from(Alpha2::NO).region_path();
+/// assert_eq!(path.to_string(), "Europe/Northern Europe");
+///
+/// let path: RegionPath = "Europe/Northern Europe/Channel Islands".parse().unwrap();
+/// assert_eq!(path.intermediate_region, Some(IntermediateRegion::ChannelIslands));
+/// ```
+///
+/// [Region]: enum.Region.html
+/// [IntermediateRegion]: enum.IntermediateRegion.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RegionPath {
+    /// The region of the path.
+    pub region: Region,
+    /// The sub region of the path.
+    pub sub_region: SubRegion,
+    /// The intermediate region of the path, present only for sub regions subdivided
+    /// further.
+    pub intermediate_region: Option<IntermediateRegion>,
+}
+
+/// An error which can be returned when parsing a [RegionPath](struct.RegionPath.html).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseRegionPathError {
+    /// The input does not consist of two or three segments separated by slashes.
+    Segments(String),
+    /// The first segment is not the name of a region.
+    Region(String),
+    /// The second segment is not the name of a sub region.
+    SubRegion(String),
+    /// The third segment is not the name of an intermediate region.
+    IntermediateRegion(String),
+}
+
+impl fmt::Display for ParseRegionPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRegionPathError::Segments(s) => {
+                write!(f, "expected two or three region path segments: {:?}", s)
+            }
+            ParseRegionPathError::Region(s) => write!(f, "unrecognized region: {:?}", s),
+            ParseRegionPathError::SubRegion(s) => write!(f, "unrecognized sub region: {:?}", s),
+            ParseRegionPathError::IntermediateRegion(s) => {
+                write!(f, "unrecognized intermediate region: {:?}", s)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseRegionPathError {}
+
+/// Parse the name of a region classification, rejecting names that are unknown to it
+/// rather than deserializing them as its `undefined` variant.
+pub(crate) fn parse_segment<T>(segment: &str, undefined: T) -> Option<T>
+where
+    T: for<'de> Deserialize<'de> + PartialEq,
+{
+    serde_plain::from_str(segment)
+        .ok()
+        .filter(|region| *region != undefined || segment == "Undefined")
+}
+
+/// Return the name of a region classification.
+fn segment<T: Serialize>(region: &T) -> Result<String, fmt::Error> {
+    serde_plain::to_string(region).map_err(|_| fmt::Error)
+}
+
+impl fmt::Display for RegionPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}",
+            segment(&self.region)?,
+            segment(&self.sub_region)?
+        )?;
+        if let Some(intermediate_region) = &self.intermediate_region {
+            write!(f, "/{}", segment(intermediate_region)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RegionPath {
+    type Err = ParseRegionPathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = s.split('/').collect();
+        let (region, sub_region, intermediate_region) = match segments[..] {
+            [region, sub_region] => (region, sub_region, None),
+            [region, sub_region, intermediate_region] => {
+                (region, sub_region, Some(intermediate_region))
+            }
+            _ => return Err(ParseRegionPathError::Segments(s.to_string())),
+        };
+        Ok(RegionPath {
+            region: parse_segment(region, Region::Undefined)
+                .ok_or_else(|| ParseRegionPathError::Region(region.to_string()))?,
+            sub_region: parse_segment(sub_region, SubRegion::Undefined)
+                .ok_or_else(|| ParseRegionPathError::SubRegion(sub_region.to_string()))?,
+            intermediate_region: intermediate_region
+                .map(|segment| {
+                    parse_segment(segment, IntermediateRegion::Undefined).ok_or_else(|| {
+                        ParseRegionPathError::IntermediateRegion(segment.to_string())
+                    })
+                })
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "strum"))]
+mod tests {
+    use super::*;
+
+    use strum::IntoEnumIterator;
+
+    fn assert_strum_agrees_with_serde<T>()
+    where
+        T: IntoEnumIterator + Into<&'static str> + FromStr + Serialize + PartialEq + Copy,
+        T: std::fmt::Debug,
+        <T as FromStr>::Err: std::fmt::Debug,
+    {
+        for variant in T::iter() {
+            let name: &'static str = variant.into();
+            assert_eq!(name, serde_plain::to_string(&variant).unwrap());
+            assert_eq!(T::from_str(name).unwrap(), variant);
+            assert_eq!(T::from_str(&name.to_lowercase()).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn test_strum_region_names() {
+        assert_strum_agrees_with_serde::<Region>();
+        assert_strum_agrees_with_serde::<SubRegion>();
+        assert_strum_agrees_with_serde::<IntermediateRegion>();
+    }
+
+    fn assert_names_agree_with_serde<T>(names: &[&str])
+    where
+        T: IntoEnumIterator + strum::EnumCount + Serialize,
+    {
+        assert_eq!(names.len(), T::COUNT);
+        for (variant, name) in T::iter().zip(names) {
+            assert_eq!(serde_plain::to_string(&variant).unwrap(), *name);
+        }
+    }
+
+    #[test]
+    fn test_region_names() {
+        assert_names_agree_with_serde::<Region>(Region::NAMES);
+        assert_names_agree_with_serde::<SubRegion>(SubRegion::NAMES);
+        assert_names_agree_with_serde::<IntermediateRegion>(IntermediateRegion::NAMES);
+    }
+
+    #[test]
+    fn test_strum_region_from_str() {
+        assert_eq!(
+            SubRegion::from_str("northern europe"),
+            Ok(SubRegion::NorthernEurope)
+        );
+        assert!(SubRegion::from_str("NorthernEurope").is_err());
+    }
+}
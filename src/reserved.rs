@@ -0,0 +1,183 @@
+//! Exceptionally reserved ISO 3166-1 alpha-2 codes.
+//!
+//! ISO 3166 exceptionally reserves a number of alpha-2 codes on request of national ISO
+//! bodies and international organisations. These codes are not assigned to a jurisdiction,
+//! and are therefore rejected by `Jurisdiction::from_str`, yet they appear in real-world
+//! data, such as `UK` for the United Kingdom or `EU` for VAT registrations under the
+//! EU One-Stop Shop.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! use jurisdiction::reserved::{self, ReservedCode};
+//!
+//! let code = reserved::parse("UK").unwrap();
+//! assert_eq!(code, ReservedCode::UK);
+//! assert_eq!(code.resolve_to_jurisdiction().unwrap(), Alpha2::GB);
+//! ```
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// An exceptionally reserved ISO 3166-1 alpha-2 code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ReservedCode {
+    /// Ascension Island, reserved on request of the Universal Postal Union.
+    AC,
+    /// Clipperton Island, reserved on request of the International Telecommunication Union.
+    CP,
+    /// Diego Garcia, reserved on request of the International Telecommunication Union.
+    DG,
+    /// Ceuta and Melilla, reserved on request of the World Customs Organization.
+    EA,
+    /// The European Union.
+    EU,
+    /// The Eurozone.
+    EZ,
+    /// Metropolitan France.
+    FX,
+    /// The Canary Islands, reserved on request of the World Customs Organization.
+    IC,
+    /// The former Union of Soviet Socialist Republics.
+    SU,
+    /// Tristan da Cunha, reserved on request of the Universal Postal Union.
+    TA,
+    /// The United Kingdom, reserved on request of the United Kingdom.
+    UK,
+    /// The United Nations.
+    UN,
+}
+
+impl ReservedCode {
+    /// Return the code as a string, e.g. `UK`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReservedCode::AC => "AC",
+            ReservedCode::CP => "CP",
+            ReservedCode::DG => "DG",
+            ReservedCode::EA => "EA",
+            ReservedCode::EU => "EU",
+            ReservedCode::EZ => "EZ",
+            ReservedCode::FX => "FX",
+            ReservedCode::IC => "IC",
+            ReservedCode::SU => "SU",
+            ReservedCode::TA => "TA",
+            ReservedCode::UK => "UK",
+            ReservedCode::UN => "UN",
+        }
+    }
+
+    /// Return the [Jurisdiction] the area identified by this code is part of.
+    ///
+    /// Return `None` where the code does not identify a part of a single jurisdiction:
+    /// `EU`, `EZ` and `UN` identify organisations spanning several jurisdictions, and `SU`
+    /// identifies a state that has been dissolved.
+    ///
+    /// [Jurisdiction]: ../struct.Jurisdiction.html
+    pub fn resolve_to_jurisdiction(&self) -> Option<Jurisdiction> {
+        let alpha2 = match self {
+            ReservedCode::AC | ReservedCode::TA => Alpha2::SH,
+            ReservedCode::CP | ReservedCode::FX => Alpha2::FR,
+            ReservedCode::DG => Alpha2::IO,
+            ReservedCode::EA | ReservedCode::IC => Alpha2::ES,
+            ReservedCode::UK => Alpha2::GB,
+            ReservedCode::EU | ReservedCode::EZ | ReservedCode::SU | ReservedCode::UN => {
+                return None
+            }
+        };
+        Some(Jurisdiction::from(alpha2))
+    }
+}
+
+impl fmt::Display for ReservedCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when a string is not an exceptionally reserved code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseReservedCodeError {
+    /// The input is not an exceptionally reserved alpha-2 code.
+    Unknown(String),
+}
+
+impl fmt::Display for ParseReservedCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseReservedCodeError::Unknown(s) => {
+                write!(f, "unrecognized reserved alpha-2 code: {}", s)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseReservedCodeError {}
+
+impl FromStr for ReservedCode {
+    type Err = ParseReservedCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = match s {
+            "AC" => ReservedCode::AC,
+            "CP" => ReservedCode::CP,
+            "DG" => ReservedCode::DG,
+            "EA" => ReservedCode::EA,
+            "EU" => ReservedCode::EU,
+            "EZ" => ReservedCode::EZ,
+            "FX" => ReservedCode::FX,
+            "IC" => ReservedCode::IC,
+            "SU" => ReservedCode::SU,
+            "TA" => ReservedCode::TA,
+            "UK" => ReservedCode::UK,
+            "UN" => ReservedCode::UN,
+            _ => return Err(ParseReservedCodeError::Unknown(s.to_string())),
+        };
+        Ok(code)
+    }
+}
+
+/// Parse an exceptionally reserved alpha-2 code, in uppercase.
+pub fn parse(code: &str) -> Result<ReservedCode, ParseReservedCodeError> {
+    code.parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserved_uk_resolves_to_gb() {
+        let uk = parse("UK").unwrap();
+        assert_eq!(uk, ReservedCode::UK);
+        assert_eq!(uk.resolve_to_jurisdiction().unwrap(), Alpha2::GB);
+    }
+
+    #[test]
+    fn test_reserved_eu_does_not_resolve() {
+        let eu = parse("EU").unwrap();
+        assert_eq!(eu, ReservedCode::EU);
+        assert_eq!(eu.to_string(), "EU");
+        assert_eq!(eu.resolve_to_jurisdiction(), None);
+        assert_eq!(ReservedCode::UN.resolve_to_jurisdiction(), None);
+    }
+
+    #[test]
+    fn test_reserved_strict_jurisdiction_parser() {
+        for code in &["UK", "EU", "UN", "FX"] {
+            assert!(parse(code).is_ok());
+            assert!(Jurisdiction::from_str(code).is_err());
+        }
+    }
+
+    #[test]
+    fn test_reserved_unknown() {
+        assert_eq!(
+            parse("NO"),
+            Err(ParseReservedCodeError::Unknown("NO".to_string()))
+        );
+        assert!(parse("uk").is_err());
+    }
+}
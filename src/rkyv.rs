@@ -0,0 +1,284 @@
+//! [rkyv] zero-copy archiving.
+//!
+//! A [Jurisdiction] is archived as its numeric country code, a little endian `u16`, and
+//! [Alpha2] and [Alpha3] as the ASCII bytes of their code. Unlike the indices of the alpha
+//! enums, both forms are stable across releases of this crate, such that archives remain
+//! readable as jurisdictions are added to ISO 3166-1.
+//!
+//! Validating an archive through `rkyv::access` checks that every archived code is
+//! assigned, such that the archived values always convert back into jurisdictions.
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use rkyv::rancor::Error;
+//!
+//! let countries = vec![Jurisdiction::from(Alpha2::NO), Jurisdiction::from(Alpha2::SE)];
+//! let bytes = rkyv::to_bytes::<Error>(&countries).unwrap();
+//!
+//! let archived = rkyv::access::<rkyv::Archived<Vec<Jurisdiction>>, Error>(&bytes).unwrap();
+//! assert_eq!(archived[0].country_code(), 578);
+//! assert_eq!(archived[1], Jurisdiction::from(Alpha2::SE));
+//! ```
+//!
+//! [rkyv]: https://rkyv.org
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::rancor::{fail, Fallible, Source};
+use rkyv::rend::u16_le;
+use rkyv::traits::NoUndef;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The archived form of a [Jurisdiction](../struct.Jurisdiction.html), its numeric
+/// country code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CheckBytes, Portable)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[rkyv(crate = rkyv)]
+#[repr(transparent)]
+pub struct ArchivedJurisdiction(u16_le);
+
+// SAFETY: The archived jurisdiction is a transparent wrapper of an integer.
+unsafe impl NoUndef for ArchivedJurisdiction {}
+
+impl ArchivedJurisdiction {
+    /// Return the ISO 3166 numeric country code of the archived jurisdiction.
+    pub fn country_code(&self) -> u16 {
+        self.0.to_native()
+    }
+}
+
+impl PartialEq<Jurisdiction> for ArchivedJurisdiction {
+    fn eq(&self, other: &Jurisdiction) -> bool {
+        self.country_code() == other.country_code()
+    }
+}
+
+/// Error returned when validating an archived code that is not assigned to a
+/// jurisdiction.
+#[derive(Debug)]
+struct UnassignedError(String);
+
+impl fmt::Display for UnassignedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "archived code is not assigned to a jurisdiction: {}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnassignedError {}
+
+// SAFETY: Only country codes assigned to a jurisdiction are verified.
+unsafe impl<C: Fallible + ?Sized> Verify<C> for ArchivedJurisdiction
+where
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        if Jurisdiction::try_from(self.country_code()).is_err() {
+            fail!(UnassignedError(format!("{:03}", self.country_code())));
+        }
+        Ok(())
+    }
+}
+
+impl Archive for Jurisdiction {
+    type Archived = ArchivedJurisdiction;
+    type Resolver = ();
+
+    fn resolve(&self, _: (), out: Place<ArchivedJurisdiction>) {
+        out.write(ArchivedJurisdiction(u16_le::from_native(
+            self.country_code(),
+        )));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Jurisdiction {
+    fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Jurisdiction, D> for ArchivedJurisdiction
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Jurisdiction, D::Error> {
+        Jurisdiction::try_from(self.country_code()).map_err(D::Error::new)
+    }
+}
+
+macro_rules! archived_alpha {
+    ($alpha:ident, $method:ident, $archived:ident, $len:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, CheckBytes, Portable)]
+        #[bytecheck(crate = rkyv::bytecheck, verify)]
+        #[rkyv(crate = rkyv)]
+        #[repr(transparent)]
+        pub struct $archived([u8; $len]);
+
+        // SAFETY: The archived code is a transparent wrapper of bytes.
+        unsafe impl NoUndef for $archived {}
+
+        impl $archived {
+            /// Return the archived code.
+            pub fn as_str(&self) -> &str {
+                // Verified to be an assigned code of ASCII letters
+                std::str::from_utf8(&self.0).unwrap_or_default()
+            }
+
+            fn get(&self) -> Option<$alpha> {
+                std::str::from_utf8(&self.0)
+                    .ok()
+                    .and_then(Alpha2::from_code)
+                    .map(|alpha2| Jurisdiction::from(alpha2).$method())
+            }
+        }
+
+        impl PartialEq<$alpha> for $archived {
+            fn eq(&self, other: &$alpha) -> bool {
+                self.get() == Some(*other)
+            }
+        }
+
+        // SAFETY: Only codes assigned to a jurisdiction are verified.
+        unsafe impl<C: Fallible + ?Sized> Verify<C> for $archived
+        where
+            C::Error: Source,
+        {
+            fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+                if self.get().is_none() {
+                    fail!(UnassignedError(format!(
+                        "{:?}",
+                        String::from_utf8_lossy(&self.0)
+                    )));
+                }
+                Ok(())
+            }
+        }
+
+        impl Archive for $alpha {
+            type Archived = $archived;
+            type Resolver = ();
+
+            fn resolve(&self, _: (), out: Place<$archived>) {
+                let mut code = [0; $len];
+                code.copy_from_slice(self.to_string().as_bytes());
+                out.write($archived(code));
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $alpha {
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$alpha, D> for $archived
+        where
+            D::Error: Source,
+        {
+            fn deserialize(&self, _: &mut D) -> Result<$alpha, D::Error> {
+                match self.get() {
+                    Some(alpha) => Ok(alpha),
+                    None => fail!(UnassignedError(format!(
+                        "{:?}",
+                        String::from_utf8_lossy(&self.0)
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+archived_alpha!(
+    Alpha2,
+    alpha2,
+    ArchivedAlpha2,
+    2,
+    "The archived form of an [Alpha2](../enum.Alpha2.html), its two ASCII letters."
+);
+archived_alpha!(
+    Alpha3,
+    alpha3,
+    ArchivedAlpha3,
+    3,
+    "The archived form of an [Alpha3](../enum.Alpha3.html), its three ASCII letters."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rkyv::rancor::Error;
+    use rkyv::Archived;
+
+    #[test]
+    fn test_rkyv_jurisdiction_round_trip() {
+        let jurisdictions: Vec<Jurisdiction> = Jurisdiction::all().collect();
+        let bytes = rkyv::to_bytes::<Error>(&jurisdictions).unwrap();
+        let archived = rkyv::access::<Archived<Vec<Jurisdiction>>, Error>(&bytes).unwrap();
+        for (archived, jurisdiction) in archived.iter().zip(&jurisdictions) {
+            assert_eq!(archived, jurisdiction);
+        }
+        let deserialized = rkyv::deserialize::<Vec<Jurisdiction>, Error>(archived).unwrap();
+        assert_eq!(deserialized, jurisdictions);
+    }
+
+    #[test]
+    fn test_rkyv_alpha_round_trip() {
+        let codes = (Alpha2::NO, Alpha3::SWE);
+        let bytes = rkyv::to_bytes::<Error>(&codes).unwrap();
+        let archived = rkyv::access::<Archived<(Alpha2, Alpha3)>, Error>(&bytes).unwrap();
+        assert_eq!(archived.0.as_str(), "NO");
+        assert_eq!(archived.1.as_str(), "SWE");
+        assert_eq!(archived.0, Alpha2::NO);
+        assert_eq!(
+            rkyv::deserialize::<(Alpha2, Alpha3), Error>(archived).unwrap(),
+            codes
+        );
+    }
+
+    #[test]
+    fn test_rkyv_archived_form() {
+        let bytes = rkyv::to_bytes::<Error>(&Jurisdiction::from(Alpha2::NO)).unwrap();
+        assert_eq!(&bytes[..], &578u16.to_le_bytes());
+        let bytes = rkyv::to_bytes::<Error>(&Alpha3::NOR).unwrap();
+        assert_eq!(&bytes[..], b"NOR");
+    }
+
+    #[test]
+    fn test_rkyv_corrupted() {
+        let mut bytes = rkyv::to_bytes::<Error>(&Jurisdiction::from(Alpha2::NO)).unwrap();
+        bytes.copy_from_slice(&1000u16.to_le_bytes());
+        let err = rkyv::access::<ArchivedJurisdiction, Error>(&bytes).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("archived code is not assigned to a jurisdiction: 1000"));
+
+        let mut bytes = rkyv::to_bytes::<Error>(&Alpha2::NO).unwrap();
+        bytes.copy_from_slice(b"XX");
+        assert!(rkyv::access::<ArchivedAlpha2, Error>(&bytes).is_err());
+        bytes.copy_from_slice(b"no");
+        assert!(rkyv::access::<ArchivedAlpha2, Error>(&bytes).is_err());
+        bytes.copy_from_slice(&[0xff, 0xfe]);
+        assert!(rkyv::access::<ArchivedAlpha2, Error>(&bytes).is_err());
+
+        let mut bytes = rkyv::to_bytes::<Error>(&vec![Jurisdiction::from(Alpha2::NO); 3]).unwrap();
+        let position = bytes
+            .windows(2)
+            .rposition(|w| w == 578u16.to_le_bytes())
+            .unwrap();
+        bytes[position..position + 2].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert!(rkyv::access::<Archived<Vec<Jurisdiction>>, Error>(&bytes).is_err());
+    }
+}
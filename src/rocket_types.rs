@@ -0,0 +1,206 @@
+//! [Rocket] support.
+//!
+//! [Jurisdiction], [Alpha2] and [Alpha3] are usable as the types of path segments, query
+//! parameters and form fields of handlers, e.g. `fn customers(country: Jurisdiction)`, and
+//! as the parameters of the `uri!` macro. A `Jurisdiction` is parsed from either an alpha2
+//! or an alpha3 code, and is displayed in URIs as its alpha2 code.
+//!
+//! Unrecognized codes forward path segments with `422 Unprocessable Entity`, and fail form
+//! fields with a validation error carrying the error message into the form context.
+//!
+//! [Rocket]: https://rocket.rs
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+use anyhow::format_err;
+use rocket::form::{self, FromFormField, ValueField};
+use rocket::http::impl_from_uri_param_identity;
+use rocket::http::uri::fmt::{Formatter, Part, UriDisplay};
+use rocket::request::FromParam;
+
+use std::fmt;
+use std::str::FromStr;
+
+fn alpha2_from_str(s: &str) -> anyhow::Result<Alpha2> {
+    serde_plain::from_str(s).map_err(|_| format_err!("unrecognized alpha2 code: {}", s))
+}
+
+fn alpha3_from_str(s: &str) -> anyhow::Result<Alpha3> {
+    serde_plain::from_str(s).map_err(|_| format_err!("unrecognized alpha3 code: {}", s))
+}
+
+impl<'a> FromParam<'a> for Jurisdiction {
+    type Error = anyhow::Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        Jurisdiction::from_str(param)
+    }
+}
+
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for Jurisdiction {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        Jurisdiction::from_str(field.value)
+            .map_err(|err| form::Error::validation(err.to_string()).into())
+    }
+}
+
+impl<P: Part> UriDisplay<P> for Jurisdiction {
+    fn fmt(&self, f: &mut Formatter<'_, P>) -> fmt::Result {
+        UriDisplay::<P>::fmt(&self.alpha2(), f)
+    }
+}
+
+impl_from_uri_param_identity!(Jurisdiction);
+
+impl<'a> FromParam<'a> for Alpha2 {
+    type Error = anyhow::Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        alpha2_from_str(param)
+    }
+}
+
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for Alpha2 {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        alpha2_from_str(field.value).map_err(|err| form::Error::validation(err.to_string()).into())
+    }
+}
+
+impl<P: Part> UriDisplay<P> for Alpha2 {
+    fn fmt(&self, f: &mut Formatter<'_, P>) -> fmt::Result {
+        f.write_value(self.to_string())
+    }
+}
+
+impl_from_uri_param_identity!(Alpha2);
+
+impl<'a> FromParam<'a> for Alpha3 {
+    type Error = anyhow::Error;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        alpha3_from_str(param)
+    }
+}
+
+#[rocket::async_trait]
+impl<'v> FromFormField<'v> for Alpha3 {
+    fn from_value(field: ValueField<'v>) -> form::Result<'v, Self> {
+        alpha3_from_str(field.value).map_err(|err| form::Error::validation(err.to_string()).into())
+    }
+}
+
+impl<P: Part> UriDisplay<P> for Alpha3 {
+    fn fmt(&self, f: &mut Formatter<'_, P>) -> fmt::Result {
+        f.write_value(self.to_string())
+    }
+}
+
+impl_from_uri_param_identity!(Alpha3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rocket::form::{Context, Contextual, Form};
+    use rocket::http::{ContentType, Status};
+    use rocket::local::blocking::Client;
+    use rocket::{get, post, routes, uri, FromForm};
+
+    #[get("/customers/<country>")]
+    fn customers(country: Jurisdiction) -> String {
+        country.alpha3().to_string()
+    }
+
+    #[get("/alpha/<alpha2>/<alpha3>")]
+    fn alpha(alpha2: Alpha2, alpha3: Alpha3) -> String {
+        format!("{} {}", alpha2, alpha3)
+    }
+
+    #[derive(FromForm)]
+    struct Address {
+        country: Jurisdiction,
+        previous: Option<Alpha2>,
+    }
+
+    #[post("/addresses", data = "<form>")]
+    fn addresses(form: Form<Contextual<'_, Address>>) -> (Status, String) {
+        match &form.value {
+            Some(address) => (
+                Status::Ok,
+                format!("{} {:?}", address.country.alpha2(), address.previous),
+            ),
+            None => (form.context.status(), errors(&form.context)),
+        }
+    }
+
+    fn errors(context: &Context<'_>) -> String {
+        context
+            .errors()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn client() -> Client {
+        Client::tracked(rocket::build().mount("/", routes![customers, alpha, addresses])).unwrap()
+    }
+
+    #[test]
+    fn test_rocket_path_param() {
+        let client = client();
+        let response = client.get("/customers/NO").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "NOR");
+
+        let response = client.get("/alpha/SE/DNK").dispatch();
+        assert_eq!(response.into_string().unwrap(), "SE DNK");
+    }
+
+    #[test]
+    fn test_rocket_invalid_path_param() {
+        let client = client();
+        let response = client.get("/customers/XX").dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        let response = client.get("/alpha/SWE/DNK").dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_rocket_form() {
+        let client = client();
+        let response = client
+            .post("/addresses")
+            .header(ContentType::Form)
+            .body("country=NOR&previous=SE")
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "NO Some(SE)");
+
+        let response = client
+            .post("/addresses")
+            .header(ContentType::Form)
+            .body("country=XX")
+            .dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            response.into_string().unwrap(),
+            "unrecognized ISO 3166 alpha country code: XX"
+        );
+    }
+
+    #[test]
+    fn test_rocket_uri() {
+        let norway = Jurisdiction::from(Alpha3::NOR);
+        assert_eq!(uri!(customers(norway)).to_string(), "/customers/NO");
+        assert_eq!(
+            uri!(alpha(Alpha2::SE, Alpha3::DNK)).to_string(),
+            "/alpha/SE/DNK"
+        );
+    }
+}
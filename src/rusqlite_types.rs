@@ -0,0 +1,172 @@
+//! [rusqlite] support.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are bound as their alpha code, where a
+//! `Jurisdiction` is bound as its alpha2 code. Each is read back from either an alpha2 or
+//! an alpha3 code stored as `TEXT`, or a numeric country code stored as `INTEGER`.
+//! Values that do not identify a jurisdiction, including `NULL`, fail to convert with
+//! `FromSqlError::Other`; read an `Option` to accept `NULL`.
+//!
+//! ```rust,no_run
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use rusqlite::{params, Connection};
+//!
+//! # fn main() -> rusqlite::Result<()> {
+//! let conn = Connection::open("customers.db")?;
+//! conn.execute(
+//!     "INSERT INTO customers (name, country) VALUES (?1, ?2)",
+//!     params!["Orca Labs", Jurisdiction::from(Alpha2::NO)],
+//! )?;
+//! let country: Jurisdiction = conn.query_row(
+//!     "SELECT country FROM customers WHERE name = ?1",
+//!     params!["Orca Labs"],
+//!     |row| row.get(0),
+//! )?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [rusqlite]: https://docs.rs/rusqlite
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{jurisdiction_from_sql, jurisdiction_from_sql_numeric};
+use crate::Jurisdiction;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use std::fmt;
+
+/// Error returned when a jurisdiction is read from a `NULL` value.
+#[derive(Debug)]
+struct NullCodeError;
+
+impl fmt::Display for NullCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unexpected NULL, expected a jurisdiction code")
+    }
+}
+
+impl std::error::Error for NullCodeError {}
+
+/// Resolve a stored alpha2 code, alpha3 code or numeric country code.
+fn jurisdiction_from_value(value: ValueRef<'_>) -> FromSqlResult<Jurisdiction> {
+    match value {
+        ValueRef::Text(_) => jurisdiction_from_sql(value.as_str()?).map_err(FromSqlError::other),
+        ValueRef::Integer(cc) => jurisdiction_from_sql_numeric(cc).map_err(FromSqlError::other),
+        ValueRef::Null => Err(FromSqlError::other(NullCodeError)),
+        _ => Err(FromSqlError::InvalidType),
+    }
+}
+
+impl ToSql for Alpha2 {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for Alpha2 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        jurisdiction_from_value(value).map(|jurisdiction| jurisdiction.alpha2())
+    }
+}
+
+impl ToSql for Alpha3 {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for Alpha3 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        jurisdiction_from_value(value).map(|jurisdiction| jurisdiction.alpha3())
+    }
+}
+
+impl ToSql for Jurisdiction {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.alpha2().to_string()))
+    }
+}
+
+impl FromSql for Jurisdiction {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        jurisdiction_from_value(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rusqlite::{params, Connection};
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE addresses (id INTEGER, country)", [])
+            .unwrap();
+        conn
+    }
+
+    fn country_of<T: FromSql>(conn: &Connection, id: i64) -> rusqlite::Result<T> {
+        conn.query_row(
+            "SELECT country FROM addresses WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )
+    }
+
+    #[test]
+    fn test_rusqlite_round_trip() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO addresses VALUES (1, ?1), (2, ?2), (3, ?3)",
+            params![Jurisdiction::from(Alpha2::NO), Alpha2::SE, Alpha3::DNK],
+        )
+        .unwrap();
+
+        let stored: String = country_of(&conn, 1).unwrap();
+        assert_eq!(stored, "NO");
+        assert_eq!(country_of::<Jurisdiction>(&conn, 1).unwrap(), Alpha2::NO);
+        assert_eq!(country_of::<Alpha2>(&conn, 2).unwrap(), Alpha2::SE);
+        assert_eq!(country_of::<Alpha3>(&conn, 3).unwrap(), Alpha3::DNK);
+    }
+
+    #[test]
+    fn test_rusqlite_column_representations() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO addresses VALUES (1, 'NO'), (2, 'NOR'), (3, 578)",
+            [],
+        )
+        .unwrap();
+
+        for id in 1..=3 {
+            assert_eq!(country_of::<Jurisdiction>(&conn, id).unwrap(), Alpha2::NO);
+            assert_eq!(country_of::<Alpha2>(&conn, id).unwrap(), Alpha2::NO);
+            assert_eq!(country_of::<Alpha3>(&conn, id).unwrap(), Alpha3::NOR);
+        }
+    }
+
+    #[test]
+    fn test_rusqlite_unknown_value() {
+        let conn = connection();
+        conn.execute(
+            "INSERT INTO addresses VALUES (1, 'XX'), (2, 0), (3, NULL), (4, 5.78)",
+            [],
+        )
+        .unwrap();
+
+        let message = |id| match country_of::<Jurisdiction>(&conn, id) {
+            Err(rusqlite::Error::FromSqlConversionFailure(_, _, err)) => err.to_string(),
+            result => panic!("unexpected result: {:?}", result),
+        };
+        assert_eq!(message(1), r#"unrecognized jurisdiction code: "XX""#);
+        assert_eq!(message(2), r#"unrecognized numeric country code: "0""#);
+        assert_eq!(message(3), "unexpected NULL, expected a jurisdiction code");
+        assert!(matches!(
+            country_of::<Jurisdiction>(&conn, 4),
+            Err(rusqlite::Error::InvalidColumnType(..))
+        ));
+
+        assert_eq!(country_of::<Option<Jurisdiction>>(&conn, 3).unwrap(), None);
+    }
+}
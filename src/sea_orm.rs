@@ -0,0 +1,342 @@
+//! [SeaORM] support.
+//!
+//! [Jurisdiction] and [Alpha2] are usable as the column types of entities, stored as their
+//! alpha2 code in a string column. A `Jurisdiction` is read back from either an alpha2 or
+//! an alpha3 code, tolerating the space padding of `CHAR` columns. To store a
+//! jurisdiction as its numeric country code in a `SMALLINT` column instead, use the
+//! [NumericJurisdiction] wrapper as the column type.
+//!
+//! Stored values that do not identify a jurisdiction fail to decode with `DbErr::Type`,
+//! naming the value and the column.
+//!
+//! ```rust
+//! use jurisdiction::sea_orm::NumericJurisdiction;
+//! use jurisdiction::Jurisdiction;
+//! use sea_orm::entity::prelude::*;
+//! # use std::convert::TryInto;
+//!
+//! #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+//! #[sea_orm(table_name = "customers")]
+//! pub struct Model {
+//!     #[sea_orm(primary_key)]
+//!     pub id: i32,
+//!     pub country: Jurisdiction,
+//!     pub billing_country: Option<Jurisdiction>,
+//!     pub country_code: NumericJurisdiction,
+//! }
+//!
+//! #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+//! pub enum Relation {}
+//!
+//! impl ActiveModelBehavior for ActiveModel {}
+//! # fn main() {}
+//! ```
+//!
+//! [SeaORM]: https://www.sea-ql.org/SeaORM/
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+//! [Alpha2]: ../enum.Alpha2.html
+//! [NumericJurisdiction]: struct.NumericJurisdiction.html
+
+use crate::alpha::Alpha2;
+use crate::sql::{alpha2_from_sql, jurisdiction_from_sql, jurisdiction_from_sql_numeric};
+use crate::Jurisdiction;
+
+use sea_orm::sea_query::{
+    ArrayType, ColumnType, Nullable, StringLen, Value, ValueType, ValueTypeErr,
+};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+
+use std::fmt;
+
+/// A [Jurisdiction] stored as its numeric country code in a `SMALLINT` column.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericJurisdiction(pub Jurisdiction);
+
+impl From<Jurisdiction> for NumericJurisdiction {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        NumericJurisdiction(jurisdiction)
+    }
+}
+
+impl From<NumericJurisdiction> for Jurisdiction {
+    fn from(numeric: NumericJurisdiction) -> Self {
+        numeric.0
+    }
+}
+
+/// Map an error decoding a stored value into the error of the column.
+fn column_error<I: ColIdx>(err: impl fmt::Display, index: I) -> TryGetError {
+    let column = match (index.as_str(), index.as_usize()) {
+        (Some(name), _) => format!("{:?}", name),
+        (_, Some(position)) => position.to_string(),
+        _ => format!("{:?}", index),
+    };
+    TryGetError::DbErr(DbErr::Type(format!("{} in column {}", err, column)))
+}
+
+impl From<Alpha2> for Value {
+    fn from(alpha2: Alpha2) -> Self {
+        Value::String(Some(alpha2.to_string()))
+    }
+}
+
+impl ValueType for Alpha2 {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(code)) => alpha2_from_sql(&code).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Alpha2".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(StringLen::N(2))
+    }
+}
+
+impl Nullable for Alpha2 {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl TryGetable for Alpha2 {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let code = String::try_get_by(res, index)?;
+        alpha2_from_sql(&code).map_err(|err| column_error(err, index))
+    }
+}
+
+impl From<Jurisdiction> for Value {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        Value::from(jurisdiction.alpha2())
+    }
+}
+
+impl ValueType for Jurisdiction {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(code)) => jurisdiction_from_sql(&code).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Jurisdiction".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(StringLen::N(2))
+    }
+}
+
+impl Nullable for Jurisdiction {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl TryGetable for Jurisdiction {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let code = String::try_get_by(res, index)?;
+        jurisdiction_from_sql(&code).map_err(|err| column_error(err, index))
+    }
+}
+
+impl From<NumericJurisdiction> for Value {
+    fn from(numeric: NumericJurisdiction) -> Self {
+        // Numeric country codes are made up of three digits
+        Value::SmallInt(Some(numeric.0.country_code() as i16))
+    }
+}
+
+impl ValueType for NumericJurisdiction {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::SmallInt(Some(cc)) => jurisdiction_from_sql_numeric(i64::from(cc))
+                .map(NumericJurisdiction)
+                .map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "NumericJurisdiction".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+}
+
+impl Nullable for NumericJurisdiction {
+    fn null() -> Value {
+        Value::SmallInt(None)
+    }
+}
+
+impl TryGetable for NumericJurisdiction {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let cc = i16::try_get_by(res, index)?;
+        jurisdiction_from_sql_numeric(i64::from(cc))
+            .map(NumericJurisdiction)
+            .map_err(|err| column_error(err, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha3;
+
+    use futures_executor::block_on;
+    use sea_orm::{
+        DatabaseBackend, EntityTrait, MockDatabase, MockExecResult, Set, Statement, Transaction,
+    };
+    use std::collections::BTreeMap;
+
+    mod address {
+        use super::*;
+        use sea_orm::entity::prelude::*;
+        use std::convert::TryInto;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "addresses")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub country: Jurisdiction,
+            pub alpha2: Alpha2,
+            pub previous: Option<Jurisdiction>,
+            pub country_code: NumericJurisdiction,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    fn norway() -> address::Model {
+        address::Model {
+            id: 1,
+            country: Alpha3::NOR.into(),
+            alpha2: Alpha2::SE,
+            previous: None,
+            country_code: Jurisdiction::from(Alpha2::DK).into(),
+        }
+    }
+
+    #[test]
+    fn test_sea_orm_column_types() {
+        use sea_orm::{ColumnTrait, ColumnType};
+
+        assert_eq!(
+            address::Column::Country.def().get_column_type(),
+            &ColumnType::String(StringLen::N(2))
+        );
+        assert_eq!(
+            address::Column::CountryCode.def().get_column_type(),
+            &ColumnType::SmallInteger
+        );
+    }
+
+    #[test]
+    fn test_sea_orm_select() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![norway()]])
+            .into_connection();
+
+        let found = block_on(address::Entity::find_by_id(1).one(&db)).unwrap();
+        assert_eq!(found, Some(norway()));
+    }
+
+    #[test]
+    fn test_sea_orm_select_alpha3() {
+        let row = BTreeMap::from([
+            ("id", Value::Int(Some(1))),
+            ("country", Value::String(Some("NOR".to_string()))),
+            ("alpha2", Value::String(Some("SE".to_string()))),
+            ("previous", Value::String(None)),
+            ("country_code", Value::SmallInt(Some(208))),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![row]])
+            .into_connection();
+
+        let found = block_on(address::Entity::find_by_id(1).one(&db)).unwrap();
+        assert_eq!(found, Some(norway()));
+    }
+
+    #[test]
+    fn test_sea_orm_select_unknown_value() {
+        let row = BTreeMap::from([
+            ("id", Value::Int(Some(1))),
+            ("country", Value::String(Some("XX".to_string()))),
+            ("alpha2", Value::String(Some("SE".to_string()))),
+            ("previous", Value::String(None)),
+            ("country_code", Value::SmallInt(Some(208))),
+        ]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![row]])
+            .into_connection();
+
+        let err = block_on(address::Entity::find_by_id(1).one(&db)).unwrap_err();
+        assert_eq!(
+            err,
+            DbErr::Type(r#"unrecognized jurisdiction code: "XX" in column "country""#.to_string())
+        );
+    }
+
+    #[test]
+    fn test_sea_orm_insert() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let model = address::ActiveModel {
+            id: Set(1),
+            country: Set(Alpha2::NO.into()),
+            alpha2: Set(Alpha2::SE),
+            previous: Set(None),
+            country_code: Set(Jurisdiction::from(Alpha2::DK).into()),
+        };
+        block_on(address::Entity::insert(model).exec_without_returning(&db)).unwrap();
+
+        let log = db.into_transaction_log();
+        assert_eq!(
+            log,
+            [Transaction::one(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                r#"INSERT INTO "addresses" ("id", "country", "alpha2", "previous", "country_code") VALUES ($1, $2, $3, $4, $5)"#,
+                [
+                    Value::Int(Some(1)),
+                    Value::String(Some("NO".to_string())),
+                    Value::String(Some("SE".to_string())),
+                    Value::String(None),
+                    Value::SmallInt(Some(208)),
+                ],
+            ))]
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Search of jurisdictions by name.
+
+use crate::Jurisdiction;
+
+use lazy_static::lazy_static;
+
+/// An index of names, sorted by name.
+type NameIndex = Vec<(String, Jurisdiction)>;
+
+fn build_index(name: impl Fn(&Jurisdiction) -> String) -> NameIndex {
+    let mut index: NameIndex = Jurisdiction::all()
+        .map(|jurisdiction| (name(&jurisdiction), jurisdiction))
+        .collect();
+    index.sort_by(|(a, _), (b, _)| a.cmp(b));
+    index
+}
+
+lazy_static! {
+    /// The english names of all jurisdictions folded to lower case.
+    static ref NAME_INDEX: NameIndex = build_index(|j| j.name().to_lowercase());
+    /// The english names of all jurisdictions folded to lower case ASCII.
+    static ref ASCII_INDEX: NameIndex = build_index(|j| j.name_ascii().to_string());
+}
+
+/// Fold a query in the manner of `Jurisdiction::name_ascii`.
+///
+/// Only ASCII punctuation is dropped, as the diacritics of the names are folded by the
+/// build script. Any other characters are lower cased and kept, failing to match. A
+/// trailing separator is kept, such that the prefix `cote ` only matches whole words.
+pub(crate) fn fold_query(query: &str) -> String {
+    let mut folded = String::with_capacity(query.len());
+    for c in query.chars() {
+        if c.is_whitespace() || c == '-' || c == '/' {
+            if !folded.is_empty() && !folded.ends_with(' ') {
+                folded.push(' ');
+            }
+        } else if !c.is_ascii_punctuation() {
+            folded.extend(c.to_lowercase());
+        }
+    }
+    folded
+}
+
+/// Return the entries of `index` whose name starts with `prefix`.
+fn prefixed<'a>(index: &'a [(String, Jurisdiction)], prefix: &str) -> &'a [(String, Jurisdiction)] {
+    // The names sharing a prefix are adjacent in the sorted index
+    let start = index.partition_point(|(name, _)| name.as_str() < prefix);
+    let len = index[start..].partition_point(|(name, _)| name.starts_with(prefix));
+    &index[start..start + len]
+}
+
+/// Return the entry of `index` whose name is `name`.
+fn exact(index: &[(String, Jurisdiction)], name: &str) -> Option<Jurisdiction> {
+    index
+        .binary_search_by(|(entry, _)| entry.as_str().cmp(name))
+        .ok()
+        .map(|position| index[position].1.clone())
+}
+
+/// Return the jurisdiction whose english name is `name` ignoring case, falling back to
+/// the ASCII folded names.
+pub(crate) fn by_name(name: &str) -> Option<Jurisdiction> {
+    exact(&NAME_INDEX, &name.to_lowercase())
+        .or_else(|| exact(&ASCII_INDEX, fold_query(name).trim_end()))
+}
+
+/// Return the jurisdictions whose english name starts with `prefix` ignoring case, ordered
+/// by name, falling back to the ASCII folded names.
+pub(crate) fn by_name_prefix(prefix: &str) -> impl Iterator<Item = Jurisdiction> {
+    let mut found = prefixed(&NAME_INDEX, &prefix.to_lowercase());
+    if found.is_empty() {
+        found = prefixed(&ASCII_INDEX, &fold_query(prefix));
+    }
+    found.iter().map(|(_, jurisdiction)| jurisdiction.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha2;
+
+    fn names(prefix: &str) -> Vec<&'static str> {
+        by_name_prefix(prefix).map(|j| j.name()).collect()
+    }
+
+    #[test]
+    fn test_name_prefix() {
+        let expected: &[&str] = match crate::dataset::get("country-region").unwrap().version() {
+            // The fixture of tests/fixtures, named as before the renaming in 2019
+            Some("2017") => &["Norfolk Island", "Northern Mariana Islands", "Norway"],
+            _ => &[
+                "Norfolk Island",
+                "North Macedonia",
+                "Northern Mariana Islands",
+                "Norway",
+            ],
+        };
+        assert_eq!(names("Nor"), expected);
+        assert_eq!(names("nOR"), names("Nor"));
+    }
+
+    #[test]
+    fn test_name_prefix_single() {
+        let found: Vec<_> = by_name_prefix("swe").collect();
+        assert_eq!(found, [Jurisdiction::from(Alpha2::SE)]);
+        assert_eq!(names("ÅLAND"), ["Åland Islands"]);
+    }
+
+    #[test]
+    fn test_name_prefix_none() {
+        assert_eq!(by_name_prefix("Xyz").count(), 0);
+        assert_eq!(by_name_prefix("Norway and Sweden").count(), 0);
+    }
+
+    #[test]
+    fn test_name_prefix_empty() {
+        assert_eq!(by_name_prefix("").count(), Jurisdiction::all().count());
+    }
+
+    #[test]
+    fn test_name_ascii() {
+        let folded = |alpha2: Alpha2| Jurisdiction::from(alpha2).name_ascii();
+        assert_eq!(folded(Alpha2::CI), "cote divoire");
+        assert_eq!(folded(Alpha2::CW), "curacao");
+        assert_eq!(folded(Alpha2::AX), "aland islands");
+        assert_eq!(folded(Alpha2::RE), "reunion");
+        assert_eq!(folded(Alpha2::GW), "guinea bissau");
+        assert_eq!(folded(Alpha2::VI), "virgin islands us");
+        for jurisdiction in Jurisdiction::all() {
+            let name = jurisdiction.name_ascii();
+            assert!(name
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b' '));
+        }
+    }
+
+    #[test]
+    fn test_name_ascii_fallback() {
+        let by_name = |name| by_name(name).map(|j| j.alpha2());
+        assert_eq!(by_name("Côte d'Ivoire"), Some(Alpha2::CI));
+        assert_eq!(by_name("cote divoire"), Some(Alpha2::CI));
+        assert_eq!(by_name("Cote d'Ivoire"), Some(Alpha2::CI));
+        assert_eq!(by_name("CURACAO"), Some(Alpha2::CW));
+        assert_eq!(by_name("aland islands"), Some(Alpha2::AX));
+        assert_eq!(by_name("Reunion"), Some(Alpha2::RE));
+        assert_eq!(by_name("Guinea Bissau"), Some(Alpha2::GW));
+        assert_eq!(by_name("Reunio"), None);
+
+        assert_eq!(names("reu"), ["Réunion"]);
+        assert_eq!(names("Cura"), ["Curaçao"]);
+        assert_eq!(names("cote "), ["Côte d'Ivoire"]);
+        assert_eq!(names("aland"), ["Åland Islands"]);
+    }
+}
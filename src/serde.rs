@@ -0,0 +1,166 @@
+//! Alternative serializations of jurisdictions, for use with `#[serde(with = "...")]`.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] serialize as their upper case code. Fields that
+//! must be lower case on the wire, such as Kubernetes labels, serialize through the
+//! [alpha2_lowercase](alpha2_lowercase/index.html) and
+//! [alpha3_lowercase](alpha3_lowercase/index.html) modules instead, keeping the upper case
+//! types in memory. The modules apply to fields of any of the three types, and
+//! deserialize the code in any ASCII case:
+//!
+//! ```rust
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Labels {
+//!     #[serde(with = "jurisdiction::serde::alpha2_lowercase")]
+//!     country: Jurisdiction,
+//!     #[serde(with = "jurisdiction::serde::alpha3_lowercase")]
+//!     origin: Alpha2,
+//! }
+//!
+//! let labels = Labels {
+//!     country: Jurisdiction::from(Alpha2::NO),
+//!     origin: Alpha2::SE,
+//! };
+//! let json = serde_json::to_string(&labels).unwrap();
+//! assert_eq!(json, r#"{"country":"no","origin":"swe"}"#);
+//!
+//! let labels: Labels = serde_json::from_str(r#"{"country":"NO","origin":"Swe"}"#).unwrap();
+//! assert_eq!(labels.origin, Alpha2::SE);
+//! ```
+//!
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+//! [Jurisdiction]: ../struct.Jurisdiction.html
+
+/// Implement the lower case with-module of an alpha code.
+macro_rules! lowercase_alpha {
+    ($module:ident, $alpha:ident, $name:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $module {
+            use crate::alpha::$alpha;
+            use crate::Jurisdiction;
+
+            use serde::{Deserializer, Serializer};
+
+            #[doc = concat!("Serialize as the lower case ", $name, " code.")]
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Clone + Into<Jurisdiction>,
+                S: Serializer,
+            {
+                let jurisdiction: Jurisdiction = value.clone().into();
+                serializer.serialize_str($alpha::from(jurisdiction).lowercase())
+            }
+
+            #[doc = concat!("Deserialize from the ", $name, " code, in any case.")]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: From<Jurisdiction>,
+                D: Deserializer<'de>,
+            {
+                let alpha: $alpha = crate::strict::deserialize_alpha_str(deserializer, true)?;
+                Ok(T::from(Jurisdiction::from(alpha)))
+            }
+        }
+    };
+}
+
+lowercase_alpha!(
+    alpha2_lowercase,
+    Alpha2,
+    "alpha2",
+    "Serialize an [Alpha2](../../enum.Alpha2.html), [Alpha3](../../enum.Alpha3.html) or \
+     [Jurisdiction](../../struct.Jurisdiction.html) as its lower case alpha2 code."
+);
+lowercase_alpha!(
+    alpha3_lowercase,
+    Alpha3,
+    "alpha3",
+    "Serialize an [Alpha2](../../enum.Alpha2.html), [Alpha3](../../enum.Alpha3.html) or \
+     [Jurisdiction](../../struct.Jurisdiction.html) as its lower case alpha3 code."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::alpha::{Alpha2, Alpha3};
+    use crate::Jurisdiction;
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Lowercase {
+        #[serde(with = "super::alpha2_lowercase")]
+        alpha2: Alpha2,
+        #[serde(with = "super::alpha3_lowercase")]
+        alpha3: Alpha3,
+        #[serde(with = "super::alpha2_lowercase")]
+        jurisdiction: Jurisdiction,
+    }
+
+    #[test]
+    fn test_lowercase_round_trip_json() {
+        for jurisdiction in Jurisdiction::all() {
+            let value = Lowercase {
+                alpha2: jurisdiction.alpha2(),
+                alpha3: jurisdiction.alpha3(),
+                jurisdiction: jurisdiction.clone(),
+            };
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(
+                json,
+                format!(
+                    r#"{{"alpha2":"{}","alpha3":"{}","jurisdiction":"{}"}}"#,
+                    jurisdiction.alpha2().to_string().to_lowercase(),
+                    jurisdiction.alpha3().to_string().to_lowercase(),
+                    jurisdiction.alpha2().to_string().to_lowercase(),
+                )
+            );
+            assert_eq!(serde_json::from_str::<Lowercase>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_lowercase_mixed_case_input() {
+        let json = r#"{"alpha2": "No", "alpha3": "sWE", "jurisdiction": "DK"}"#;
+        let value: Lowercase = serde_json::from_str(json).unwrap();
+        assert_eq!(value.alpha2, Alpha2::NO);
+        assert_eq!(value.alpha3, Alpha3::SWE);
+        assert_eq!(value.jurisdiction, Alpha2::DK);
+
+        // Only the code of the module deserializes
+        let json = r#"{"alpha2": "nor", "alpha3": "swe", "jurisdiction": "dk"}"#;
+        assert!(serde_json::from_str::<Lowercase>(json).is_err());
+    }
+
+    #[test]
+    fn test_lowercase_across_types() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Converted {
+            #[serde(with = "super::alpha3_lowercase")]
+            alpha2: Alpha2,
+            #[serde(with = "super::alpha2_lowercase")]
+            alpha3: Alpha3,
+        }
+
+        let value = Converted {
+            alpha2: Alpha2::NO,
+            alpha3: Alpha3::SWE,
+        };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"alpha2":"nor","alpha3":"se"}"#);
+        assert_eq!(serde_json::from_str::<Converted>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_lowercase_bincode() {
+        let value = Lowercase {
+            alpha2: Alpha2::NO,
+            alpha3: Alpha3::NOR,
+            jurisdiction: Jurisdiction::from(Alpha2::NO),
+        };
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<Lowercase>(&bytes).unwrap(), value);
+    }
+}
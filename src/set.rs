@@ -0,0 +1,302 @@
+//! A compact set of jurisdictions.
+
+use crate::alpha::Alpha2;
+use crate::generated::definition::DEFINITION_COUNT;
+use crate::Jurisdiction;
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::iter::FromIterator;
+
+const WORDS: usize = DEFINITION_COUNT.div_ceil(64);
+
+/// A set of [Jurisdiction]s, backed by a fixed size bit array.
+///
+/// Each jurisdiction occupies a single bit, making the set `Copy` and all operations,
+/// including the set algebra, a handful of bitwise operations.
+/// Iteration yields the jurisdictions in the order of `Jurisdiction::all`.
+///
+/// ```rust
+/// use jurisdiction::{Alpha2, Jurisdiction, JurisdictionSet};
+///
+/// const NORDICS: JurisdictionSet =
+///     JurisdictionSet::from_alpha2(&[Alpha2::DK, Alpha2::FI, Alpha2::IS, Alpha2::NO, Alpha2::SE]);
+///
+/// assert!(NORDICS.contains(&Jurisdiction::from(Alpha2::NO)));
+/// assert_eq!(NORDICS.len(), 5);
+/// ```
+///
+/// The set serializes as a list of [Alpha2] codes, sorted alphabetically.
+///
+/// [Jurisdiction]: struct.Jurisdiction.html
+/// [Alpha2]: enum.Alpha2.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct JurisdictionSet {
+    bits: [u64; WORDS],
+}
+
+impl JurisdictionSet {
+    /// Construct an empty set.
+    pub const fn new() -> JurisdictionSet {
+        JurisdictionSet { bits: [0; WORDS] }
+    }
+
+    /// Construct a set from a list of [Alpha2] codes, usable in constant expressions.
+    ///
+    /// [Alpha2]: enum.Alpha2.html
+    pub const fn from_alpha2(codes: &[Alpha2]) -> JurisdictionSet {
+        let mut bits = [0; WORDS];
+        let mut i = 0;
+        while i < codes.len() {
+            let index = codes[i] as usize;
+            bits[index / 64] |= 1 << (index % 64);
+            i += 1;
+        }
+        JurisdictionSet { bits }
+    }
+
+    /// Insert a jurisdiction into the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, jurisdiction: Jurisdiction) -> bool {
+        let (word, mask) = position(jurisdiction.position());
+        let inserted = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        inserted
+    }
+
+    /// Remove a jurisdiction from the set, returning whether it was present.
+    pub fn remove(&mut self, jurisdiction: &Jurisdiction) -> bool {
+        let (word, mask) = position(jurisdiction.position());
+        let removed = self.bits[word] & mask != 0;
+        self.bits[word] &= !mask;
+        removed
+    }
+
+    /// Return whether the set contains the jurisdiction.
+    pub fn contains(&self, jurisdiction: &Jurisdiction) -> bool {
+        let (word, mask) = position(jurisdiction.position());
+        self.bits[word] & mask != 0
+    }
+
+    /// Return the number of jurisdictions in the set.
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Return whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|word| *word == 0)
+    }
+
+    /// Return the jurisdictions in either set.
+    pub fn union(&self, other: &JurisdictionSet) -> JurisdictionSet {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Return the jurisdictions in both sets.
+    pub fn intersection(&self, other: &JurisdictionSet) -> JurisdictionSet {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Return the jurisdictions in this set, but not in `other`.
+    pub fn difference(&self, other: &JurisdictionSet) -> JurisdictionSet {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    /// Return an iterator over the jurisdictions in the set, in the order of
+    /// `Jurisdiction::all`.
+    pub fn iter(&self) -> Iter {
+        Iter {
+            set: *self,
+            index: 0,
+        }
+    }
+
+    fn combine(&self, other: &JurisdictionSet, op: impl Fn(u64, u64) -> u64) -> JurisdictionSet {
+        let mut bits = [0; WORDS];
+        for (i, word) in bits.iter_mut().enumerate() {
+            *word = op(self.bits[i], other.bits[i]);
+        }
+        JurisdictionSet { bits }
+    }
+}
+
+/// Return the word and bit mask of the dense jurisdiction index.
+fn position(index: usize) -> (usize, u64) {
+    (index / 64, 1 << (index % 64))
+}
+
+/// An iterator over the jurisdictions of a [JurisdictionSet](struct.JurisdictionSet.html).
+#[derive(Clone, Debug)]
+pub struct Iter {
+    set: JurisdictionSet,
+    index: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Jurisdiction;
+
+    fn next(&mut self) -> Option<Jurisdiction> {
+        while self.index < DEFINITION_COUNT {
+            let index = self.index;
+            self.index += 1;
+            let (word, mask) = position(index);
+            if self.set.bits[word] & mask != 0 {
+                return Some(Jurisdiction::from_position(index));
+            }
+        }
+        None
+    }
+}
+
+impl IntoIterator for JurisdictionSet {
+    type Item = Jurisdiction;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &JurisdictionSet {
+    type Item = Jurisdiction;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Jurisdiction> for JurisdictionSet {
+    fn from_iter<I: IntoIterator<Item = Jurisdiction>>(iter: I) -> JurisdictionSet {
+        let mut set = JurisdictionSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Jurisdiction> for JurisdictionSet {
+    fn extend<I: IntoIterator<Item = Jurisdiction>>(&mut self, iter: I) {
+        for jurisdiction in iter {
+            self.insert(jurisdiction);
+        }
+    }
+}
+
+impl fmt::Debug for JurisdictionSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(|jurisdiction| jurisdiction.alpha2()))
+            .finish()
+    }
+}
+
+impl Serialize for JurisdictionSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut codes: Vec<String> = self.iter().map(|j| j.alpha2().to_string()).collect();
+        codes.sort();
+        codes.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JurisdictionSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let codes = Vec::<Alpha2>::deserialize(deserializer)?;
+        Ok(codes.into_iter().map(Jurisdiction::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(codes: &[Alpha2]) -> JurisdictionSet {
+        JurisdictionSet::from_alpha2(codes)
+    }
+
+    #[test]
+    fn test_set_insert_remove() {
+        let mut nordics = JurisdictionSet::new();
+        assert!(nordics.is_empty());
+        assert!(nordics.insert(Alpha2::NO.into()));
+        assert!(nordics.insert(Alpha2::SE.into()));
+        assert!(!nordics.insert(Alpha2::NO.into()));
+        assert_eq!(nordics.len(), 2);
+        assert!(nordics.contains(&Alpha2::NO.into()));
+        assert!(!nordics.contains(&Alpha2::DK.into()));
+
+        assert!(nordics.remove(&Alpha2::NO.into()));
+        assert!(!nordics.remove(&Alpha2::NO.into()));
+        assert_eq!(nordics.len(), 1);
+    }
+
+    #[test]
+    fn test_set_last_definition() {
+        // The last definition occupies the highest bit in use.
+        let last = Jurisdiction::all().last().unwrap();
+        let mut all: JurisdictionSet = Jurisdiction::all().collect();
+        assert_eq!(all.len(), DEFINITION_COUNT);
+        assert!(all.contains(&last));
+        assert!(all.remove(&last));
+        assert_eq!(all.len(), DEFINITION_COUNT - 1);
+    }
+
+    #[test]
+    fn test_set_algebra_identities() {
+        let a = set(&[Alpha2::NO, Alpha2::SE, Alpha2::DK, Alpha2::ZW]);
+        let b = set(&[Alpha2::SE, Alpha2::FI, Alpha2::AF]);
+        let c = set(&[Alpha2::DK, Alpha2::FI, Alpha2::IS]);
+        let empty = JurisdictionSet::new();
+
+        assert_eq!(a.union(&b), b.union(&a));
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+        assert_eq!(a.union(&empty), a);
+        assert_eq!(a.intersection(&empty), empty);
+        assert_eq!(a.difference(&a), empty);
+        assert_eq!(
+            a.intersection(&b.union(&c)),
+            a.intersection(&b).union(&a.intersection(&c))
+        );
+        assert_eq!(
+            a.difference(&b.union(&c)),
+            a.difference(&b).intersection(&a.difference(&c))
+        );
+        assert_eq!(
+            a.union(&b).len(),
+            a.len() + b.len() - a.intersection(&b).len()
+        );
+        assert_eq!(a.intersection(&b), set(&[Alpha2::SE]));
+    }
+
+    #[test]
+    fn test_set_iter_ordered_by_name() {
+        let nordics = set(&[Alpha2::SE, Alpha2::NO, Alpha2::DK]);
+        let codes: Vec<Alpha2> = nordics.iter().map(|j| j.alpha2()).collect();
+        assert_eq!(codes, vec![Alpha2::DK, Alpha2::NO, Alpha2::SE]);
+
+        let collected: JurisdictionSet = nordics.into_iter().collect();
+        assert_eq!(collected, nordics);
+    }
+
+    #[test]
+    fn test_set_serde_round_trip() {
+        // United Kingdom orders before Viet Nam by name, while GB orders after AF by code.
+        let set = set(&[Alpha2::VN, Alpha2::GB, Alpha2::AF]);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"["AF","GB","VN"]"#);
+
+        let deserialized: JurisdictionSet = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, set);
+
+        assert!(serde_json::from_str::<JurisdictionSet>(r#"["XX"]"#).is_err());
+    }
+
+    #[test]
+    fn test_set_debug() {
+        let set = set(&[Alpha2::NO]);
+        assert_eq!(format!("{:?}", set), "{NO}");
+    }
+}
@@ -0,0 +1,89 @@
+//! Predefined sets of jurisdictions for common groupings.
+//!
+//! The memberships are a snapshot as of the version of the `political`
+//! [dataset](../dataset/index.html), and back the membership predicates of `Jurisdiction`,
+//! such as `Jurisdiction::is_eu_member`.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::sets;
+//!
+//! let norway = Jurisdiction::from(Alpha2::NO);
+//! assert!(!sets::EU.contains(&norway));
+//! assert!(sets::EU.union(&sets::EFTA).contains(&norway));
+//! ```
+
+pub use crate::generated::sets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction, JurisdictionSet};
+
+    #[test]
+    fn test_sets_match_members() {
+        use Alpha2::*;
+
+        // The members as of the political dataset, listed independently of it
+        let eu = JurisdictionSet::from_alpha2(&[
+            AT, BE, BG, CY, CZ, DE, DK, EE, ES, FI, FR, GR, HR, HU, IE, IT, LT, LU, LV, MT, NL, PL,
+            PT, RO, SE, SI, SK,
+        ]);
+        let efta = JurisdictionSet::from_alpha2(&[CH, IS, LI, NO]);
+        let eea = eu.union(&JurisdictionSet::from_alpha2(&[IS, LI, NO]));
+        let schengen = eu
+            .difference(&JurisdictionSet::from_alpha2(&[CY, IE]))
+            .union(&efta);
+
+        assert_eq!(EU, eu);
+        assert_eq!(EFTA, efta);
+        assert_eq!(EEA, eea);
+        assert_eq!(SCHENGEN, schengen);
+        assert_eq!(GDPR_SCOPE, eea);
+        assert_eq!(NORDICS, JurisdictionSet::from_alpha2(&[DK, FI, IS, NO, SE]));
+        assert_eq!(BALTICS, JurisdictionSet::from_alpha2(&[EE, LT, LV]));
+
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(eu.contains(&jurisdiction), jurisdiction.is_eu_member());
+            assert_eq!(eea.contains(&jurisdiction), jurisdiction.is_eea_member());
+            assert_eq!(efta.contains(&jurisdiction), jurisdiction.is_efta_member());
+            assert_eq!(
+                schengen.contains(&jurisdiction),
+                jurisdiction.is_schengen_member()
+            );
+        }
+    }
+
+    #[test]
+    fn test_sets_membership() {
+        assert_eq!(EU.len(), 27);
+        assert_eq!(EEA.len(), 30);
+        assert_eq!(EFTA.len(), 4);
+        assert_eq!(SCHENGEN.len(), 29);
+        assert_eq!(NORDICS.len(), 5);
+        assert_eq!(
+            BENELUX,
+            JurisdictionSet::from_alpha2(&[Alpha2::BE, Alpha2::NL, Alpha2::LU])
+        );
+        assert_eq!(BALTICS.len(), 3);
+        assert!(NANP.contains(&Alpha2::CA.into()));
+        assert!(NANP.contains(&Alpha2::JM.into()));
+        assert!(!NANP.contains(&Alpha2::MX.into()));
+    }
+
+    #[test]
+    fn test_sets_relations() {
+        assert_eq!(EEA.intersection(&EU), EU);
+        assert_eq!(GDPR_SCOPE, EU.union(&EEA));
+        let switzerland = JurisdictionSet::from_alpha2(&[Alpha2::CH]);
+        assert_eq!(EEA.difference(&EU), EFTA.difference(&switzerland));
+
+        let switzerland = Jurisdiction::from(Alpha2::CH);
+        assert!(switzerland.is_schengen_member());
+        assert!(!switzerland.is_eea_member());
+
+        let ireland = Jurisdiction::from(Alpha2::IE);
+        assert!(ireland.is_eu_member());
+        assert!(!ireland.is_schengen_member());
+    }
+}
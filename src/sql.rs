@@ -0,0 +1,119 @@
+//! Conversions shared by the database integrations.
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::Jurisdiction;
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "redis",
+    feature = "rusqlite",
+    feature = "sea-orm",
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Error returned when a stored value does not identify a jurisdiction.
+#[derive(Debug)]
+pub(crate) struct UnknownCodeError {
+    kind: &'static str,
+    value: String,
+}
+
+impl fmt::Display for UnknownCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognized {}: {:?}", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for UnknownCodeError {}
+
+/// Parse a stored alpha2 code, tolerating the trailing space padding of `CHAR` columns.
+pub(crate) fn alpha2_from_sql(value: &str) -> Result<Alpha2, UnknownCodeError> {
+    serde_plain::from_str(value.trim_end()).map_err(|_| UnknownCodeError {
+        kind: "alpha2 code",
+        value: value.to_string(),
+    })
+}
+
+/// Parse a stored alpha3 code, tolerating the trailing space padding of `CHAR` columns.
+pub(crate) fn alpha3_from_sql(value: &str) -> Result<Alpha3, UnknownCodeError> {
+    serde_plain::from_str(value.trim_end()).map_err(|_| UnknownCodeError {
+        kind: "alpha3 code",
+        value: value.to_string(),
+    })
+}
+
+/// Parse a stored alpha2 or alpha3 code, tolerating the trailing space padding of
+/// `CHAR` columns.
+pub(crate) fn jurisdiction_from_sql(value: &str) -> Result<Jurisdiction, UnknownCodeError> {
+    let trimmed = value.trim_end();
+    alpha2_from_sql(trimmed)
+        .map(Jurisdiction::from)
+        .or_else(|_| alpha3_from_sql(trimmed).map(Jurisdiction::from))
+        .map_err(|_| UnknownCodeError {
+            kind: "jurisdiction code",
+            value: value.to_string(),
+        })
+}
+
+/// Resolve a stored numeric country code.
+#[cfg(any(
+    feature = "postgres",
+    feature = "redis",
+    feature = "rusqlite",
+    feature = "sea-orm",
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres",
+    feature = "sqlx-sqlite"
+))]
+pub(crate) fn jurisdiction_from_sql_numeric(value: i64) -> Result<Jurisdiction, UnknownCodeError> {
+    u16::try_from(value)
+        .ok()
+        .and_then(|cc| Jurisdiction::try_from(cc).ok())
+        .ok_or_else(|| UnknownCodeError {
+            kind: "numeric country code",
+            value: value.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sql_alpha_padding() {
+        assert_eq!(alpha2_from_sql("NO").unwrap(), Alpha2::NO);
+        assert_eq!(alpha2_from_sql("NO ").unwrap(), Alpha2::NO);
+        assert_eq!(alpha3_from_sql("NOR").unwrap(), Alpha3::NOR);
+        assert_eq!(jurisdiction_from_sql("NOR").unwrap(), Alpha2::NO);
+        assert_eq!(jurisdiction_from_sql("NO  ").unwrap(), Alpha2::NO);
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "postgres",
+        feature = "redis",
+        feature = "rusqlite",
+        feature = "sea-orm",
+        feature = "sqlx-mysql",
+        feature = "sqlx-postgres",
+        feature = "sqlx-sqlite"
+    ))]
+    fn test_sql_numeric() {
+        assert_eq!(jurisdiction_from_sql_numeric(578).unwrap(), Alpha2::NO);
+        assert!(jurisdiction_from_sql_numeric(0).is_err());
+        assert!(jurisdiction_from_sql_numeric(-578).is_err());
+        assert!(jurisdiction_from_sql_numeric(100_000).is_err());
+    }
+
+    #[test]
+    fn test_sql_unknown_message() {
+        let err = alpha2_from_sql("XX").unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized alpha2 code: "XX""#);
+        let err = jurisdiction_from_sql(" NO").unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized jurisdiction code: " NO""#);
+    }
+}
@@ -0,0 +1,183 @@
+//! [sqlx] support for MySQL.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are encoded as their alpha code into text columns,
+//! and decoded from `VARCHAR`, `CHAR` and `TEXT` columns, tolerating the space padding of
+//! `CHAR` columns retrieved with `PAD_CHAR_TO_FULL_LENGTH`. A `Jurisdiction` is encoded as
+//! its alpha2 code, and is additionally decoded from integer columns holding the numeric
+//! country code, such as `SMALLINT UNSIGNED`.
+//!
+//! ```rust,no_run
+//! use jurisdiction::Jurisdiction;
+//!
+//! async fn customers_in(
+//!     pool: &sqlx::MySqlPool,
+//!     jurisdiction: &Jurisdiction,
+//! ) -> Result<Vec<(String, Jurisdiction)>, sqlx::Error> {
+//!     sqlx::query_as("SELECT name, country FROM customers WHERE country = ?")
+//!         .bind(jurisdiction)
+//!         .fetch_all(pool)
+//!         .await
+//! }
+//! ```
+//!
+//! [sqlx]: https://docs.rs/sqlx
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{
+    alpha2_from_sql, alpha3_from_sql, jurisdiction_from_sql, jurisdiction_from_sql_numeric,
+};
+use crate::Jurisdiction;
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
+use sqlx::{Decode, Encode, Type, ValueRef};
+
+impl Type<MySql> for Alpha2 {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <str as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for Alpha2 {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<MySql>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for Alpha2 {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha2_from_sql(<&str as Decode<MySql>>::decode(value)?)?)
+    }
+}
+
+impl Type<MySql> for Alpha3 {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <str as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for Alpha3 {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<MySql>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for Alpha3 {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha3_from_sql(<&str as Decode<MySql>>::decode(value)?)?)
+    }
+}
+
+impl Type<MySql> for Jurisdiction {
+    fn type_info() -> MySqlTypeInfo {
+        <str as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <str as Type<MySql>>::compatible(ty)
+            || <i64 as Type<MySql>>::compatible(ty)
+            || <u64 as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for Jurisdiction {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        <Alpha2 as Encode<MySql>>::encode_by_ref(&self.alpha2(), buf)
+    }
+}
+
+impl<'r> Decode<'r, MySql> for Jurisdiction {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        let ty = value.type_info();
+        if <i64 as Type<MySql>>::compatible(&ty) {
+            let cc = <i64 as Decode<MySql>>::decode(value)?;
+            return Ok(jurisdiction_from_sql_numeric(cc)?);
+        }
+        if <u64 as Type<MySql>>::compatible(&ty) {
+            let cc = <u32 as Decode<MySql>>::decode(value)?;
+            return Ok(jurisdiction_from_sql_numeric(i64::from(cc))?);
+        }
+        let code = <&str as Decode<MySql>>::decode(value)?;
+        Ok(jurisdiction_from_sql(code)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqlx_mysql_compatible() {
+        let text = &<str as Type<MySql>>::type_info();
+        assert!(<Alpha2 as Type<MySql>>::compatible(text));
+        assert!(<Alpha3 as Type<MySql>>::compatible(text));
+        assert!(<Jurisdiction as Type<MySql>>::compatible(text));
+        for int in &[
+            <i16 as Type<MySql>>::type_info(),
+            <u16 as Type<MySql>>::type_info(),
+            <i32 as Type<MySql>>::type_info(),
+        ] {
+            assert!(<Jurisdiction as Type<MySql>>::compatible(int));
+            assert!(!<Alpha2 as Type<MySql>>::compatible(int));
+        }
+        let float = &<f64 as Type<MySql>>::type_info();
+        assert!(!<Jurisdiction as Type<MySql>>::compatible(float));
+    }
+
+    #[test]
+    fn test_sqlx_mysql_encode() {
+        let mut buf = Vec::new();
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(matches!(
+            Encode::<MySql>::encode_by_ref(&norway, &mut buf),
+            Ok(IsNull::No)
+        ));
+        // Length encoded string
+        assert_eq!(&buf[..], b"\x02NO");
+    }
+
+    /// Run against the MySQL server of `JURISDICTION_MYSQL_URL`, if set.
+    #[test]
+    fn test_sqlx_mysql_server() {
+        use futures_executor::block_on;
+        use sqlx::{Connection, MySqlConnection};
+
+        let url = match std::env::var("JURISDICTION_MYSQL_URL") {
+            Ok(url) => url,
+            Err(_) => return,
+        };
+        let mut conn = block_on(MySqlConnection::connect(&url)).unwrap();
+
+        let norway: Jurisdiction = block_on(
+            sqlx::query_scalar("SELECT CAST(? AS CHAR(3))")
+                .bind(Jurisdiction::from(Alpha2::NO))
+                .fetch_one(&mut conn),
+        )
+        .unwrap();
+        assert_eq!(norway, Alpha2::NO);
+        let norway: Jurisdiction =
+            block_on(sqlx::query_scalar("SELECT CAST(578 AS UNSIGNED)").fetch_one(&mut conn))
+                .unwrap();
+        assert_eq!(norway, Alpha2::NO);
+        let alpha3: Alpha3 =
+            block_on(sqlx::query_scalar("SELECT 'NOR'").fetch_one(&mut conn)).unwrap();
+        assert_eq!(alpha3, Alpha3::NOR);
+
+        let err = block_on(sqlx::query_scalar::<_, Alpha2>("SELECT 'XX'").fetch_one(&mut conn))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"unrecognized alpha2 code: "XX""#));
+    }
+}
@@ -0,0 +1,148 @@
+//! [sqlx] support for Postgres.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are encoded as their alpha code into text columns,
+//! and decoded from `TEXT`, `VARCHAR` and `CHAR` columns, tolerating the space padding of
+//! `CHAR`. A `Jurisdiction` is encoded as its alpha2 code, and is additionally decoded
+//! from `SMALLINT` columns holding the numeric country code.
+//!
+//! ```rust,no_run
+//! use jurisdiction::{Alpha2, Jurisdiction};
+//!
+//! async fn customers_in(
+//!     pool: &sqlx::PgPool,
+//!     jurisdiction: &Jurisdiction,
+//! ) -> Result<Vec<(String, Jurisdiction)>, sqlx::Error> {
+//!     sqlx::query_as("SELECT name, country FROM customers WHERE country = $1")
+//!         .bind(jurisdiction)
+//!         .fetch_all(pool)
+//!         .await
+//! }
+//!
+//! async fn country_code_of(pool: &sqlx::PgPool, id: i64) -> Result<Jurisdiction, sqlx::Error> {
+//!     // Decode the numeric country code stored as SMALLINT
+//!     sqlx::query_scalar("SELECT country_code FROM addresses WHERE id = $1")
+//!         .bind(id)
+//!         .fetch_one(pool)
+//!         .await
+//! }
+//! ```
+//!
+//! [sqlx]: https://docs.rs/sqlx
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{
+    alpha2_from_sql, alpha3_from_sql, jurisdiction_from_sql, jurisdiction_from_sql_numeric,
+};
+use crate::Jurisdiction;
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use sqlx::{Decode, Encode, Type, ValueRef};
+
+impl Type<Postgres> for Alpha2 {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Alpha2 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<Postgres>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Alpha2 {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha2_from_sql(value.as_str()?)?)
+    }
+}
+
+impl Type<Postgres> for Alpha3 {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Alpha3 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<Postgres>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Alpha3 {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha3_from_sql(value.as_str()?)?)
+    }
+}
+
+impl Type<Postgres> for Jurisdiction {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty) || <i16 as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for Jurisdiction {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <Alpha2 as Encode<Postgres>>::encode_by_ref(&self.alpha2(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Jurisdiction {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if <i16 as Type<Postgres>>::compatible(&value.type_info()) {
+            let cc = <i16 as Decode<Postgres>>::decode(value)?;
+            return Ok(jurisdiction_from_sql_numeric(i64::from(cc))?);
+        }
+        Ok(jurisdiction_from_sql(value.as_str()?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqlx_postgres_compatible() {
+        for name in &["text", "varchar", "bpchar"] {
+            let ty = &PgTypeInfo::with_name(name);
+            assert!(<Alpha2 as Type<Postgres>>::compatible(ty));
+            assert!(<Alpha3 as Type<Postgres>>::compatible(ty));
+            assert!(<Jurisdiction as Type<Postgres>>::compatible(ty));
+        }
+        assert!(<Jurisdiction as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("int2")
+        ));
+        assert!(!<Alpha2 as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("int2")
+        ));
+        assert!(!<Jurisdiction as Type<Postgres>>::compatible(
+            &PgTypeInfo::with_name("bool")
+        ));
+    }
+
+    #[test]
+    fn test_sqlx_postgres_encode() {
+        let mut buf = PgArgumentBuffer::default();
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert!(matches!(
+            Encode::<Postgres>::encode_by_ref(&norway, &mut buf),
+            Ok(IsNull::No)
+        ));
+        assert_eq!(&buf[..], b"NO");
+    }
+}
@@ -0,0 +1,197 @@
+//! [sqlx] support for SQLite.
+//!
+//! [Alpha2], [Alpha3] and [Jurisdiction] are encoded as their alpha code into `TEXT`
+//! columns, and decoded from text values, tolerating trailing space padding. A
+//! `Jurisdiction` is encoded as its alpha2 code, and is additionally decoded from
+//! `INTEGER` values holding the numeric country code. As SQLite types values rather than
+//! columns, a single column may hold jurisdictions stored either way.
+//!
+//! ```rust,no_run
+//! use jurisdiction::Jurisdiction;
+//!
+//! async fn customers_in(
+//!     pool: &sqlx::SqlitePool,
+//!     jurisdiction: &Jurisdiction,
+//! ) -> Result<Vec<(String, Jurisdiction)>, sqlx::Error> {
+//!     sqlx::query_as("SELECT name, country FROM customers WHERE country = ?")
+//!         .bind(jurisdiction)
+//!         .fetch_all(pool)
+//!         .await
+//! }
+//! ```
+//!
+//! [sqlx]: https://docs.rs/sqlx
+
+use crate::alpha::{Alpha2, Alpha3};
+use crate::sql::{
+    alpha2_from_sql, alpha3_from_sql, jurisdiction_from_sql, jurisdiction_from_sql_numeric,
+};
+use crate::Jurisdiction;
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{Sqlite, SqliteArgumentsBuffer, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Type, ValueRef};
+
+impl Type<Sqlite> for Alpha2 {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <str as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Sqlite> for Alpha2 {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<Sqlite>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Alpha2 {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha2_from_sql(<&str as Decode<Sqlite>>::decode(value)?)?)
+    }
+}
+
+impl Type<Sqlite> for Alpha3 {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <str as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Sqlite> for Alpha3 {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        let code = self.to_string();
+        <&str as Encode<Sqlite>>::encode_by_ref(&code.as_str(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Alpha3 {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(alpha3_from_sql(<&str as Decode<Sqlite>>::decode(value)?)?)
+    }
+}
+
+impl Type<Sqlite> for Jurisdiction {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <str as Type<Sqlite>>::compatible(ty) || <i64 as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Sqlite> for Jurisdiction {
+    fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+        <Alpha2 as Encode<Sqlite>>::encode_by_ref(&self.alpha2(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for Jurisdiction {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        // The type of the value itself, rather than the declared type of its column
+        if <i64 as Type<Sqlite>>::compatible(&value.type_info()) {
+            let cc = <i64 as Decode<Sqlite>>::decode(value)?;
+            return Ok(jurisdiction_from_sql_numeric(cc)?);
+        }
+        let code = <&str as Decode<Sqlite>>::decode(value)?;
+        Ok(jurisdiction_from_sql(code)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_executor::block_on;
+    use sqlx::{Connection, SqliteConnection};
+
+    fn connect() -> SqliteConnection {
+        let mut conn = block_on(SqliteConnection::connect("sqlite::memory:")).unwrap();
+        block_on(
+            sqlx::query("CREATE TABLE customers (name TEXT NOT NULL, country)").execute(&mut conn),
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_sqlx_sqlite_roundtrip() {
+        let mut conn = connect();
+        let norway = Jurisdiction::from(Alpha2::NO);
+        block_on(
+            sqlx::query("INSERT INTO customers VALUES ('Ola', ?)")
+                .bind(&norway)
+                .execute(&mut conn),
+        )
+        .unwrap();
+
+        let (country,): (String,) =
+            block_on(sqlx::query_as("SELECT country FROM customers").fetch_one(&mut conn)).unwrap();
+        assert_eq!(country, "NO");
+
+        let found: Vec<(String, Jurisdiction)> = block_on(
+            sqlx::query_as("SELECT name, country FROM customers WHERE country = ?")
+                .bind(&norway)
+                .fetch_all(&mut conn),
+        )
+        .unwrap();
+        assert_eq!(found, [("Ola".to_string(), norway)]);
+
+        let alpha3: Alpha3 =
+            block_on(sqlx::query_scalar("SELECT 'NOR'").fetch_one(&mut conn)).unwrap();
+        assert_eq!(alpha3, Alpha3::NOR);
+        let alpha2: Alpha2 =
+            block_on(sqlx::query_scalar("SELECT 'NO '").fetch_one(&mut conn)).unwrap();
+        assert_eq!(alpha2, Alpha2::NO);
+    }
+
+    #[test]
+    fn test_sqlx_sqlite_text_and_integer() {
+        let mut conn = connect();
+        block_on(
+            sqlx::query(
+                "INSERT INTO customers VALUES ('Ola', 'NO'), ('Kari', 578), ('Nils', 'NOR')",
+            )
+            .execute(&mut conn),
+        )
+        .unwrap();
+
+        let countries: Vec<Jurisdiction> =
+            block_on(sqlx::query_scalar("SELECT country FROM customers").fetch_all(&mut conn))
+                .unwrap();
+        assert_eq!(countries, [Alpha2::NO, Alpha2::NO, Alpha2::NO]);
+    }
+
+    #[test]
+    fn test_sqlx_sqlite_unknown() {
+        let mut conn = connect();
+        let err =
+            block_on(sqlx::query_scalar::<_, Jurisdiction>("SELECT 'XX'").fetch_one(&mut conn))
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"unrecognized jurisdiction code: "XX""#));
+
+        let err =
+            block_on(sqlx::query_scalar::<_, Jurisdiction>("SELECT 1000").fetch_one(&mut conn))
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"unrecognized numeric country code: "1000""#));
+
+        let err = block_on(sqlx::query_scalar::<_, Alpha2>("SELECT 'NOR'").fetch_one(&mut conn))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(r#"unrecognized alpha2 code: "NOR""#));
+    }
+}
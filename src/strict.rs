@@ -0,0 +1,508 @@
+//! Strict deserialization of alpha codes and regions, for use with `#[serde(with = "...")]`.
+//!
+//! [Alpha2] and [Alpha3] deserialize from their code in any ASCII case, such that `"no"`,
+//! `"No"` and `"NO"` all deserialize as [Alpha2::NO], and always serialize in upper case.
+//! Fields that must only accept the upper case codes of ISO 3166 opt out through the
+//! [alpha2](alpha2/index.html) and [alpha3](alpha3/index.html) modules:
+//!
+//! ```rust
+//! use jurisdiction::Alpha2;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Shipment {
+//!     origin: Alpha2,
+//!     #[serde(with = "jurisdiction::strict::alpha2")]
+//!     destination: Alpha2,
+//! }
+//!
+//! let shipment: Shipment =
+//!     serde_json::from_str(r#"{"origin": "no", "destination": "SE"}"#).unwrap();
+//! assert_eq!(shipment.origin, Alpha2::NO);
+//!
+//! let lower = serde_json::from_str::<Shipment>(r#"{"origin": "NO", "destination": "se"}"#);
+//! assert!(lower.is_err());
+//! ```
+//!
+//! The region classifications deserialize names unknown to them as their `Undefined`
+//! variant, such that regions added to UN M49 deserialize in older releases. With the
+//! `region` feature, fields where an unknown name is a mistake, such as a typo in a
+//! configuration file, opt out through the [region](region/index.html),
+//! [sub_region](sub_region/index.html) and
+//! [intermediate_region](intermediate_region/index.html) modules, rejecting the name
+//! with an error listing the names of the classification:
+//!
+//! ```rust
+//! # #[cfg(feature = "region")]
+//! # {
+//! use jurisdiction::region::SubRegion;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Rollout {
+//!     #[serde(with = "jurisdiction::strict::sub_region")]
+//!     sub_region: SubRegion,
+//! }
+//!
+//! let rollout: Rollout = serde_json::from_str(r#"{"sub_region": "Northern Europe"}"#).unwrap();
+//! assert_eq!(rollout.sub_region, SubRegion::NorthernEurope);
+//!
+//! let typo = serde_json::from_str::<Rollout>(r#"{"sub_region": "Nothern Europe"}"#);
+//! assert!(typo.is_err());
+//! # }
+//! ```
+//!
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+//! [Alpha2::NO]: ../enum.Alpha2.html#variant.NO
+
+use serde::de::{self, DeserializeSeed, EnumAccess, Unexpected, VariantAccess, Visitor};
+use serde::Deserializer;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// An enum of alpha codes, whose variants are named by their upper case code.
+pub(crate) trait AlphaCode: Sized {
+    /// The name of the enum.
+    const NAME: &'static str;
+    /// What a deserializer expects of the enum, e.g. "an ISO 3166 alpha2 code".
+    const EXPECTING: &'static str;
+    /// The codes of the variants, in order of declaration.
+    const VARIANTS: &'static [&'static str];
+
+    /// Return the variant of an upper case code.
+    fn from_exact(code: &[u8]) -> Option<Self>;
+}
+
+/// Return the variant of a code, folding its case if `ignore_case`.
+fn from_code<T: AlphaCode>(code: &[u8], ignore_case: bool) -> Option<T> {
+    if !ignore_case {
+        return T::from_exact(code);
+    }
+    let mut upper = [0; 3];
+    let upper = upper.get_mut(..code.len())?;
+    upper.copy_from_slice(code);
+    upper.make_ascii_uppercase();
+    T::from_exact(upper)
+}
+
+/// Visit an alpha code as a string, or as the index of its variant.
+struct CodeVisitor<T> {
+    ignore_case: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: AlphaCode> Visitor<'de> for CodeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<T, E> {
+        T::VARIANTS
+            .get(index as usize)
+            .and_then(|code| T::from_exact(code.as_bytes()))
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, code: &str) -> Result<T, E> {
+        from_code(code.as_bytes(), self.ignore_case)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(code), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, code: &[u8]) -> Result<T, E> {
+        from_code(code, self.ignore_case)
+            .ok_or_else(|| E::invalid_value(Unexpected::Bytes(code), &self))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<T, A::Error> {
+        let (value, variant) = data.variant_seed(self)?;
+        variant.unit_variant()?;
+        Ok(value)
+    }
+}
+
+impl<'de, T: AlphaCode> DeserializeSeed<'de> for CodeVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+/// Deserialize an alpha code as a unit variant of its enum, the way it is serialized,
+/// folding its case if `ignore_case`.
+pub(crate) fn deserialize_alpha<'de, D, T>(
+    deserializer: D,
+    ignore_case: bool,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: AlphaCode,
+{
+    let visitor = CodeVisitor {
+        ignore_case,
+        marker: PhantomData,
+    };
+    deserializer.deserialize_enum(T::NAME, T::VARIANTS, visitor)
+}
+
+/// Deserialize an alpha code from a string, folding its case if `ignore_case`.
+pub(crate) fn deserialize_alpha_str<'de, D, T>(
+    deserializer: D,
+    ignore_case: bool,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: AlphaCode,
+{
+    let visitor = CodeVisitor {
+        ignore_case,
+        marker: PhantomData,
+    };
+    deserializer.deserialize_str(visitor)
+}
+
+/// Deserialize an [Alpha2](../../enum.Alpha2.html) from its upper case code only.
+pub mod alpha2 {
+    use crate::alpha::Alpha2;
+
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Serialize an alpha2 code as its upper case code, as it serializes by default.
+    pub fn serialize<S: Serializer>(alpha2: &Alpha2, serializer: S) -> Result<S::Ok, S::Error> {
+        alpha2.serialize(serializer)
+    }
+
+    /// Deserialize an alpha2 code, rejecting codes that are not in upper case.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Alpha2, D::Error> {
+        super::deserialize_alpha(deserializer, false)
+    }
+}
+
+/// Deserialize an [Alpha3](../../enum.Alpha3.html) from its upper case code only.
+pub mod alpha3 {
+    use crate::alpha::Alpha3;
+
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Serialize an alpha3 code as its upper case code, as it serializes by default.
+    pub fn serialize<S: Serializer>(alpha3: &Alpha3, serializer: S) -> Result<S::Ok, S::Error> {
+        alpha3.serialize(serializer)
+    }
+
+    /// Deserialize an alpha3 code, rejecting codes that are not in upper case.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Alpha3, D::Error> {
+        super::deserialize_alpha(deserializer, false)
+    }
+}
+
+/// Visit the name of a region classification, or the index of its variant, rejecting
+/// names that are unknown to it rather than visiting them as its `Undefined` variant.
+#[cfg(feature = "region")]
+struct RegionVisitor<T> {
+    undefined: T,
+    names: &'static [&'static str],
+}
+
+#[cfg(feature = "region")]
+impl<'de, T> Visitor<'de> for RegionVisitor<T>
+where
+    T: for<'a> serde::Deserialize<'a> + PartialEq,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a UN M49 region name")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<T, E> {
+        let undefined = self.undefined;
+        self.names
+            .get(index as usize)
+            .and_then(|name| crate::region::parse_segment(name, undefined))
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(index), &"a variant index"))
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<T, E> {
+        let names = self.names;
+        crate::region::parse_segment(name, self.undefined)
+            .ok_or_else(|| E::unknown_variant(name, names))
+    }
+
+    fn visit_bytes<E: de::Error>(self, name: &[u8]) -> Result<T, E> {
+        match std::str::from_utf8(name) {
+            Ok(name) => self.visit_str(name),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(name), &self)),
+        }
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<T, A::Error> {
+        let (value, variant) = data.variant_seed(self)?;
+        variant.unit_variant()?;
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "region")]
+impl<'de, T> DeserializeSeed<'de> for RegionVisitor<T>
+where
+    T: for<'a> serde::Deserialize<'a> + PartialEq,
+{
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+/// Implement the strict with-module of a region classification.
+#[cfg(feature = "region")]
+macro_rules! strict_region {
+    ($module:ident, $region:ident, $name:literal, $doc:literal) => {
+        #[doc = $doc]
+        pub mod $module {
+            use crate::region::$region;
+
+            use serde::{Deserializer, Serialize, Serializer};
+
+            #[doc = concat!("Serialize ", $name, " as its name, as it serializes by default.")]
+            pub fn serialize<S: Serializer>(
+                region: &$region,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                region.serialize(serializer)
+            }
+
+            #[doc = concat!("Deserialize ", $name, ", rejecting names that are unknown to it.")]
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$region, D::Error> {
+                let visitor = super::RegionVisitor {
+                    undefined: $region::Undefined,
+                    names: $region::NAMES,
+                };
+                deserializer.deserialize_enum(stringify!($region), $region::NAMES, visitor)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "region")]
+strict_region!(
+    region,
+    Region,
+    "a region",
+    "Deserialize a [Region](../../region/enum.Region.html) from a name known to it only."
+);
+#[cfg(feature = "region")]
+strict_region!(
+    sub_region,
+    SubRegion,
+    "a sub region",
+    "Deserialize a [SubRegion](../../region/enum.SubRegion.html) from a name known to it only."
+);
+#[cfg(feature = "region")]
+strict_region!(
+    intermediate_region,
+    IntermediateRegion,
+    "an intermediate region",
+    "Deserialize an [IntermediateRegion](../../region/enum.IntermediateRegion.html) from a \
+     name known to it only."
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::alpha::{Alpha2, Alpha3};
+
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Codes {
+        alpha2: Alpha2,
+        alpha3: Alpha3,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct StrictCodes {
+        #[serde(with = "super::alpha2")]
+        alpha2: Alpha2,
+        #[serde(with = "super::alpha3")]
+        alpha3: Alpha3,
+    }
+
+    #[test]
+    fn test_alpha_ignore_case_json() {
+        for (alpha2, alpha3) in &[("NO", "NOR"), ("no", "nor"), ("No", "Nor"), ("nO", "nOR")] {
+            let json = format!(r#"{{"alpha2": "{}", "alpha3": "{}"}}"#, alpha2, alpha3);
+            let codes: Codes = serde_json::from_str(&json).unwrap();
+            assert_eq!(codes.alpha2, Alpha2::NO);
+            assert_eq!(codes.alpha3, Alpha3::NOR);
+            assert_eq!(
+                serde_json::to_string(&codes).unwrap(),
+                r#"{"alpha2":"NO","alpha3":"NOR"}"#
+            );
+        }
+    }
+
+    #[test]
+    fn test_alpha_ignore_case_plain() {
+        assert_eq!(serde_plain::from_str::<Alpha2>("se").unwrap(), Alpha2::SE);
+        assert_eq!(serde_plain::from_str::<Alpha3>("Swe").unwrap(), Alpha3::SWE);
+        assert!(serde_plain::from_str::<Alpha2>("swe").is_err());
+    }
+
+    #[test]
+    fn test_alpha_unknown() {
+        let err = serde_json::from_str::<Alpha2>(r#""xx""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value: string "xx", expected an ISO 3166 alpha2 code at line 1 column 4"#
+        );
+        let err = serde_json::from_str::<Alpha3>(r#""NO""#).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            r#"invalid value: string "NO", expected an ISO 3166 alpha3 code at line 1 column 4"#
+        );
+        assert!(serde_json::from_str::<Alpha2>(r#""NOOO""#).is_err());
+        assert!(serde_json::from_str::<Alpha2>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_alpha_strict() {
+        let codes: StrictCodes =
+            serde_json::from_str(r#"{"alpha2": "NO", "alpha3": "NOR"}"#).unwrap();
+        assert_eq!(codes.alpha2, Alpha2::NO);
+        assert_eq!(
+            serde_json::to_string(&codes).unwrap(),
+            r#"{"alpha2":"NO","alpha3":"NOR"}"#
+        );
+        for json in &[
+            r#"{"alpha2": "no", "alpha3": "NOR"}"#,
+            r#"{"alpha2": "NO", "alpha3": "Nor"}"#,
+            r#"{"alpha2": "XX", "alpha3": "NOR"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<StrictCodes>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn test_alpha_variant_index() {
+        // Formats that are not self describing encode the alpha codes by variant index
+        let bytes = bincode::serialize(&Codes {
+            alpha2: Alpha2::NO,
+            alpha3: Alpha3::NOR,
+        })
+        .unwrap();
+        let codes: Codes = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(codes.alpha2, Alpha2::NO);
+        assert_eq!(codes.alpha3, Alpha3::NOR);
+        let strict: StrictCodes = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(strict.alpha2, Alpha2::NO);
+
+        let unknown = bincode::serialize(&(u32::MAX, 0u32)).unwrap();
+        assert!(bincode::deserialize::<Codes>(&unknown).is_err());
+    }
+
+    #[cfg(feature = "region")]
+    mod region {
+        use crate::region::{IntermediateRegion, Region, SubRegion};
+
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Regions {
+            region: Region,
+            sub_region: SubRegion,
+            intermediate_region: IntermediateRegion,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct StrictRegions {
+            #[serde(with = "crate::strict::region")]
+            region: Region,
+            #[serde(with = "crate::strict::sub_region")]
+            sub_region: SubRegion,
+            #[serde(with = "crate::strict::intermediate_region")]
+            intermediate_region: IntermediateRegion,
+        }
+
+        fn json(sub_region: &str) -> String {
+            format!(
+                r#"{{"region": "Europe", "sub_region": "{}", "intermediate_region": "Channel Islands"}}"#,
+                sub_region
+            )
+        }
+
+        #[test]
+        fn test_region_valid_names() {
+            let lenient: Regions = serde_json::from_str(&json("Northern Europe")).unwrap();
+            let strict: StrictRegions = serde_json::from_str(&json("Northern Europe")).unwrap();
+            assert_eq!(lenient.sub_region, SubRegion::NorthernEurope);
+            assert_eq!(strict.sub_region, SubRegion::NorthernEurope);
+            assert_eq!(
+                strict.intermediate_region,
+                IntermediateRegion::ChannelIslands
+            );
+            assert_eq!(
+                serde_json::to_string(&strict).unwrap(),
+                serde_json::to_string(&lenient).unwrap()
+            );
+
+            for name in SubRegion::NAMES {
+                let strict: StrictRegions = serde_json::from_str(&json(name)).unwrap();
+                assert_eq!(serde_plain::to_string(&strict.sub_region).unwrap(), *name);
+            }
+        }
+
+        #[test]
+        fn test_region_typo() {
+            let lenient: Regions = serde_json::from_str(&json("Nothern Europe")).unwrap();
+            assert_eq!(lenient.sub_region, SubRegion::Undefined);
+
+            let err = serde_json::from_str::<StrictRegions>(&json("Nothern Europe")).unwrap_err();
+            let message = err.to_string();
+            assert!(
+                message.starts_with("unknown variant `Nothern Europe`, expected one of"),
+                "{}",
+                message
+            );
+            assert!(message.contains("`Northern Europe`"), "{}", message);
+        }
+
+        #[test]
+        fn test_region_empty() {
+            let lenient: Regions = serde_json::from_str(&json("")).unwrap();
+            assert_eq!(lenient.sub_region, SubRegion::Undefined);
+
+            let err = serde_json::from_str::<StrictRegions>(&json("")).unwrap_err();
+            assert!(err.to_string().starts_with("unknown variant ``"));
+
+            let err = serde_json::from_str::<StrictRegions>(
+                r#"{"region": "", "sub_region": "Northern Europe", "intermediate_region": "Channel Islands"}"#,
+            )
+            .unwrap_err();
+            assert!(err.to_string().contains("`Africa`, `Asia`, `Europe`"));
+        }
+
+        #[test]
+        fn test_region_variant_index() {
+            let regions = Regions {
+                region: Region::Europe,
+                sub_region: SubRegion::NorthernEurope,
+                intermediate_region: IntermediateRegion::Undefined,
+            };
+            let bytes = bincode::serialize(&regions).unwrap();
+            let strict: StrictRegions = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(strict.region, Region::Europe);
+            assert_eq!(strict.sub_region, SubRegion::NorthernEurope);
+            assert_eq!(strict.intermediate_region, IntermediateRegion::Undefined);
+        }
+    }
+}
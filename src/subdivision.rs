@@ -0,0 +1,305 @@
+//! [ISO 3166-2] subdivision definitions.
+//!
+//! A [Subdivision] identifies a principal subdivision of a country, such as a county,
+//! province or state. Its code is made up of the [Alpha2] code of the country and a
+//! suffix of up to three alphanumeric characters, separated by a hyphen, e.g. `NO-03`
+//! or `US-CA`.
+//!
+//! The subdivision table is sourced from the ISO 3166-2 data of the Debian [iso-codes]
+//! project.
+//!
+//! [ISO 3166-2]: https://www.iso.org/iso-3166-country-codes.html
+//! [iso-codes]: https://salsa.debian.org/iso-codes-team/iso-codes
+//! [Subdivision]: struct.Subdivision.html
+//! [Alpha2]: ../enum.Alpha2.html
+
+use crate::alpha::Alpha2;
+use crate::definition::SubdivisionDefinition;
+use crate::generated::subdivision::GENERATED_SUBDIVISIONS;
+use crate::Jurisdiction;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A pointer sized object encoding an ISO 3166-2 country subdivision.
+///
+/// The textual representation, used by `FromStr`, `Display` and serde alike,
+/// is the canonical uppercase subdivision code, e.g. `NO-03`.
+#[derive(Clone, Copy, Debug)]
+pub struct Subdivision {
+    definition: &'static SubdivisionDefinition,
+}
+
+/// Error returned when a string could not be parsed into a [Subdivision].
+///
+/// [Subdivision]: struct.Subdivision.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseSubdivisionError {
+    /// The input is not shaped like an ISO 3166-2 code, `<alpha2>-<suffix>`.
+    Malformed(String),
+    /// The country prefix is not a recognized ISO 3166 alpha2 country code.
+    UnknownCountry(String),
+    /// The country is recognized, but has no subdivision with this code.
+    UnknownSubdivision(String),
+}
+
+impl fmt::Display for ParseSubdivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSubdivisionError::Malformed(s) => {
+                write!(f, "malformed ISO 3166-2 subdivision code: {}", s)
+            }
+            ParseSubdivisionError::UnknownCountry(s) => {
+                write!(f, "unrecognized ISO 3166 alpha2 country code: {}", s)
+            }
+            ParseSubdivisionError::UnknownSubdivision(s) => {
+                write!(f, "unrecognized ISO 3166-2 subdivision code: {}", s)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSubdivisionError {}
+
+impl std::cmp::PartialEq<Subdivision> for Subdivision {
+    fn eq(&self, other: &Subdivision) -> bool {
+        self.definition.code == other.definition.code
+    }
+}
+
+impl FromStr for Subdivision {
+    type Err = ParseSubdivisionError;
+
+    /// Parse the canonical uppercase subdivision code.
+    ///
+    /// Use [from_str_lenient] to accept other casing and surrounding whitespace.
+    ///
+    /// [from_str_lenient]: struct.Subdivision.html#method.from_str_lenient
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseSubdivisionError::Malformed(s.to_string());
+
+        let mut parts = s.splitn(2, '-');
+        let prefix = parts.next().ok_or_else(malformed)?;
+        let suffix = parts.next().ok_or_else(malformed)?;
+        if prefix.len() != 2
+            || !prefix.bytes().all(|b| b.is_ascii_uppercase())
+            || suffix.is_empty()
+            || suffix.len() > 3
+            || !suffix
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        {
+            return Err(malformed());
+        }
+
+        if serde_plain::from_str::<Alpha2>(prefix).is_err() {
+            return Err(ParseSubdivisionError::UnknownCountry(prefix.to_string()));
+        }
+
+        GENERATED_SUBDIVISIONS
+            .binary_search_by(|def| def.code.cmp(s))
+            .map(|index| Subdivision {
+                definition: &GENERATED_SUBDIVISIONS[index],
+            })
+            .map_err(|_| ParseSubdivisionError::UnknownSubdivision(s.to_string()))
+    }
+}
+
+impl fmt::Display for Subdivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.definition.code)
+    }
+}
+
+impl Serialize for Subdivision {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.definition.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Subdivision {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SubdivisionVisitor;
+
+        impl<'de> Visitor<'de> for SubdivisionVisitor {
+            type Value = Subdivision;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO 3166-2 subdivision code")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Subdivision::from_str(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(SubdivisionVisitor)
+    }
+}
+
+impl Subdivision {
+    /// Parse a subdivision code, ignoring ASCII case and surrounding whitespace.
+    ///
+    /// ```rust
+    /// # use jurisdiction::subdivision::Subdivision;
+    /// let oslo = Subdivision::from_str_lenient(" no-03 ").unwrap();
+    /// assert_eq!(oslo.code(), "NO-03");
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<Subdivision, ParseSubdivisionError> {
+        let trimmed = s.trim();
+        // Subdivision codes are at most six characters long, which spares folding the
+        // case of arbitrarily long input
+        if trimmed.len() > 6 {
+            return Err(ParseSubdivisionError::Malformed(s.to_string()));
+        }
+        Subdivision::from_str(&trimmed.to_ascii_uppercase())
+    }
+
+    /// Return the full ISO 3166-2 code of this subdivision, e.g. `NO-03`.
+    pub fn code(&self) -> &'static str {
+        self.definition.code
+    }
+
+    /// Return the name of this subdivision.
+    ///
+    /// Where ISO 3166-2 lists several names, only the first listed one is embedded.
+    pub fn name(&self) -> &'static str {
+        self.definition.name
+    }
+
+    /// Return the kind of subdivision, as classified by ISO 3166-2, e.g. `County` or `State`.
+    pub fn kind(&self) -> &'static str {
+        self.definition.kind
+    }
+
+    /// Return the [Jurisdiction] this subdivision belongs to.
+    ///
+    /// [Jurisdiction]: ../struct.Jurisdiction.html
+    pub fn jurisdiction(&self) -> Jurisdiction {
+        Jurisdiction::from(self.definition.alpha2)
+    }
+}
+
+/// Return all subdivisions of the country identified by `alpha2`, ordered by code.
+pub(crate) fn of_country(alpha2: Alpha2) -> Vec<Subdivision> {
+    let prefix = format!("{}-", alpha2);
+    let start = GENERATED_SUBDIVISIONS.partition_point(|def| def.code < prefix.as_str());
+
+    GENERATED_SUBDIVISIONS[start..]
+        .iter()
+        .take_while(|def| def.code.starts_with(&prefix))
+        .map(|definition| Subdivision { definition })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subdivision_from_str() {
+        let oslo = Subdivision::from_str("NO-03").unwrap();
+        assert_eq!(oslo.code(), "NO-03");
+        assert_eq!(oslo.name(), "Oslo");
+        assert_eq!(oslo.kind(), "County");
+        assert_eq!(oslo.jurisdiction(), Alpha2::NO);
+
+        let california = Subdivision::from_str("US-CA").unwrap();
+        assert_eq!(california.name(), "California");
+        assert_eq!(california.jurisdiction(), Alpha2::US);
+    }
+
+    #[test]
+    fn test_subdivision_from_str_strict_casing() {
+        assert_eq!(
+            Subdivision::from_str("no-03"),
+            Err(ParseSubdivisionError::Malformed("no-03".to_string()))
+        );
+        assert_eq!(
+            Subdivision::from_str(" NO-03"),
+            Err(ParseSubdivisionError::Malformed(" NO-03".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_subdivision_from_str_lenient() {
+        let oslo = Subdivision::from_str_lenient(" no-03\n").unwrap();
+        assert_eq!(oslo, Subdivision::from_str("NO-03").unwrap());
+
+        let california = Subdivision::from_str_lenient("Us-cA").unwrap();
+        assert_eq!(california.code(), "US-CA");
+
+        let long = format!(" no-03{} ", "0".repeat(1 << 16));
+        assert!(matches!(
+            Subdivision::from_str_lenient(&long),
+            Err(ParseSubdivisionError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_subdivision_from_str_malformed() {
+        for input in &[
+            "", "NO", "NO-", "NO03", "N-03", "NOR-03", "NO-0301", "NO-0_",
+        ] {
+            assert_eq!(
+                Subdivision::from_str(input),
+                Err(ParseSubdivisionError::Malformed(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_subdivision_from_str_unknown_subdivision() {
+        assert_eq!(
+            Subdivision::from_str("NO-99"),
+            Err(ParseSubdivisionError::UnknownSubdivision(
+                "NO-99".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_subdivision_from_str_unknown_country() {
+        assert_eq!(
+            Subdivision::from_str("XX-01"),
+            Err(ParseSubdivisionError::UnknownCountry("XX".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_subdivision_display() {
+        assert_eq!(Subdivision::from_str("US-CA").unwrap().to_string(), "US-CA");
+    }
+
+    #[test]
+    fn test_subdivision_serde_round_trip() {
+        for code in &["NO-03", "US-CA", "GB-ENG"] {
+            let subdivision = Subdivision::from_str(code).unwrap();
+            let json = serde_json::to_string(&subdivision).unwrap();
+            assert_eq!(json, format!("\"{}\"", code));
+
+            let parsed: Subdivision = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, subdivision);
+        }
+    }
+
+    #[test]
+    fn test_subdivision_deserialize_unknown() {
+        assert!(serde_json::from_str::<Subdivision>("\"NO-99\"").is_err());
+        assert!(serde_json::from_str::<Subdivision>("\"XX-01\"").is_err());
+        assert!(serde_json::from_str::<Subdivision>("3").is_err());
+    }
+
+    #[test]
+    fn test_subdivisions_of_jurisdiction() {
+        let norway = Jurisdiction::from(Alpha2::NO).subdivisions();
+        assert!(norway.contains(&Subdivision::from_str("NO-03").unwrap()));
+        assert!(norway.iter().all(|sub| sub.code().starts_with("NO-")));
+        assert!(norway.windows(2).all(|w| w[0].code() < w[1].code()));
+
+        let antarctica = Jurisdiction::from(Alpha2::AQ).subdivisions();
+        assert!(antarctica.is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//! E.212 mobile country codes.
+//!
+//! The mobile country code (MCC) of ITU-T E.212 identifies the country of a mobile
+//! network, as the first three digits of the international mobile subscriber identity
+//! (IMSI) of its subscribers. A jurisdiction may be assigned several codes, such as
+//! `310`–`316` for the United States, and a code may be shared by several jurisdictions,
+//! such as `340` for the French Antilles.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! use jurisdiction::telephony;
+//!
+//! let mcc = telephony::mcc_from_imsi("242016000001234").unwrap();
+//! assert_eq!(mcc, 242);
+//! assert_eq!(telephony::from_mcc(mcc).unwrap(), Alpha2::NO);
+//! ```
+
+use crate::generated::telephony::GENERATED_MOBILE_COUNTRY_CODES;
+use crate::Jurisdiction;
+
+/// Return the [Jurisdiction] assigned the mobile country code, if any.
+///
+/// Codes shared by several jurisdictions resolve to the primary jurisdiction of the code,
+/// e.g. Guadeloupe for `340` of the French Antilles and Israel for `425`.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn from_mcc(mcc: u16) -> Option<Jurisdiction> {
+    GENERATED_MOBILE_COUNTRY_CODES
+        .binary_search_by_key(&mcc, |(code, _)| *code)
+        .ok()
+        .map(|index| Jurisdiction::from(GENERATED_MOBILE_COUNTRY_CODES[index].1))
+}
+
+/// Return the mobile country code of an IMSI, its first three digits.
+///
+/// The IMSI must be made up of digits only, of up to 15 digits and at least the mobile
+/// country code followed by a two digit mobile network code. The code is not required
+/// to be assigned, see [from_mcc] on resolving it.
+///
+/// [from_mcc]: fn.from_mcc.html
+pub fn mcc_from_imsi(imsi: &str) -> Option<u16> {
+    if !(5..=15).contains(&imsi.len()) || !imsi.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    imsi[..3].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_telephony_from_mcc() {
+        assert_eq!(from_mcc(242), Some(Alpha2::NO.into()));
+        for mcc in 310..=316 {
+            assert_eq!(from_mcc(mcc), Some(Alpha2::US.into()), "{}", mcc);
+        }
+        assert_eq!(from_mcc(262), Some(Alpha2::DE.into()));
+    }
+
+    #[test]
+    fn test_telephony_unassigned_mcc() {
+        assert_eq!(from_mcc(0), None);
+        assert_eq!(from_mcc(241), None);
+        assert_eq!(from_mcc(999), None);
+        assert_eq!(from_mcc(1000), None);
+    }
+
+    #[test]
+    fn test_telephony_shared_mcc() {
+        assert_eq!(from_mcc(340), Some(Alpha2::GP.into()));
+        for alpha2 in &[Alpha2::GP, Alpha2::MQ, Alpha2::BL, Alpha2::MF] {
+            assert_eq!(
+                Jurisdiction::from(*alpha2).mobile_country_codes(),
+                &[340],
+                "{:?}",
+                alpha2
+            );
+        }
+        // French Guiana shares the French Antilles code, along with one of its own
+        assert_eq!(
+            Jurisdiction::from(Alpha2::GF).mobile_country_codes(),
+            &[340, 742]
+        );
+        assert_eq!(from_mcc(742), Some(Alpha2::GF.into()));
+    }
+
+    #[test]
+    fn test_telephony_mobile_country_codes() {
+        let us = Jurisdiction::from(Alpha2::US);
+        assert_eq!(
+            us.mobile_country_codes(),
+            &[310, 311, 312, 313, 314, 315, 316]
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::NO).mobile_country_codes(),
+            &[242]
+        );
+        assert!(Jurisdiction::from(Alpha2::AQ)
+            .mobile_country_codes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_telephony_mcc_from_imsi() {
+        assert_eq!(mcc_from_imsi("242016000001234"), Some(242));
+        assert_eq!(mcc_from_imsi("310150123456789"), Some(310));
+        assert_eq!(mcc_from_imsi("00101"), Some(1));
+        assert_eq!(mcc_from_imsi(""), None);
+        assert_eq!(mcc_from_imsi("2420"), None);
+        assert_eq!(mcc_from_imsi("2420160000012345"), None);
+        assert_eq!(mcc_from_imsi("24201600000123X"), None);
+        assert_eq!(mcc_from_imsi("242-01-600000"), None);
+    }
+}
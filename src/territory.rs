@@ -0,0 +1,78 @@
+//! Kinds of territory.
+//!
+//! The entries of ISO 3166-1 mix sovereign states with dependent territories, regions
+//! forming an integral part of a state and areas without a recognized sovereign. The
+//! [TerritoryKind] of a jurisdiction tells them apart, complementing the sovereign state
+//! given by `Jurisdiction::sovereign`: the kind answers what a jurisdiction is, while the
+//! sovereign answers who governs it.
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::territory::TerritoryKind;
+//!
+//! let greenland = Jurisdiction::from(Alpha2::GL);
+//! assert_eq!(greenland.territory_kind(), TerritoryKind::DependentTerritory);
+//! assert_eq!(greenland.sovereign().unwrap(), Alpha2::DK);
+//! ```
+//!
+//! [TerritoryKind]: enum.TerritoryKind.html
+
+use serde::{Deserialize, Serialize};
+
+/// The kind of territory of a jurisdiction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum TerritoryKind {
+    /// An independent, sovereign state.
+    SovereignState,
+    /// A territory dependent on its sovereign state, whether self-governing such as
+    /// Greenland, or uninhabited such as Bouvet Island.
+    DependentTerritory,
+    /// A region forming an integral part of its sovereign state, yet listed separately by
+    /// ISO 3166, such as French Guiana or Svalbard and Jan Mayen.
+    IntegralPart,
+    /// A self-governing state in free association with its former sovereign, such as the
+    /// Cook Islands.
+    AssociatedState,
+    /// A special administrative region, Hong Kong and Macao of China.
+    SpecialAdministrativeRegion,
+    /// A state recognized by only part of the international community, such as Taiwan.
+    PartiallyRecognizedState,
+    /// A territory whose sovereignty is disputed, Western Sahara.
+    Disputed,
+    /// The area governed by the Antarctic Treaty System, Antarctica.
+    AntarcticTreatyArea,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    #[test]
+    fn test_territory_kind() {
+        for (alpha2, kind) in &[
+            (Alpha2::NO, TerritoryKind::SovereignState),
+            (Alpha2::GL, TerritoryKind::DependentTerritory),
+            (Alpha2::SJ, TerritoryKind::IntegralPart),
+            (Alpha2::CK, TerritoryKind::AssociatedState),
+            (Alpha2::HK, TerritoryKind::SpecialAdministrativeRegion),
+            (Alpha2::TW, TerritoryKind::PartiallyRecognizedState),
+            (Alpha2::EH, TerritoryKind::Disputed),
+            (Alpha2::AQ, TerritoryKind::AntarcticTreatyArea),
+        ] {
+            assert_eq!(Jurisdiction::from(*alpha2).territory_kind(), *kind);
+        }
+    }
+
+    #[test]
+    fn test_territory_kind_sovereignty() {
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(
+                jurisdiction.territory_kind() == TerritoryKind::SovereignState,
+                jurisdiction.is_sovereign_state(),
+                "{:?}",
+                jurisdiction
+            );
+        }
+    }
+}
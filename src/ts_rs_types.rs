@@ -0,0 +1,140 @@
+//! [ts-rs] support.
+//!
+//! [Alpha2] and [Alpha3] are declared as string literal unions of their codes, and the
+//! region classifications as unions of their names, matching their serde form. As a
+//! `Jurisdiction` serializes as its alpha2 code, it is declared as an alias of `Alpha2`.
+//!
+//! ```rust,no_run
+//! use ts_rs::TS;
+//!
+//! jurisdiction::Jurisdiction::export_all_to("bindings").unwrap();
+//! ```
+//!
+//! Exports `bindings/Jurisdiction.ts` declaring `export type Jurisdiction = Alpha2;`, along
+//! with `bindings/Alpha2.ts` declaring `export type Alpha2 = "AF" | "AX" | ...;`.
+//!
+//! [ts-rs]: https://docs.rs/ts-rs
+//! [Alpha2]: ../enum.Alpha2.html
+//! [Alpha3]: ../enum.Alpha3.html
+
+use crate::alpha::Alpha2;
+use crate::Jurisdiction;
+
+use std::path::PathBuf;
+use ts_rs::{TypeVisitor, TS};
+
+impl TS for Jurisdiction {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn docs() -> Option<String> {
+        Some(
+            "/**\n * A jurisdiction, represented by its ISO 3166-1 alpha-2 code.\n */\n"
+                .to_string(),
+        )
+    }
+
+    fn decl() -> String {
+        format!(
+            "type {} = {};",
+            <Self as TS>::name(),
+            <Alpha2 as TS>::name()
+        )
+    }
+
+    fn decl_concrete() -> String {
+        Self::decl()
+    }
+
+    fn name() -> String {
+        "Jurisdiction".to_string()
+    }
+
+    fn inline() -> String {
+        <Alpha2 as TS>::inline()
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", <Self as TS>::name())
+    }
+
+    fn visit_dependencies(v: &mut impl TypeVisitor)
+    where
+        Self: 'static,
+    {
+        v.visit::<Alpha2>();
+    }
+
+    fn output_path() -> Option<PathBuf> {
+        Some(PathBuf::from("Jurisdiction.ts"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alpha::Alpha3;
+
+    use std::fs;
+
+    fn union(codes: impl Iterator<Item = String>) -> String {
+        codes
+            .map(|code| format!("\"{}\"", code))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    #[test]
+    fn test_ts_rs_export() {
+        let dir = std::env::temp_dir().join(format!("jurisdiction-ts-rs-{}", std::process::id()));
+        Jurisdiction::export_all_to(&dir).unwrap();
+        Alpha3::export_all_to(&dir).unwrap();
+        #[cfg(feature = "region")]
+        crate::region::Region::export_all_to(&dir).unwrap();
+
+        let read = |name: &str| {
+            let declaration = fs::read_to_string(dir.join(name)).unwrap();
+            // Skip the header comment of ts-rs
+            declaration
+                .lines()
+                .filter(|line| !line.starts_with("//"))
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim()
+                .to_string()
+        };
+        assert_eq!(
+            read("Jurisdiction.ts"),
+            "import type { Alpha2 } from \"./Alpha2\";\n\
+             \n\
+             /**\n \
+             * A jurisdiction, represented by its ISO 3166-1 alpha-2 code.\n \
+             */\n\
+             export type Jurisdiction = Alpha2;"
+        );
+        assert_eq!(
+            read("Alpha2.ts"),
+            format!(
+                "/**\n * Two alpha character ISO 3166 country code classification.\n */\n\
+                 export type Alpha2 = {};",
+                union(Jurisdiction::all().map(|j| j.alpha2().to_string()))
+            )
+        );
+        assert_eq!(
+            read("Alpha3.ts"),
+            format!(
+                "/**\n * Three alpha character ISO 3166 country code classification.\n */\n\
+                 export type Alpha3 = {};",
+                union(Jurisdiction::all().map(|j| j.alpha3().to_string()))
+            )
+        );
+        #[cfg(feature = "region")]
+        assert_eq!(
+            read("Region.ts"),
+            "/**\n * The high level region a Jurisdiction may zone to.\n */\n\
+             export type Region = \"Africa\" | \"Asia\" | \"Europe\" | \"Oceania\" | \"Americas\" \
+             | \"Undefined\";"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+//! United Nations regional groups.
+//!
+//! The member states of the United Nations are divided into five regional groups, which
+//! allocate the seats of elected UN bodies and are the usual unit of voting bloc
+//! analysis. The groups do not coincide with the geographic [regions] of UN M49, such
+//! that Israel, Australia and the United States are within the Western European and
+//! Others Group.
+//!
+//! Only member states are assigned a group. Non-member territories, along with the
+//! observer states of Palestine and the Holy See, have none. The memberships are a
+//! snapshot as of the version of the `un-regional-group` [dataset](../dataset/index.html).
+//!
+//! ```rust
+//! # use jurisdiction::{Jurisdiction, Alpha2};
+//! use jurisdiction::un::UnRegionalGroup;
+//!
+//! let israel = Jurisdiction::from(Alpha2::IL);
+//! assert_eq!(israel.un_regional_group(), Some(UnRegionalGroup::WesternEuropeanAndOthers));
+//! assert!(UnRegionalGroup::WesternEuropeanAndOthers.members().contains(&israel));
+//! ```
+//!
+//! [regions]: ../region/index.html
+
+use crate::{sets, JurisdictionSet};
+
+use serde::{Deserialize, Serialize};
+
+/// A regional group of the United Nations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum UnRegionalGroup {
+    /// The African Group.
+    African,
+    /// The Asia-Pacific Group.
+    AsiaPacific,
+    /// The Eastern European Group.
+    EasternEuropean,
+    /// The Latin American and Caribbean Group (GRULAC).
+    LatinAmericanAndCaribbean,
+    /// The Western European and Others Group (WEOG).
+    ///
+    /// The United States takes part as an observer, yet is considered a member for
+    /// electoral purposes and is listed as such. Turkey takes part in both this group and
+    /// the Asia-Pacific Group, and is listed within this group only, being its member for
+    /// electoral purposes.
+    WesternEuropeanAndOthers,
+}
+
+impl UnRegionalGroup {
+    /// Return the member states of the group, such as [sets::UN_WEOG].
+    ///
+    /// [sets::UN_WEOG]: ../sets/constant.UN_WEOG.html
+    pub fn members(&self) -> JurisdictionSet {
+        match self {
+            UnRegionalGroup::African => sets::UN_AFRICAN,
+            UnRegionalGroup::AsiaPacific => sets::UN_ASIA_PACIFIC,
+            UnRegionalGroup::EasternEuropean => sets::UN_EASTERN_EUROPEAN,
+            UnRegionalGroup::LatinAmericanAndCaribbean => sets::UN_GRULAC,
+            UnRegionalGroup::WesternEuropeanAndOthers => sets::UN_WEOG,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha2, Jurisdiction};
+
+    const GROUPS: &[UnRegionalGroup] = &[
+        UnRegionalGroup::African,
+        UnRegionalGroup::AsiaPacific,
+        UnRegionalGroup::EasternEuropean,
+        UnRegionalGroup::LatinAmericanAndCaribbean,
+        UnRegionalGroup::WesternEuropeanAndOthers,
+    ];
+
+    #[test]
+    fn test_un_regional_group_weog() {
+        for alpha2 in &[Alpha2::IL, Alpha2::US, Alpha2::AU, Alpha2::NZ, Alpha2::CA] {
+            assert_eq!(
+                Jurisdiction::from(*alpha2).un_regional_group(),
+                Some(UnRegionalGroup::WesternEuropeanAndOthers),
+                "{:?}",
+                alpha2
+            );
+        }
+    }
+
+    #[test]
+    fn test_un_regional_group_turkey() {
+        // Listed within WEOG only, by its membership for electoral purposes
+        let turkey = Jurisdiction::from(Alpha2::TR);
+        assert_eq!(
+            turkey.un_regional_group(),
+            Some(UnRegionalGroup::WesternEuropeanAndOthers)
+        );
+        assert!(!UnRegionalGroup::AsiaPacific.members().contains(&turkey));
+    }
+
+    #[test]
+    fn test_un_regional_group_geographic() {
+        assert_eq!(
+            Jurisdiction::from(Alpha2::KE).un_regional_group(),
+            Some(UnRegionalGroup::African)
+        );
+        assert_eq!(
+            Jurisdiction::from(Alpha2::BR).un_regional_group(),
+            Some(UnRegionalGroup::LatinAmericanAndCaribbean)
+        );
+    }
+
+    #[test]
+    fn test_un_regional_group_non_members() {
+        for alpha2 in &[Alpha2::GL, Alpha2::PR, Alpha2::AQ, Alpha2::PS, Alpha2::VA] {
+            assert_eq!(
+                Jurisdiction::from(*alpha2).un_regional_group(),
+                None,
+                "{:?}",
+                alpha2
+            );
+        }
+    }
+
+    #[test]
+    fn test_un_regional_group_members() {
+        let mut all = JurisdictionSet::new();
+        for group in GROUPS {
+            let members = group.members();
+            assert!(members
+                .iter()
+                .all(|j| j.un_regional_group() == Some(*group)));
+            all = all.union(&members);
+        }
+        // The member states of the United Nations
+        assert_eq!(all.len(), 193);
+        for jurisdiction in Jurisdiction::all() {
+            assert_eq!(
+                all.contains(&jurisdiction),
+                jurisdiction.un_regional_group().is_some()
+            );
+        }
+        assert_eq!(UnRegionalGroup::EasternEuropean.members().len(), 23);
+    }
+
+    #[test]
+    fn test_un_regional_group_snapshot() {
+        let dataset = crate::dataset::get("un-regional-group").unwrap();
+        assert_eq!(dataset.version(), Some("2025-01-01"));
+    }
+}
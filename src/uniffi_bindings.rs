@@ -0,0 +1,157 @@
+//! [UniFFI] bindings, exposing jurisdictions to Kotlin and Swift.
+//!
+//! The interface is declared through the UniFFI procedural macros, and consists of a
+//! `Jurisdiction` record along with the functions `from_code`, `all` and `in_region`.
+//! Foreign bindings are generated from a dynamic library of the crate by the
+//! `uniffi-bindgen` binary of the `uniffi-bindgen` feature:
+//!
+//! ```sh
+//! cargo rustc --lib --features uniffi --crate-type cdylib
+//! cargo run --features uniffi-bindgen --bin uniffi-bindgen -- generate \
+//!     --library target/debug/libjurisdiction.so --language kotlin --out-dir bindings
+//! ```
+//!
+//! [UniFFI]: https://mozilla.github.io/uniffi-rs/
+
+use crate::region::Region;
+use crate::Jurisdiction;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A jurisdiction along with its ISO 3166 codes and UN M49 region classifications.
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+#[uniffi(name = "Jurisdiction")]
+pub struct JurisdictionRecord {
+    /// The common english name.
+    pub name: String,
+    /// The ISO 3166 alpha2 code.
+    pub alpha2: String,
+    /// The ISO 3166 alpha3 code.
+    pub alpha3: String,
+    /// The ISO 3166 numeric country code.
+    pub numeric: u16,
+    /// The name of the region, e.g. `Europe`.
+    pub region: String,
+    /// The name of the sub region, e.g. `Northern Europe`.
+    pub sub_region: String,
+    /// The name of the intermediate region, e.g. `Channel Islands`.
+    pub intermediate_region: String,
+}
+
+impl From<Jurisdiction> for JurisdictionRecord {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        JurisdictionRecord {
+            name: jurisdiction.name().to_string(),
+            alpha2: jurisdiction.alpha2().to_string(),
+            alpha3: jurisdiction.alpha3().to_string(),
+            numeric: jurisdiction.country_code(),
+            region: serde_plain::to_string(&jurisdiction.region())
+                .expect("regions serialize to strings"),
+            sub_region: serde_plain::to_string(&jurisdiction.sub_region())
+                .expect("regions serialize to strings"),
+            intermediate_region: serde_plain::to_string(&jurisdiction.intermediate_region())
+                .expect("regions serialize to strings"),
+        }
+    }
+}
+
+/// The error of the functions of the interface.
+#[derive(Clone, Debug, PartialEq, uniffi::Error)]
+pub enum JurisdictionError {
+    /// The code is not an alpha2 or alpha3 code of a jurisdiction.
+    UnrecognizedCode {
+        /// The unrecognized code.
+        code: String,
+    },
+    /// The name is not the name of a region.
+    UnrecognizedRegion {
+        /// The unrecognized name.
+        region: String,
+    },
+}
+
+impl fmt::Display for JurisdictionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JurisdictionError::UnrecognizedCode { code } => {
+                write!(f, "unrecognized jurisdiction code: {:?}", code)
+            }
+            JurisdictionError::UnrecognizedRegion { region } => {
+                write!(f, "unrecognized region: {:?}", region)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JurisdictionError {}
+
+/// Return the jurisdiction of an alpha2 or alpha3 code.
+#[uniffi::export]
+pub fn from_code(code: String) -> Result<JurisdictionRecord, JurisdictionError> {
+    Jurisdiction::from_str(&code)
+        .map(JurisdictionRecord::from)
+        .map_err(|_| JurisdictionError::UnrecognizedCode { code })
+}
+
+/// Return all jurisdictions, in the order of ISO 3166-1.
+#[uniffi::export]
+pub fn all() -> Vec<JurisdictionRecord> {
+    Jurisdiction::all().map(JurisdictionRecord::from).collect()
+}
+
+/// Return all jurisdictions zoning to the region of a name, e.g. `Europe`.
+#[uniffi::export]
+pub fn in_region(region: String) -> Result<Vec<JurisdictionRecord>, JurisdictionError> {
+    // Unknown names deserialize as the undefined region
+    let parsed: Region = serde_plain::from_str(&region).expect("unknown regions are undefined");
+    if parsed == Region::Undefined && region != "Undefined" {
+        return Err(JurisdictionError::UnrecognizedRegion { region });
+    }
+    Ok(Jurisdiction::in_region(parsed)
+        .into_iter()
+        .map(JurisdictionRecord::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniffi_from_code() {
+        let norway = from_code("NOR".to_string()).unwrap();
+        assert_eq!(
+            norway,
+            JurisdictionRecord {
+                name: "Norway".to_string(),
+                alpha2: "NO".to_string(),
+                alpha3: "NOR".to_string(),
+                numeric: 578,
+                region: "Europe".to_string(),
+                sub_region: "Northern Europe".to_string(),
+                intermediate_region: "Undefined".to_string(),
+            }
+        );
+        let err = from_code("XX".to_string()).unwrap_err();
+        assert_eq!(err.to_string(), r#"unrecognized jurisdiction code: "XX""#);
+    }
+
+    #[test]
+    fn test_uniffi_all() {
+        assert_eq!(all().len(), Jurisdiction::all().count());
+    }
+
+    #[test]
+    fn test_uniffi_in_region() {
+        let europe = in_region("Europe".to_string()).unwrap();
+        assert!(europe.iter().all(|record| record.region == "Europe"));
+        assert_eq!(europe.len(), Jurisdiction::in_region(Region::Europe).len());
+        assert_eq!(
+            in_region("Atlantis".to_string()),
+            Err(JurisdictionError::UnrecognizedRegion {
+                region: "Atlantis".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Validation of alpha codes without constructing jurisdictions.
+//!
+//! The codes are matched against the generated code tables, ignoring ASCII case as
+//! [Jurisdiction::from_str](struct.Jurisdiction.html#impl-FromStr-for-Jurisdiction) does,
+//! without allocating for valid or invalid input alike.
+
+use crate::alpha::Alpha2;
+
+/// Return whether `code` is a code of the generated tables, of `N` ASCII letters.
+fn is_valid<const N: usize>(code: &str) -> bool {
+    let mut upper = [0u8; N];
+    if code.len() != N {
+        return false;
+    }
+    for (upper, byte) in upper.iter_mut().zip(code.bytes()) {
+        if !byte.is_ascii_alphabetic() {
+            return false;
+        }
+        *upper = byte.to_ascii_uppercase();
+    }
+    // ASCII letters are valid UTF-8
+    matches!(std::str::from_utf8(&upper), Ok(upper) if Alpha2::from_code(upper).is_some())
+}
+
+/// Return whether `code` is the alpha2 code of a jurisdiction, ignoring ASCII case.
+///
+/// ```rust
+/// assert!(jurisdiction::is_valid_alpha2("NO"));
+/// assert!(jurisdiction::is_valid_alpha2("no"));
+/// assert!(!jurisdiction::is_valid_alpha2("NOR"));
+/// assert!(!jurisdiction::is_valid_alpha2("XX"));
+/// ```
+pub fn is_valid_alpha2(code: &str) -> bool {
+    is_valid::<2>(code)
+}
+
+/// Return whether `code` is the alpha3 code of a jurisdiction, ignoring ASCII case.
+///
+/// ```rust
+/// assert!(jurisdiction::is_valid_alpha3("NOR"));
+/// assert!(!jurisdiction::is_valid_alpha3("NO"));
+/// ```
+pub fn is_valid_alpha3(code: &str) -> bool {
+    is_valid::<3>(code)
+}
+
+/// Return whether `code` is the alpha2 or alpha3 code of a jurisdiction, ignoring ASCII
+/// case, such that `code.parse::<Jurisdiction>()` succeeds.
+pub fn is_valid_code(code: &str) -> bool {
+    is_valid_alpha2(code) || is_valid_alpha3(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Jurisdiction;
+
+    #[test]
+    fn test_is_valid_codes() {
+        for code in &["NO", "no", "nO", "SE", "AX"] {
+            assert!(is_valid_alpha2(code), "{}", code);
+            assert!(!is_valid_alpha3(code), "{}", code);
+            assert!(is_valid_code(code), "{}", code);
+        }
+        for code in &["NOR", "nor", "SWE", "ALA"] {
+            assert!(!is_valid_alpha2(code), "{}", code);
+            assert!(is_valid_alpha3(code), "{}", code);
+            assert!(is_valid_code(code), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_is_valid_invalid() {
+        for code in &[
+            "", "N", "XX", "XXX", "NORW", "Norway", "N0", "N O", " NO", "NO ", "ÅL", "NØR", "ñ",
+            "\0NO",
+        ] {
+            assert!(!is_valid_alpha2(code), "{:?}", code);
+            assert!(!is_valid_alpha3(code), "{:?}", code);
+            assert!(!is_valid_code(code), "{:?}", code);
+        }
+    }
+
+    #[test]
+    fn test_is_valid_agrees_with_from_str() {
+        for jurisdiction in Jurisdiction::all() {
+            for code in &[
+                jurisdiction.alpha2().to_string(),
+                jurisdiction.alpha3().to_string(),
+            ] {
+                assert!(is_valid_code(code));
+                assert!(is_valid_code(&code.to_lowercase()));
+            }
+        }
+        for code in &["", "XX", "ZZZ", "n", "nø"] {
+            assert_eq!(
+                is_valid_code(code),
+                code.parse::<Jurisdiction>().is_ok(),
+                "{:?}",
+                code
+            );
+        }
+    }
+}
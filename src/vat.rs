@@ -0,0 +1,216 @@
+//! Value added tax (VAT) identification numbers.
+//!
+//! A VAT identification number within the European Union is made up of a two letter
+//! prefix identifying the member state, followed by the national number. The prefix
+//! follows the [Alpha2] code of the member state, with two exceptions:
+//!
+//! * Greece uses the prefix `EL`.
+//! * Northern Ireland uses the prefix `XI` for trade in goods, following the withdrawal
+//!   of the United Kingdom from the European Union. It resolves to
+//!   the United Kingdom, `GB`.
+//!
+//! Validation is limited to the structure of the national number, as described by its
+//! [VatFormat]; check digits are not verified.
+//!
+//! ```rust
+//! # use jurisdiction::Alpha2;
+//! let (jurisdiction, number) = jurisdiction::vat::parse("EL123456789").unwrap();
+//! assert_eq!(jurisdiction, Alpha2::GR);
+//! assert_eq!(number, "123456789");
+//! ```
+//!
+//! [Alpha2]: ../enum.Alpha2.html
+//! [VatFormat]: struct.VatFormat.html
+
+use crate::alpha::Alpha2;
+use crate::generated::vat::GENERATED_VAT_FORMATS;
+use crate::Jurisdiction;
+
+use std::fmt;
+
+/// The structure of the VAT identification numbers issued under a prefix.
+///
+/// The national number is described in the same pattern language as postal codes:
+/// `#` matches a digit, `@` a letter, `*` a letter or digit, `|` separates alternatives
+/// and any other character matches itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VatFormat {
+    pub(crate) prefix: &'static str,
+    pub(crate) pattern: &'static str,
+    pub(crate) example: &'static str,
+    pub(crate) alpha2: Alpha2,
+    pub(crate) primary: bool,
+}
+
+impl VatFormat {
+    /// Return the two letter prefix of the VAT identification numbers, e.g. `EL` for Greece.
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    /// Return the pattern describing the national number following the prefix.
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+
+    /// Return an example of a structurally valid national number.
+    pub fn example(&self) -> &'static str {
+        self.example
+    }
+
+    /// Return the [Jurisdiction] issuing VAT identification numbers under this prefix.
+    ///
+    /// [Jurisdiction]: ../struct.Jurisdiction.html
+    pub fn jurisdiction(&self) -> Jurisdiction {
+        Jurisdiction::from(self.alpha2)
+    }
+
+    /// Return whether `number`, excluding the prefix, matches the pattern.
+    pub fn is_match(&self, number: &str) -> bool {
+        crate::pattern::is_match(self.pattern, number)
+    }
+}
+
+/// Error returned when a VAT identification number could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVatError {
+    /// The input does not start with a two letter prefix.
+    Malformed(String),
+    /// The prefix does not identify a jurisdiction issuing VAT identification numbers.
+    UnknownPrefix(String),
+    /// The national number does not match the format for the prefix.
+    InvalidNumber(String),
+}
+
+impl fmt::Display for ParseVatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseVatError::Malformed(s) => {
+                write!(f, "malformed VAT identification number: {}", s)
+            }
+            ParseVatError::UnknownPrefix(s) => {
+                write!(f, "unrecognized VAT identification number prefix: {}", s)
+            }
+            ParseVatError::InvalidNumber(s) => {
+                write!(f, "invalid VAT identification number: {}", s)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseVatError {}
+
+/// Parse a VAT identification number in its compact form, without separators.
+///
+/// Return the issuing [Jurisdiction] and the national number following the prefix.
+/// The prefix is matched without regard to ASCII case.
+///
+/// [Jurisdiction]: ../struct.Jurisdiction.html
+pub fn parse(vat: &str) -> Result<(Jurisdiction, &str), ParseVatError> {
+    let prefix = match vat.get(..2) {
+        Some(prefix) if prefix.bytes().all(|b| b.is_ascii_alphabetic()) => prefix,
+        _ => return Err(ParseVatError::Malformed(vat.to_string())),
+    };
+    let number = &vat[2..];
+
+    let format = GENERATED_VAT_FORMATS
+        .iter()
+        .find(|format| format.prefix.eq_ignore_ascii_case(prefix))
+        .ok_or_else(|| ParseVatError::UnknownPrefix(prefix.to_string()))?;
+
+    if !format.is_match(number) {
+        return Err(ParseVatError::InvalidNumber(vat.to_string()));
+    }
+
+    Ok((format.jurisdiction(), number))
+}
+
+/// Return the format of the VAT identification numbers issued nationally by a jurisdiction.
+pub(crate) fn primary_format(alpha2: Alpha2) -> Option<VatFormat> {
+    GENERATED_VAT_FORMATS
+        .iter()
+        .find(|format| format.primary && format.alpha2 == alpha2)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Alpha2;
+
+    #[test]
+    fn test_vat_parse_greece() {
+        let (greece, number) = parse("EL123456789").unwrap();
+        assert_eq!(greece, Alpha2::GR);
+        assert_eq!(number, "123456789");
+
+        assert_eq!(
+            parse("GR123456789"),
+            Err(ParseVatError::UnknownPrefix("GR".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_vat_parse_northern_ireland() {
+        let (uk, number) = parse("XI123456789").unwrap();
+        assert_eq!(uk, Alpha2::GB);
+        assert_eq!(number, "123456789");
+    }
+
+    #[test]
+    fn test_vat_parse_germany() {
+        let (germany, number) = parse("DE123456789").unwrap();
+        assert_eq!(germany, Alpha2::DE);
+        assert_eq!(number, "123456789");
+
+        assert!(parse("de123456789").is_ok());
+        assert_eq!(
+            parse("DE12345678"),
+            Err(ParseVatError::InvalidNumber("DE12345678".to_string()))
+        );
+        assert_eq!(
+            parse("DE12345678A"),
+            Err(ParseVatError::InvalidNumber("DE12345678A".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_vat_parse_malformed() {
+        for input in &["", "D", "1E123456789", "Ø123"] {
+            assert_eq!(
+                parse(input),
+                Err(ParseVatError::Malformed(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_vat_prefix() {
+        let greece = Jurisdiction::from(Alpha2::GR);
+        assert_eq!(greece.vat_prefix(), Some("EL"));
+
+        let germany = Jurisdiction::from(Alpha2::DE);
+        assert_eq!(germany.vat_prefix(), Some("DE"));
+        assert_eq!(germany.vat_number_format().unwrap().pattern(), "#########");
+    }
+
+    #[test]
+    fn test_vat_prefix_outside_eu() {
+        let norway = Jurisdiction::from(Alpha2::NO);
+        assert_eq!(norway.vat_prefix(), None);
+        assert_eq!(norway.vat_number_format(), None);
+
+        // Northern Ireland does not make XI the prefix of the United Kingdom as a whole.
+        let uk = Jurisdiction::from(Alpha2::GB);
+        assert_eq!(uk.vat_prefix(), None);
+    }
+
+    #[test]
+    fn test_vat_examples_match_format() {
+        for format in GENERATED_VAT_FORMATS.iter() {
+            let vat = format!("{}{}", format.prefix(), format.example());
+            let (jurisdiction, _) = parse(&vat).unwrap();
+            assert_eq!(jurisdiction, format.jurisdiction());
+        }
+    }
+}
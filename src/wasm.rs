@@ -0,0 +1,150 @@
+//! [wasm-bindgen] bindings.
+//!
+//! [JsJurisdiction] exposes a `Jurisdiction` to JavaScript as a class of the same name,
+//! constructed from an alpha2 or alpha3 code:
+//!
+//! ```js
+//! import { JsJurisdiction } from "jurisdiction";
+//!
+//! const norway = new JsJurisdiction("NOR");
+//! console.log(norway.name, norway.alpha2, norway.numeric); // Norway NO 578
+//! console.log(JsJurisdiction.all().length);
+//! ```
+//!
+//! Constructing a `JsJurisdiction` from an unrecognized code throws an `Error`.
+//!
+//! The tests of the bindings only run on `wasm32-unknown-unknown`, e.g. with
+//! `CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner cargo test
+//! --target wasm32-unknown-unknown --features wasm`.
+//!
+//! [wasm-bindgen]: https://docs.rs/wasm-bindgen
+//! [JsJurisdiction]: struct.JsJurisdiction.html
+
+use crate::Jurisdiction;
+
+use wasm_bindgen::prelude::*;
+
+use std::str::FromStr;
+
+/// A `Jurisdiction` exported to JavaScript.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsJurisdiction(Jurisdiction);
+
+impl From<Jurisdiction> for JsJurisdiction {
+    fn from(jurisdiction: Jurisdiction) -> Self {
+        JsJurisdiction(jurisdiction)
+    }
+}
+
+impl From<JsJurisdiction> for Jurisdiction {
+    fn from(jurisdiction: JsJurisdiction) -> Self {
+        jurisdiction.0
+    }
+}
+
+#[wasm_bindgen]
+impl JsJurisdiction {
+    /// Construct a `JsJurisdiction` from an alpha2 or alpha3 code.
+    ///
+    /// Throws an `Error` if `code` is not recognized.
+    #[wasm_bindgen(constructor)]
+    pub fn new(code: &str) -> Result<JsJurisdiction, JsError> {
+        Jurisdiction::from_str(code)
+            .map(JsJurisdiction)
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+
+    /// Return all jurisdictions, in the order of ISO 3166-1.
+    pub fn all() -> Vec<JsJurisdiction> {
+        Jurisdiction::all().map(JsJurisdiction).collect()
+    }
+
+    /// The common english name.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    /// The ISO 3166 alpha2 code.
+    #[wasm_bindgen(getter)]
+    pub fn alpha2(&self) -> String {
+        self.0.alpha2().to_string()
+    }
+
+    /// The ISO 3166 alpha3 code.
+    #[wasm_bindgen(getter)]
+    pub fn alpha3(&self) -> String {
+        self.0.alpha3().to_string()
+    }
+
+    /// The ISO 3166 numeric country code.
+    #[wasm_bindgen(getter)]
+    pub fn numeric(&self) -> u16 {
+        self.0.country_code()
+    }
+
+    /// The name of the UN M49 region, e.g. `Europe`.
+    #[cfg(feature = "region")]
+    #[wasm_bindgen(getter)]
+    pub fn region(&self) -> String {
+        serde_plain::to_string(&self.0.region()).expect("regions serialize to strings")
+    }
+
+    /// The name of the UN M49 sub region, e.g. `Northern Europe`.
+    #[cfg(feature = "region")]
+    #[wasm_bindgen(getter, js_name = subRegion)]
+    pub fn sub_region(&self) -> String {
+        serde_plain::to_string(&self.0.sub_region()).expect("regions serialize to strings")
+    }
+
+    /// The name of the UN M49 intermediate region, e.g. `Channel Islands`.
+    #[cfg(feature = "region")]
+    #[wasm_bindgen(getter, js_name = intermediateRegion)]
+    pub fn intermediate_region(&self) -> String {
+        serde_plain::to_string(&self.0.intermediate_region()).expect("regions serialize to strings")
+    }
+
+    /// Return the alpha2 code.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.alpha2()
+    }
+}
+
+#[cfg(all(test, target_family = "wasm"))]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_wasm_parse() {
+        assert_eq!(JsJurisdiction::new("NO").unwrap().alpha3(), "NOR");
+        assert_eq!(JsJurisdiction::new("NOR").unwrap().alpha2(), "NO");
+        let err = JsValue::from(JsJurisdiction::new("XX").unwrap_err());
+        assert!(err.is_instance_of::<js_sys::Error>());
+        assert_eq!(
+            String::from(js_sys::Error::from(err).message()),
+            "unrecognized ISO 3166 alpha country code: XX"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_getters() {
+        let norway = JsJurisdiction::new("NO").unwrap();
+        assert_eq!(norway.name(), "Norway");
+        assert_eq!(norway.numeric(), 578);
+        assert_eq!(norway.to_js_string(), "NO");
+        #[cfg(feature = "region")]
+        {
+            assert_eq!(norway.region(), "Europe");
+            assert_eq!(norway.sub_region(), "Northern Europe");
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_wasm_all() {
+        assert_eq!(JsJurisdiction::all().len(), Jurisdiction::all().count());
+    }
+}
@@ -0,0 +1,107 @@
+use assert_cmd::Command;
+
+fn jurisdiction() -> Command {
+    Command::cargo_bin("jurisdiction").unwrap()
+}
+
+#[test]
+fn test_cli_lookup() {
+    for query in &["NO", "NOR", "578", "norway", "Kingdom of Norway"] {
+        jurisdiction()
+            .arg(query)
+            .assert()
+            .success()
+            .stdout("NO NOR 578 Norway (Europe, Northern Europe)\n");
+    }
+}
+
+#[test]
+fn test_cli_lookup_ascii_name() {
+    jurisdiction()
+        .arg("cote d'ivoire")
+        .assert()
+        .success()
+        .stdout("CI CIV 384 Côte d'Ivoire (Africa, Sub-Saharan Africa)\n");
+}
+
+#[test]
+fn test_cli_lookup_json() {
+    let output = jurisdiction()
+        .args(["SE", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "name": "Sweden",
+            "alpha2": "SE",
+            "alpha3": "SWE",
+            "numeric": 752,
+            "region": "Europe",
+            "sub_region": "Northern Europe",
+        })
+    );
+}
+
+#[test]
+fn test_cli_lookup_csv() {
+    jurisdiction()
+        .args(["KR", "DK", "--format", "csv"])
+        .assert()
+        .success()
+        .stdout(
+            "name,alpha2,alpha3,numeric,region,sub_region\n\
+             \"Korea, Republic of\",KR,KOR,410,Asia,Eastern Asia\n\
+             Denmark,DK,DNK,208,Europe,Northern Europe\n",
+        );
+}
+
+#[test]
+fn test_cli_list_region() {
+    let output = jurisdiction()
+        .args(["--region", "Europe", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let codes: Vec<_> = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|jurisdiction| jurisdiction["alpha2"].as_str().unwrap())
+        .collect();
+    assert!(codes.contains(&"NO"));
+    assert!(!codes.contains(&"JP"));
+
+    let output = jurisdiction()
+        .args(["--sub-region", "Northern Europe"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|line| line.starts_with("NO NOR")));
+    assert!(!stdout.lines().any(|line| line.starts_with("DE DEU")));
+}
+
+#[test]
+fn test_cli_unknown_input() {
+    jurisdiction()
+        .arg("XX")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr("error: unrecognized ISO 3166 alpha country code: XX\n");
+
+    let output = jurisdiction()
+        .args(["--region", "Europa"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains(r#"unrecognized region: "Europa""#),
+        "{}",
+        stderr
+    );
+}
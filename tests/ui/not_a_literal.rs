@@ -0,0 +1,6 @@
+use jurisdiction::{jurisdiction, Jurisdiction};
+
+fn main() {
+    let code = "NO";
+    let _: Jurisdiction = jurisdiction!(code);
+}
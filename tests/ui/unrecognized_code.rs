@@ -0,0 +1,5 @@
+use jurisdiction::{jurisdiction, Jurisdiction};
+
+fn main() {
+    let _: Jurisdiction = jurisdiction!("XX");
+}
@@ -0,0 +1,5 @@
+use jurisdiction::{jurisdictions, Jurisdiction};
+
+fn main() {
+    let _: [Jurisdiction; 3] = jurisdictions!["NO", "SW", "DK"];
+}